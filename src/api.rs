@@ -1,3 +1,4 @@
 pub(crate) mod common;
+pub(crate) mod omdb;
 pub(crate) mod themoviedb;
 pub(crate) mod thetvdb;
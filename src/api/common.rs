@@ -1,6 +1,8 @@
-use anyhow::Result;
+use std::fmt;
+
+use anyhow::{Error, Result};
 use chrono::{DateTime, Utc};
-use reqwest::{header, Response};
+use reqwest::{header, Response, StatusCode};
 
 use crate::model::{EpisodeId, Etag, MovieId, RemoteEpisodeId, RemoteId, SeriesId};
 
@@ -18,6 +20,81 @@ pub(crate) fn parse_last_modified(res: &Response) -> Result<Option<DateTime<Utc>
     )))
 }
 
+/// Number of seconds to back off for if a remote doesn't say.
+const DEFAULT_RETRY_AFTER: u64 = 10;
+
+/// Error raised when a remote is rate limiting us.
+#[derive(Debug)]
+pub(crate) struct RateLimited {
+    /// Number of seconds to wait before trying again.
+    pub(crate) retry_after: u64,
+}
+
+impl fmt::Display for RateLimited {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Rate limited, retry after {} seconds", self.retry_after)
+    }
+}
+
+impl std::error::Error for RateLimited {}
+
+/// Error raised when a remote responds with an unsuccessful status.
+#[derive(Debug)]
+pub(crate) struct StatusError {
+    pub(crate) status: StatusCode,
+    pub(crate) body: String,
+}
+
+impl fmt::Display for StatusError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.status, self.body)
+    }
+}
+
+impl std::error::Error for StatusError {}
+
+/// Test if an error is transient, like a connection failure, a timeout or a
+/// server error, so that trying again later might succeed.
+pub(crate) fn is_transient(error: &Error) -> bool {
+    error.chain().any(|e| {
+        if let Some(e) = e.downcast_ref::<StatusError>() {
+            return e.status.is_server_error();
+        }
+
+        if let Some(e) = e.downcast_ref::<reqwest::Error>() {
+            return e.is_connect()
+                || e.is_timeout()
+                || e.status().is_some_and(|s| s.is_server_error());
+        }
+
+        false
+    })
+}
+
+/// Parse out the number of seconds to wait from the retry-after header, which
+/// is either a number of seconds or a date.
+pub(crate) fn parse_retry_after(res: &Response) -> u64 {
+    let Some(value) = res
+        .headers()
+        .get(header::RETRY_AFTER)
+        .and_then(|h| h.to_str().ok())
+    else {
+        return DEFAULT_RETRY_AFTER;
+    };
+
+    if let Ok(seconds) = value.trim().parse::<u64>() {
+        return seconds;
+    }
+
+    match DateTime::parse_from_rfc2822(value) {
+        Ok(at) => {
+            let seconds = at.signed_duration_since(Utc::now()).num_seconds();
+            u64::try_from(seconds).unwrap_or_default()
+        }
+        Err(..) => DEFAULT_RETRY_AFTER,
+    }
+}
+
 /// Parse out etag if available.
 pub(crate) fn parse_etag(response: &Response) -> Option<Etag> {
     let header = response.headers().get(header::ETAG)?;
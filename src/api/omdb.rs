@@ -0,0 +1,192 @@
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{bail, Error, Result};
+use chrono::NaiveDate;
+use relative_path::RelativePath;
+use reqwest::{StatusCode, Url};
+use serde::Deserialize;
+
+use crate::api::common;
+use crate::model::*;
+use crate::service::UpdateSeries;
+
+const BASE_URL: &str = "https://www.omdbapi.com";
+const IMAGE_URL: &str = "https://m.media-amazon.com";
+const IDLE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// OMDb uses this in place of values which are missing.
+const NOT_AVAILABLE: &str = "N/A";
+
+struct State {
+    base_url: Url,
+    image_url: Url,
+}
+
+/// A client for the Open Movie Database, which is used to get basic metadata
+/// for shows which are only known by their IMDb id.
+#[derive(Clone)]
+pub(crate) struct Client {
+    state: Arc<State>,
+    client: reqwest::Client,
+    api_key: Arc<str>,
+}
+
+impl Client {
+    /// Construct a new client wrapping the given api key.
+    pub(crate) fn new<S>(api_key: &S) -> Result<Self>
+    where
+        S: ?Sized + AsRef<str>,
+    {
+        Ok(Self {
+            state: Arc::new(State {
+                base_url: Url::parse(BASE_URL).expect("illegal base url"),
+                image_url: Url::parse(IMAGE_URL).expect("illegal image url"),
+            }),
+            client: reqwest::ClientBuilder::new()
+                .pool_idle_timeout(IDLE_TIMEOUT)
+                .build()?,
+            api_key: api_key.as_ref().into(),
+        })
+    }
+
+    /// Set API key to the given value.
+    pub(crate) fn set_api_key<S>(&mut self, api_key: &S)
+    where
+        S: ?Sized + AsRef<str>,
+    {
+        self.api_key = api_key.as_ref().into();
+    }
+
+    /// Test if the client has an API key configured.
+    pub(crate) fn has_api_key(&self) -> bool {
+        !self.api_key.is_empty()
+    }
+
+    /// Download basic series information by IMDb id.
+    ///
+    /// Returns `None` if OMDb doesn't know about the title.
+    pub(crate) async fn series(
+        &self,
+        id: Raw<16>,
+        lookup: impl common::LookupSeriesId,
+    ) -> Result<Option<(UpdateSeries, BTreeSet<RemoteId>)>> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct Details {
+            response: String,
+            #[serde(default)]
+            title: Option<String>,
+            #[serde(default)]
+            released: Option<String>,
+            #[serde(default)]
+            year: Option<String>,
+            #[serde(default)]
+            plot: Option<String>,
+            #[serde(default)]
+            poster: Option<String>,
+            #[serde(default)]
+            genre: Option<String>,
+            #[serde(default)]
+            rated: Option<String>,
+        }
+
+        let imdb_id = id.to_string();
+
+        let res = self
+            .client
+            .get(self.state.base_url.clone())
+            .query(&[
+                ("i", imdb_id.as_str()),
+                ("apikey", self.api_key.as_ref()),
+                ("plot", "full"),
+            ])
+            .send()
+            .await?;
+
+        if res.status() == StatusCode::TOO_MANY_REQUESTS {
+            let retry_after = common::parse_retry_after(&res);
+            return Err(common::RateLimited { retry_after }.into());
+        }
+
+        if !res.status().is_success() {
+            let status = res.status();
+            let body = res.text().await?;
+            return Err(Error::from(common::StatusError { status, body }).context(id.to_string()));
+        }
+
+        let details: Details = res.json().await?;
+
+        if details.response != "True" {
+            return Ok(None);
+        }
+
+        let available = |value: Option<String>| value.filter(|v| v != NOT_AVAILABLE);
+
+        let remote_id = RemoteId::Imdb { id };
+        let remote_ids = BTreeSet::from([remote_id]);
+
+        let id = lookup
+            .lookup(remote_ids.iter().copied())
+            .unwrap_or_else(SeriesId::random);
+
+        // Only the year is known for some titles, in which case the series
+        // is dated to the start of it.
+        let first_air_date = available(details.released)
+            .and_then(|d| NaiveDate::parse_from_str(&d, "%d %b %Y").ok())
+            .or_else(|| {
+                let year = available(details.year)?;
+                let year = year.get(..4)?.parse().ok()?;
+                NaiveDate::from_ymd_opt(year, 1, 1)
+            });
+
+        let mut graphics = SeriesGraphics::default();
+        graphics.poster = available(details.poster)
+            .as_deref()
+            .and_then(|url| url.strip_prefix(IMAGE_URL))
+            .and_then(ImageV2::imdb);
+
+        let series = UpdateSeries {
+            id,
+            title: available(details.title).unwrap_or_default(),
+            language: None,
+            first_air_date,
+            overview: available(details.plot).unwrap_or_default(),
+            graphics,
+            remote_id,
+            content_rating: available(details.rated),
+            genres: available(details.genre)
+                .map(|genres| {
+                    genres
+                        .split(',')
+                        .map(|g| g.trim().to_owned())
+                        .filter(|g| !g.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default(),
+            air_time: None,
+        };
+
+        Ok(Some((series, remote_ids)))
+    }
+
+    /// Download the image at the given path.
+    pub(crate) async fn download_image_path(&self, path: &RelativePath) -> Result<Vec<u8>> {
+        let mut url = self.state.image_url.clone();
+
+        if let Ok(mut segments) = url.path_segments_mut() {
+            for c in path.components() {
+                segments.push(c.as_str());
+            }
+        }
+
+        let res = self.client.get(url).send().await?;
+
+        if !res.status().is_success() {
+            bail!("{path}: failed to download image: {}", res.status());
+        }
+
+        Ok(res.bytes().await?.to_vec())
+    }
+}
@@ -190,12 +190,48 @@ impl Client {
         Ok(output)
     }
 
+    /// Find a series or movie by its IMDb identifier.
+    pub(crate) async fn find_imdb(&self, imdb_id: &str) -> Result<Option<RemoteLink>> {
+        #[derive(Deserialize)]
+        struct Row {
+            id: u32,
+        }
+
+        #[derive(Deserialize)]
+        struct Found {
+            #[serde(default)]
+            tv_results: Vec<Row>,
+            #[serde(default)]
+            movie_results: Vec<Row>,
+        }
+
+        let res = self
+            .request_with_auth(Method::GET, &["find", imdb_id])
+            .await
+            .query(&[&("external_source", "imdb_id")])
+            .send()
+            .await?;
+
+        let found: Found = response("find", res).await?;
+
+        if let Some(row) = found.tv_results.first() {
+            return Ok(Some(RemoteLink::Series(RemoteId::Tmdb { id: row.id })));
+        }
+
+        if let Some(row) = found.movie_results.first() {
+            return Ok(Some(RemoteLink::Movie(RemoteId::Tmdb { id: row.id })));
+        }
+
+        Ok(None)
+    }
+
     /// Download series information.
     pub(crate) async fn series(
         &self,
         id: u32,
         lookup: impl common::LookupSeriesId,
         if_none_match: Option<&Etag>,
+        country: Option<&str>,
     ) -> Result<
         Option<(
             UpdateSeries,
@@ -224,6 +260,14 @@ impl Client {
             first_air_date: Option<NaiveDate>,
             #[serde(default)]
             seasons: Vec<SeasonDetails>,
+            #[serde(default)]
+            genres: Vec<Genre>,
+        }
+
+        #[derive(Deserialize)]
+        struct Genre {
+            #[serde(default)]
+            name: String,
         }
 
         #[derive(Deserialize)]
@@ -285,11 +329,26 @@ impl Client {
             .send()
             .await?;
 
-        let (external_ids, images) = tokio::try_join!(
+        let content_ratings = self
+            .request_with_auth(Method::GET, &["tv", &id.to_string(), "content_ratings"])
+            .await
+            .send()
+            .await?;
+
+        let (external_ids, images, content_ratings) = tokio::try_join!(
             response::<ExternalIds, _>(format!("tv/{id}/external_ids"), external_ids),
-            response::<Images, _>(format!("tv/{id}/images"), images)
+            response::<Images, _>(format!("tv/{id}/images"), images),
+            response::<ContentRatings, _>(format!("tv/{id}/content_ratings"), content_ratings)
         )?;
 
+        let content_rating = select_content_rating(
+            content_ratings
+                .results
+                .iter()
+                .map(|r| (r.iso_3166_1.as_str(), r.rating.as_str())),
+            country,
+        );
+
         let remote_id = RemoteId::Tmdb { id: details.id };
 
         let mut remote_ids = BTreeSet::from([remote_id]);
@@ -324,6 +383,15 @@ impl Client {
             overview: details.overview.unwrap_or_default(),
             graphics,
             remote_id,
+            content_rating,
+            genres: details
+                .genres
+                .into_iter()
+                .map(|g| g.name)
+                .filter(|name| !name.is_empty())
+                .collect(),
+            // NB: TMDb doesn't provide the time of day a series airs.
+            air_time: None,
         };
 
         let mut seasons = Vec::with_capacity(details.seasons.len());
@@ -355,63 +423,86 @@ impl Client {
     }
 
     /// Download episodes.
+    ///
+    /// Episode names are downloaded in `language` if specified, falling back
+    /// to names in `original_language` for episodes which haven't been
+    /// translated.
+    ///
+    /// Details for up to `concurrency` episodes are downloaded at the same
+    /// time.
     pub(crate) async fn download_episodes(
         &self,
         series_id: u32,
         season: SeasonNumber,
         language: Option<&str>,
+        original_language: Option<&str>,
+        concurrency: usize,
         lookup: impl common::LookupEpisodeId,
     ) -> Result<Vec<NewEpisode>> {
-        #[derive(Deserialize)]
-        struct Details {
-            #[serde(default)]
-            episodes: Vec<EpisodeDetail>,
-        }
+        use futures::{StreamExt, TryStreamExt};
 
         let season_number = match season {
             SeasonNumber::Specials => 0,
             SeasonNumber::Number(n) => n,
         };
 
-        let pair;
-
-        let query = match language {
-            Some(language) => {
-                pair = [("language", language)];
-                &pair[..]
-            }
-            None => &[],
-        };
-
-        let details = self
-            .request_with_auth(
-                Method::GET,
-                &[
-                    "tv",
-                    &series_id.to_string(),
-                    "season",
-                    &season_number.to_string(),
-                ],
-            )
-            .await
-            .query(query)
-            .send()
+        let mut details = self
+            .season_episodes(series_id, season_number, language.or(original_language))
             .await?;
 
-        let details: Details = response("tv/{id}/season/{number}", details).await?;
+        let untranslated = details
+            .iter()
+            .any(|e| !has_translated_name(e))
+            .then_some(original_language)
+            .flatten()
+            .filter(|original| language.is_some_and(|l| l != *original));
 
-        let mut episodes = Vec::with_capacity(details.episodes.len());
+        if let Some(original) = untranslated {
+            let originals = self
+                .season_episodes(series_id, season_number, Some(original))
+                .await?;
 
-        for e in details.episodes {
-            let remote_id = RemoteEpisodeId::Tmdb { id: e.id };
+            for e in details.iter_mut().filter(|e| !has_translated_name(e)) {
+                if let Some(o) = originals.iter().find(|o| o.id == e.id) {
+                    e.name.clone_from(&o.name);
+                }
+            }
+        }
 
-            let d = self
-                .download_episode_details(remote_id, series_id, season_number, e, &lookup)
-                .await?;
+        let mut episodes = Vec::with_capacity(details.len());
+
+        // NB: Buffering keeps downloads in the order of the episodes they
+        // belong to, and every request still goes through the rate limiter.
+        let downloads = futures::stream::iter(details)
+            .map(|e| {
+                let remote_id = RemoteEpisodeId::Tmdb { id: e.id };
+                self.download_episode_details(remote_id, series_id, season_number, e, &lookup)
+            })
+            .buffered(concurrency.max(1))
+            .try_collect::<Vec<_>>()
+            .await?;
 
+        for d in downloads {
             let mut graphics = EpisodeGraphics::default();
             graphics.filename = d.episode.still_path.as_deref().and_then(ImageV2::tmdb);
 
+            let mut directors = Vec::new();
+            let mut writers = Vec::new();
+
+            for crew in d.episode.crew {
+                let Some(name) = crew.name.filter(|s| !s.is_empty()) else {
+                    continue;
+                };
+
+                match crew.job.as_deref() {
+                    Some("Director") if !directors.contains(&name) => directors.push(name),
+                    Some("Writer" | "Teleplay" | "Story") if !writers.contains(&name) => {
+                        writers.push(name)
+                    }
+                    _ => {}
+                }
+            }
+
             let episode = Episode {
                 id: d.id,
                 name: d.episode.name,
@@ -423,6 +514,12 @@ impl Client {
                 compat_filename: None,
                 graphics,
                 remote_id: Some(d.remote_id),
+                directors,
+                writers,
+                kind: None,
+                added: None,
+                runtime: d.episode.runtime,
+                air_time: None,
             };
 
             episodes.push(NewEpisode {
@@ -434,6 +531,48 @@ impl Client {
         Ok(episodes)
     }
 
+    /// Download the episodes of a season in the given language.
+    async fn season_episodes(
+        &self,
+        series_id: u32,
+        season_number: u32,
+        language: Option<&str>,
+    ) -> Result<Vec<EpisodeDetail>> {
+        #[derive(Deserialize)]
+        struct Details {
+            #[serde(default)]
+            episodes: Vec<EpisodeDetail>,
+        }
+
+        let pair;
+
+        let query = match language {
+            Some(language) => {
+                pair = [("language", language)];
+                &pair[..]
+            }
+            None => &[],
+        };
+
+        let details = self
+            .request_with_auth(
+                Method::GET,
+                &[
+                    "tv",
+                    &series_id.to_string(),
+                    "season",
+                    &season_number.to_string(),
+                ],
+            )
+            .await
+            .query(query)
+            .send()
+            .await?;
+
+        let details: Details = response("tv/{id}/season/{number}", details).await?;
+        Ok(details.episodes)
+    }
+
     async fn download_episode_details(
         &self,
         remote_id: RemoteEpisodeId,
@@ -527,6 +666,7 @@ impl Client {
         id: u32,
         lookup: impl common::LookupMovieId,
         if_none_match: Option<&Etag>,
+        country: Option<&str>,
     ) -> Result<
         Option<(
             UpdateMovie,
@@ -552,6 +692,14 @@ impl Client {
             backdrop_path: Option<String>,
             #[serde(default)]
             release_date: Option<NaiveDate>,
+            #[serde(default)]
+            belongs_to_collection: Option<Collection>,
+        }
+
+        #[derive(Deserialize)]
+        struct Collection {
+            #[serde(default)]
+            name: Option<String>,
         }
 
         let mut details = self
@@ -639,6 +787,15 @@ impl Client {
             graphics.banners.extend(ImageV2::tmdb(&image.file_path));
         }
 
+        let content_rating = select_content_rating(
+            release_dates.results.iter().flat_map(|r| {
+                r.release_dates
+                    .iter()
+                    .map(|d| (r.iso_3166_1.as_str(), d.certification.as_str()))
+            }),
+            country,
+        );
+
         let release_dates = {
             let mut out = Vec::new();
 
@@ -677,6 +834,11 @@ impl Client {
             graphics,
             remote_id,
             release_dates,
+            collection: details
+                .belongs_to_collection
+                .and_then(|c| c.name)
+                .filter(|s| !s.is_empty()),
+            content_rating,
         };
 
         Ok(Some((series, remote_ids, last_etag, last_modified)))
@@ -704,6 +866,13 @@ impl Client {
     }
 }
 
+/// Test if an episode has a name, which is not the case if it hasn't been
+/// translated into the requested language. In which case it's either missing,
+/// null or empty.
+fn has_translated_name(e: &EpisodeDetail) -> bool {
+    e.name.as_deref().is_some_and(|name| !name.is_empty())
+}
+
 /// Converting a response from JSON.
 async fn response<T, W>(what: W, res: Response) -> Result<T>
 where
@@ -715,8 +884,15 @@ where
         W: fmt::Display + Send,
         T: DeserializeOwned,
     {
+        if res.status() == StatusCode::TOO_MANY_REQUESTS {
+            let retry_after = common::parse_retry_after(&res);
+            return Err(common::RateLimited { retry_after }.into());
+        }
+
         if !res.status().is_success() {
-            bail!("{}: {}", res.status(), res.text().await?);
+            let status = res.status();
+            let body = res.text().await?;
+            return Err(common::StatusError { status, body }.into());
         }
 
         let output = res.bytes().await?;
@@ -746,7 +922,6 @@ enum ReleaseType {
 #[derive(Debug, Deserialize)]
 struct ReleaseDate {
     #[serde(default)]
-    #[allow(unused)]
     certification: String,
     #[allow(unused)]
     descriptors: Vec<serde_json::Value>,
@@ -775,6 +950,20 @@ struct ReleaseDates {
     results: Vec<ReleaseDateResult>,
 }
 
+#[derive(Debug, Deserialize)]
+struct ContentRating {
+    #[serde(default)]
+    iso_3166_1: String,
+    #[serde(default)]
+    rating: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ContentRatings {
+    #[serde(default)]
+    results: Vec<ContentRating>,
+}
+
 #[derive(Default, Deserialize)]
 struct ExternalIds {
     imdb_id: Option<String>,
@@ -871,6 +1060,18 @@ struct EpisodeDetail {
     overview: Option<String>,
     #[serde(default)]
     still_path: Option<String>,
+    #[serde(default)]
+    runtime: Option<u32>,
+    #[serde(default)]
+    crew: Vec<Crew>,
+}
+
+#[derive(Deserialize)]
+struct Crew {
+    #[serde(default)]
+    job: Option<String>,
+    #[serde(default)]
+    name: Option<String>,
 }
 
 struct DownloadEpisode {
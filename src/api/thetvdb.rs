@@ -4,10 +4,10 @@ use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use anyhow::{anyhow, bail, Context, Result};
-use chrono::{DateTime, NaiveDate, Utc};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
 use leaky_bucket::RateLimiter;
 use relative_path::RelativePath;
-use reqwest::{Method, RequestBuilder, Response, Url};
+use reqwest::{header, Method, RequestBuilder, Response, StatusCode, Url};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
@@ -165,16 +165,21 @@ impl Client {
     }
 
     /// Download series information.
+    ///
+    /// Returns `None` if the series hasn't changed since `if_none_match`.
     pub(crate) async fn series(
         &self,
         id: u32,
         lookup: impl common::LookupSeriesId,
-    ) -> Result<(
-        UpdateSeries,
-        BTreeSet<RemoteId>,
-        Option<Etag>,
-        Option<DateTime<Utc>>,
-    )> {
+        if_none_match: Option<&Etag>,
+    ) -> Result<
+        Option<(
+            UpdateSeries,
+            BTreeSet<RemoteId>,
+            Option<Etag>,
+            Option<DateTime<Utc>>,
+        )>,
+    > {
         #[derive(Deserialize)]
         #[serde(rename_all = "camelCase")]
         #[allow(unused)]
@@ -200,12 +205,20 @@ impl Client {
             imdb_id: Option<String>,
         }
 
-        let res = self
+        let mut req = self
             .request_with_auth(Method::GET, &["series", &id.to_string()])
-            .await?
-            .send()
             .await?;
 
+        if let Some(etag) = if_none_match {
+            req = req.header(header::IF_NONE_MATCH, etag.as_ref());
+        }
+
+        let res = req.send().await?;
+
+        if res.status() == StatusCode::NOT_MODIFIED {
+            return Ok(None);
+        }
+
         let last_etag = common::parse_etag(&res);
         let last_modified = common::parse_last_modified(&res).context("last-modified header")?;
         let value = response::<Data<Value>>("series/{id}", res).await?.data;
@@ -238,19 +251,30 @@ impl Client {
             overview: value.overview.unwrap_or_default(),
             graphics,
             remote_id,
+            content_rating: None,
+            genres: Vec::new(),
+            air_time: value.airs_time.as_deref().and_then(parse_air_time),
         };
 
-        Ok((series, remote_ids, last_etag, last_modified))
+        Ok(Some((series, remote_ids, last_etag, last_modified)))
     }
 
-    /// Download all series episodes.
+    /// Download all series episodes along with the etag of each page.
+    ///
+    /// Returns `None` if none of the pages have changed since they were
+    /// downloaded with the etags in `if_none_match`.
     pub(crate) async fn series_episodes(
         &self,
         id: u32,
         lookup: impl common::LookupEpisodeId,
-    ) -> Result<Vec<NewEpisode>> {
+        if_none_match: &[Etag],
+    ) -> Result<Option<(Vec<NewEpisode>, Vec<Etag>)>> {
         let path = ["series", &id.to_string(), "episodes"];
 
+        if self.pages_not_modified(&path, if_none_match).await? {
+            return Ok(None);
+        }
+
         return self
             .paged_request("episode", &path, move |row: Row| {
                 let mut graphics = EpisodeGraphics::default();
@@ -284,6 +308,12 @@ impl Client {
                     compat_filename: None,
                     graphics,
                     remote_id: Some(remote_id),
+                    directors: Vec::new(),
+                    writers: Vec::new(),
+                    kind: None,
+                    added: None,
+                    runtime: None,
+                    air_time: None,
                 };
 
                 Ok(NewEpisode {
@@ -291,7 +321,8 @@ impl Client {
                     remote_ids,
                 })
             })
-            .await;
+            .await
+            .map(Some);
 
         #[derive(Debug, Deserialize)]
         #[serde(rename_all = "camelCase")]
@@ -316,13 +347,42 @@ impl Client {
         }
     }
 
-    /// Handle series pagination.
+    /// Test if none of the pages of a paged request have changed since they
+    /// were downloaded with the given etags.
+    async fn pages_not_modified<I>(&self, path: I, etags: &[Etag]) -> Result<bool>
+    where
+        I: Copy + IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        if etags.is_empty() {
+            return Ok(false);
+        }
+
+        for (index, etag) in etags.iter().enumerate() {
+            let mut req = self
+                .request_with_auth(Method::GET, path)
+                .await?
+                .header(header::IF_NONE_MATCH, etag.as_ref());
+
+            if index > 0 {
+                req = req.query(&[("page", &(index + 1).to_string())]);
+            }
+
+            if req.send().await?.status() != StatusCode::NOT_MODIFIED {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Handle series pagination, returning the etag of each page.
     async fn paged_request<T, U, M, I>(
         &self,
         thing: &'static str,
         path: I,
         mut map: M,
-    ) -> Result<Vec<U>>
+    ) -> Result<(Vec<U>, Vec<Etag>)>
     where
         T: DeserializeOwned + fmt::Debug,
         M: FnMut(T) -> Result<U>,
@@ -347,6 +407,8 @@ impl Client {
             .send()
             .await?;
 
+        let mut etags = vec![common::parse_etag(&res)];
+
         let mut data = response::<DataLinks<Vec<serde_json::Value>>>("paged", res).await?;
         let mut output = Vec::new();
 
@@ -379,10 +441,13 @@ impl Client {
                 .send()
                 .await?;
 
+            etags.push(common::parse_etag(&res));
             data = response("paged", res).await?;
         }
 
-        Ok(output)
+        // NB: Etags are only useful if every page has one.
+        let etags = etags.into_iter().collect::<Option<Vec<_>>>();
+        Ok((output, etags.unwrap_or_default()))
     }
 
     /// Search series result.
@@ -458,6 +523,16 @@ impl Client {
     }
 }
 
+/// Parse the time of day a series airs, which is commonly formatted like
+/// `9:00 PM` but is sometimes given in 24-hour time.
+fn parse_air_time(string: &str) -> Option<NaiveTime> {
+    let string = string.trim();
+
+    ["%I:%M %p", "%I:%M%p", "%H:%M"]
+        .into_iter()
+        .find_map(|format| NaiveTime::parse_from_str(string, format).ok())
+}
+
 /// Converting a response from JSON.
 async fn response<T>(what: &'static str, res: Response) -> Result<T>
 where
@@ -468,7 +543,9 @@ where
         T: DeserializeOwned,
     {
         if !res.status().is_success() {
-            bail!("{}: {}", res.status(), res.text().await?);
+            let status = res.status();
+            let body = res.text().await?;
+            return Err(common::StatusError { status, body }.into());
         }
 
         let output = res.bytes().await?;
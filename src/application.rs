@@ -1,10 +1,11 @@
 use std::time::Duration;
 
+use chrono::DateTime;
 use iced::advanced::image::Handle;
 use iced::window;
 use iced::{Command, Theme};
 
-use crate::assets::{Assets, ImageKey};
+use crate::assets::{Assets, ImageFailure, ImageKey};
 use crate::commands::{Commands, CommandsBuf};
 use crate::context::{Ctxt, CtxtRef};
 use crate::database::SeasonRef;
@@ -44,7 +45,8 @@ macro_rules! ctxt_ref {
 
 // Check for remote updates every two minutes.
 const UPDATE_TIMEOUT: u64 = 120;
-// Number of images to process in parallel.
+// Minimum number of images to load in each batch. Batches are made larger if
+// more images are allowed to load concurrently.
 const IMAGE_BATCH: usize = 10;
 
 #[derive(Debug, Clone)]
@@ -55,9 +57,11 @@ pub(crate) enum Message {
     Settings(page::settings::Message),
     Dashboard(page::dashboard::Message),
     WatchNext(page::watch_next::Message),
+    CatchUp(page::catch_up::Message),
     Search(page::search::Message),
     SeriesList(page::series_list::Message),
     MoviesList(page::movies_list::Message),
+    Collections(page::collections::Message),
     Series(page::series::Message),
     Movie(page::movie::Message),
     Season(page::season::Message),
@@ -76,27 +80,39 @@ pub(crate) enum Message {
     /// A scroll happened.
     Scroll(w::scrollable::Viewport),
     /// Images have been loaded in the background.
-    ImagesLoaded(Result<Vec<(ImageKey, Handle)>, ErrorInfo>),
+    ImagesLoaded(Result<Vec<(ImageKey, Result<Handle, ImageFailure>)>, ErrorInfo>),
     /// Update download queue with the given items.
     TaskUpdateDownloadQueue(Result<Option<TaskKind>, ErrorInfo>, Task),
     /// Task output of add series by remote.
     TaskSeriesDownloaded(Result<Option<NewSeries>, ErrorInfo>, Task),
+    /// Task output of re-resolving episode remotes.
+    TaskEpisodeRemotesResolved(Result<Option<NewSeries>, ErrorInfo>, Task),
     /// Task output of add movie by remote.
     TaskMovieDownloaded(Result<Option<NewMovie>, ErrorInfo>, Task),
     /// Queue processing.
     ProcessQueue(TimedOut, TaskId),
+    /// Dismiss notes about upgraded data.
+    DismissMigrationNotes,
+    /// Hide the confirmation that something was copied to the clipboard.
+    ClearCopied(TimedOut),
+    /// Undo the last watch or skip.
+    Undo,
+    /// A key was pressed which might be part of a shortcut.
+    Key(char),
 }
 
 /// Current page state.
 enum Current {
     Dashboard(page::Dashboard),
     WatchNext(page::WatchNext),
+    CatchUp(page::CatchUp),
     Settings(page::Settings),
     Search(page::Search),
     Series(page::Series),
     Movie(page::Movie),
     SeriesList(page::SeriesList),
     MoviesList(page::MoviesList),
+    Collections(page::Collections),
     Season(page::Season),
     Queue(page::Queue),
     Errors(page::Errors),
@@ -124,10 +140,14 @@ pub(crate) struct Application {
     update_timeout: Timeout,
     // Timeout until the next queue should wakeup.
     queue_timeout: Timeout,
+    // Timeout until the copied confirmation is hidden.
+    copied_timeout: Timeout,
     /// Image loader future being run.
     image_loader: Singleton,
     // Exit after save has been completed.
     exit_after_save: bool,
+    // `g` has been pressed, waiting for the key which picks a page.
+    go_to: bool,
     // Images to load.
     images: Vec<(ImageKey, ImageV2)>,
     /// The identifier used for the main scrollable.
@@ -147,7 +167,7 @@ impl iced::Application for Application {
     type Flags = Flags;
 
     fn new(flags: Self::Flags) -> (Self, Command<Self::Message>) {
-        let today = Utc::now().date_naive();
+        let today = flags.service.config().air_today(&Utc::now());
 
         let state = State::new(today);
         let current = Current::Dashboard(page::dashboard::Dashboard::new(&state, &flags.service));
@@ -163,8 +183,10 @@ impl iced::Application for Application {
             database_timeout: Timeout::default(),
             update_timeout: Timeout::default(),
             queue_timeout: Timeout::default(),
+            copied_timeout: Timeout::default(),
             image_loader: Singleton::default(),
             exit_after_save: false,
+            go_to: false,
             images: Vec::new(),
             scrollable_id: w::scrollable::Id::unique(),
             style: Style,
@@ -189,6 +211,7 @@ impl iced::Application for Application {
                     return format!("{BASE} - Dashboard");
                 }
                 Page::WatchNext(..) => return format!("{BASE} - Watch next"),
+                Page::CatchUp => return format!("{BASE} - Catch up"),
                 Page::Search(..) => {
                     return format!("{BASE} - Search");
                 }
@@ -198,6 +221,9 @@ impl iced::Application for Application {
                 Page::MoviesList => {
                     return format!("{BASE} - Movies overview");
                 }
+                Page::Collections => {
+                    return format!("{BASE} - Collections");
+                }
                 Page::Series(state) => {
                     if let Some(series) = self.service.series(&state.id) {
                         return format!("{BASE} - {}", series.title);
@@ -241,7 +267,11 @@ impl iced::Application for Application {
                 return self.commands.build();
             }
             (Message::Settings(message), Current::Settings(page), _) => {
-                page.update(ctxt!(self), message);
+                page.update(
+                    ctxt!(self),
+                    message,
+                    self.commands.by_ref().map(Message::Settings),
+                );
             }
             (Message::Dashboard(message), Current::Dashboard(page), _) => {
                 page.update(ctxt!(self), message);
@@ -261,20 +291,26 @@ impl iced::Application for Application {
                     self.commands.by_ref().map(Message::Search),
                 );
             }
+            (Message::CatchUp(message), Current::CatchUp(page), _) => {
+                page.update(ctxt!(self), message);
+            }
             (Message::SeriesList(message), Current::SeriesList(page), _) => {
                 page.update(ctxt!(self), message);
             }
             (Message::MoviesList(message), Current::MoviesList(page), _) => {
                 page.update(ctxt!(self), message);
             }
+            (Message::Collections(message), Current::Collections(page), _) => {
+                page.update(ctxt!(self), message);
+            }
             (Message::Series(message), Current::Series(page), _) => {
                 page.update(ctxt!(self), message);
             }
             (Message::Movie(message), Current::Movie(page), _) => {
                 page.update(ctxt!(self), message);
             }
-            (Message::Season(message), Current::Season(page), _) => {
-                page.update(ctxt!(self), message);
+            (Message::Season(message), Current::Season(page), Some(Page::Season(state))) => {
+                page.update(ctxt!(self), state, message);
             }
             (Message::Queue(message), Current::Queue(page), _) => {
                 page.update(
@@ -324,8 +360,9 @@ impl iced::Application for Application {
             }
             (Message::CheckForUpdates(TimedOut::TimedOut), _, _) => {
                 let now = Utc::now();
+                self.service.purge_removed_series(&now);
                 self.service.find_updates(&now);
-                let today = now.date_naive();
+                let today = self.service.config().air_today(&now);
 
                 if *self.state.today() != today {
                     self.state.set_today(today);
@@ -339,9 +376,8 @@ impl iced::Application for Application {
             }
             (Message::TaskUpdateDownloadQueue(result, task), _, _) => {
                 let now = Utc::now();
-                self.service.complete_task(&now, task);
 
-                match result {
+                match self.finish_task(&now, task, result) {
                     Ok(task) => {
                         if let Some(task) = task {
                             self.service.push_task(&now, task);
@@ -358,6 +394,58 @@ impl iced::Application for Application {
             (Message::History(relative), _, _) => {
                 self.history_mutations.navigate(relative);
             }
+            (Message::Key(key), current, page) => match (std::mem::take(&mut self.go_to), key) {
+                (false, 'g') => {
+                    self.go_to = true;
+                }
+                (true, 'd') => {
+                    self.history_mutations
+                        .push_history(&mut self.assets, Page::Dashboard);
+                }
+                (true, 's') => {
+                    self.history_mutations
+                        .push_history(&mut self.assets, Page::SeriesList);
+                }
+                (true, 'w') => {
+                    self.history_mutations.push_history(
+                        &mut self.assets,
+                        Page::WatchNext(page::watch_next::State::default()),
+                    );
+                }
+                (false, '/') => {
+                    if !matches!(page, Some(Page::Search(..))) {
+                        self.history_mutations.push_history(
+                            &mut self.assets,
+                            Page::Search(page::search::State::default()),
+                        );
+                    }
+
+                    self.commands
+                        .command(w::text_input::focus(w::text_input::Id::new(
+                            page::search::QUERY_ID,
+                        )));
+                }
+                (false, 'j') => {
+                    self.state.move_focus(1);
+                }
+                (false, 'k') => {
+                    self.state.move_focus(-1);
+                }
+                (false, 'w') => {
+                    if let (Current::WatchNext(current), Some(Page::WatchNext(state))) =
+                        (current, page)
+                    {
+                        current.watch_focused(ctxt!(self), state);
+                    }
+                }
+                _ => {}
+            },
+            (Message::Undo, _, _) => {
+                self.service.undo_last_action();
+            }
+            (Message::DismissMigrationNotes, _, _) => {
+                self.service.dismiss_migration_notes();
+            }
             (Message::Scroll(offset), _, _) => {
                 self.history.history_scroll(offset.relative_offset());
             }
@@ -373,13 +461,18 @@ impl iced::Application for Application {
 
                 self.image_loader.clear();
                 self.handle_image_loading();
+
+                // Tasks held back while images were loading can now run.
+                if !self.image_loader.is_set() {
+                    self.handle_process_queue(None);
+                }
+
                 return self.commands.build();
             }
             (Message::TaskSeriesDownloaded(result, task), _, _) => {
                 let now = Utc::now();
-                self.service.complete_task(&now, task);
 
-                match result {
+                match self.finish_task(&now, task, result) {
                     Ok(new_series) => {
                         if let Some(new_series) = new_series {
                             self.service.insert_series(&now, new_series);
@@ -390,11 +483,29 @@ impl iced::Application for Application {
                     }
                 }
             }
+            (Message::TaskEpisodeRemotesResolved(result, task), _, _) => {
+                let now = Utc::now();
+                let kind = task.kind.clone();
+
+                match self.finish_task(&now, task, result) {
+                    Ok(new_series) => {
+                        if let (
+                            Some(new_series),
+                            TaskKind::ResolveEpisodeRemotes { series_id, .. },
+                        ) = (new_series, kind)
+                        {
+                            self.service.resolve_episode_remotes(&series_id, new_series);
+                        }
+                    }
+                    Err(error) => {
+                        self.state.handle_error(error);
+                    }
+                }
+            }
             (Message::TaskMovieDownloaded(result, task), _, _) => {
                 let now = Utc::now();
-                self.service.complete_task(&now, task);
 
-                match result {
+                match self.finish_task(&now, task, result) {
                     Ok(new_movie) => {
                         if let Some(new_movie) = new_movie {
                             self.service.insert_movie(&now, new_movie);
@@ -408,9 +519,20 @@ impl iced::Application for Application {
             (Message::ProcessQueue(TimedOut::TimedOut, id), _, _) => {
                 self.handle_process_queue(Some(id));
             }
+            (Message::ClearCopied(TimedOut::TimedOut), _, _) => {
+                self.state.clear_copied();
+            }
             _ => {}
         };
 
+        if let Some(text) = self.state.take_clipboard() {
+            self.commands.command(iced::clipboard::write(text));
+            self.commands.perform(
+                self.copied_timeout.set(Duration::from_secs(3)),
+                Message::ClearCopied,
+            );
+        }
+
         if self.service.has_changes() && !self.exit_after_save {
             self.commands.perform(
                 self.database_timeout.set(Duration::from_secs(5)),
@@ -419,17 +541,20 @@ impl iced::Application for Application {
         }
 
         if let Some((page, scroll)) = self.history.apply_mutation(&mut self.history_mutations) {
+            self.state.reset_focus();
             self.current = match page {
                 Page::Dashboard => {
                     Current::Dashboard(page::Dashboard::new(&self.state, &self.service))
                 }
                 Page::WatchNext(..) => Current::WatchNext(page::WatchNext::default()),
+                Page::CatchUp => Current::CatchUp(page::CatchUp::default()),
                 Page::Search(..) => Current::Search(page::Search::default()),
                 Page::SeriesList => Current::SeriesList(page::SeriesList::default()),
                 Page::MoviesList => Current::MoviesList(page::MoviesList::default()),
+                Page::Collections => Current::Collections(page::Collections::default()),
                 Page::Series(state) => Current::Series(page::Series::new(state)),
                 Page::Movie(state) => Current::Movie(page::Movie::new(ctxt_ref!(self), state)),
-                Page::Settings => Current::Settings(page::Settings),
+                Page::Settings => Current::Settings(page::Settings::default()),
                 Page::Season(state) => Current::Season(page::Season::new(state)),
                 Page::Queue(..) => {
                     let page = page::Queue::new(self.commands.by_ref().map(Message::Queue));
@@ -455,7 +580,7 @@ impl iced::Application for Application {
 
     #[inline]
     fn subscription(&self) -> iced::Subscription<Self::Message> {
-        use iced::{event, mouse, Event};
+        use iced::{event, keyboard, mouse, Event};
         return event::listen().map(handle_event);
 
         fn handle_event(event: Event) -> Message {
@@ -468,6 +593,22 @@ impl iced::Application for Application {
                     mouse::Button::Other(2) => Message::History(1),
                     _ => Message::Ignore,
                 },
+                Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. }) => match key {
+                    keyboard::Key::Character(c) if modifiers.command() => match c.as_str() {
+                        "z" => Message::Undo,
+                        _ => Message::Ignore,
+                    },
+                    keyboard::Key::Character(c) if !modifiers.alt() && !modifiers.logo() => {
+                        let mut chars = c.chars();
+
+                        match (chars.next(), chars.next()) {
+                            (Some(c), None) => Message::Key(c),
+                            _ => Message::Ignore,
+                        }
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::Backspace) => Message::History(-1),
+                    _ => Message::Ignore,
+                },
                 _ => Message::Ignore,
             }
         }
@@ -487,6 +628,13 @@ impl iced::Application for Application {
             || Page::Dashboard,
         ));
 
+        top_menu = top_menu.push(menu_item(
+            page,
+            w::text("Catch up"),
+            |p| matches!(p, Page::CatchUp),
+            || Page::CatchUp,
+        ));
+
         top_menu = top_menu.push(menu_item(
             page,
             w::text("Series"),
@@ -501,6 +649,13 @@ impl iced::Application for Application {
             || Page::MoviesList,
         ));
 
+        top_menu = top_menu.push(menu_item(
+            page,
+            w::text("Collections"),
+            |p| matches!(p, Page::Collections),
+            || Page::Collections,
+        ));
+
         top_menu = top_menu.push(menu_item(
             page,
             w::text("Search"),
@@ -544,7 +699,9 @@ impl iced::Application for Application {
 
                 menu = menu.push(sub_menu.spacing(GAP));
             }
-            Page::Season(page::season::State { series_id, season }) => {
+            Page::Season(page::season::State {
+                series_id, season, ..
+            }) => {
                 let mut sub_menu = w::Row::new();
 
                 if let Some(series) = self.service.series(series_id) {
@@ -599,6 +756,26 @@ impl iced::Application for Application {
             }
         };
 
+        let notes = self.service.migration_notes();
+
+        if !notes.is_empty() {
+            let mut column =
+                w::Column::new().push(w::text("Your data has been upgraded").size(SUBTITLE_SIZE));
+
+            for note in notes {
+                column = column.push(w::text(note).size(SMALL_SIZE));
+            }
+
+            column = column.push(
+                w::button(w::text("Dismiss").size(SMALL_SIZE))
+                    .style(theme::Button::Secondary)
+                    .on_press(Message::DismissMigrationNotes),
+            );
+
+            window = window.push(w::horizontal_rule(1));
+            window = window.push(column.spacing(SPACE).padding(GAP).width(Length::Fill));
+        }
+
         window = window.push(w::horizontal_rule(1));
         window = window.push(
             w::scrollable(page)
@@ -616,6 +793,11 @@ impl iced::Application for Application {
             any = true;
         }
 
+        if self.state.is_copied() {
+            status_bar = status_bar.push(w::text("Copied link to clipboard").size(SMALL_SIZE));
+            any = true;
+        }
+
         status_bar = status_bar.push(w::Space::new(Length::Fill, Length::Shrink));
 
         let errors = self.state.errors().len();
@@ -687,6 +869,9 @@ impl Application {
                     self.commands.by_ref().map(Message::Search),
                 );
             }
+            (Current::CatchUp(page), _) => {
+                page.prepare(ctxt!(self));
+            }
             (Current::SeriesList(page), _) => {
                 page.prepare(ctxt!(self));
             }
@@ -716,7 +901,9 @@ impl Application {
 
     /// Handle image loading.
     fn handle_image_loading(&mut self) {
-        fn translate(value: Option<Result<Vec<(ImageKey, Handle)>>>) -> Message {
+        fn translate(
+            value: Option<Result<Vec<(ImageKey, Result<Handle, ImageFailure>)>>>,
+        ) -> Message {
             match value {
                 Some(Ok(value)) => Message::ImagesLoaded(Ok(value)),
                 None => Message::ImagesLoaded(Ok(Vec::new())),
@@ -730,7 +917,9 @@ impl Application {
 
         self.images.clear();
 
-        while self.images.len() < IMAGE_BATCH {
+        let batch = IMAGE_BATCH.max(self.service.config().image_concurrency());
+
+        while self.images.len() < batch {
             let Some((key, image)) = self.assets.next_image() else {
                 break;
             };
@@ -749,11 +938,51 @@ impl Application {
         self.commands.perform(future, translate);
     }
 
+    /// Finish a task with its result.
+    ///
+    /// Tasks which failed because a remote is rate limiting us, or which have
+    /// attempts left, are put back in the queue instead of being completed, in
+    /// which case nothing is returned.
+    fn finish_task<T>(
+        &mut self,
+        now: &DateTime<Utc>,
+        task: Task,
+        result: Result<Option<T>, ErrorInfo>,
+    ) -> Result<Option<T>, ErrorInfo> {
+        if let Err(ErrorInfo {
+            retry_after: Some(seconds),
+            ..
+        }) = &result
+        {
+            self.service.retry_task_after(now, task, *seconds);
+            return Ok(None);
+        }
+
+        if matches!(&result, Err(e) if e.transient) && self.service.should_retry_task(&task) {
+            self.service.retry_failed_task(now, task);
+            return Ok(None);
+        }
+
+        self.service.complete_task(now, task);
+        result
+    }
+
     /// Handle process queue.
     fn handle_process_queue(&mut self, timed_out: Option<TaskId>) {
         let now = Utc::now();
 
-        while let Some(task) = self.service.next_task(&now, timed_out) {
+        // NB: Images for the current page take precedence, so fewer tasks are
+        // allowed to run while they are loading.
+        let concurrency = self
+            .service
+            .config()
+            .task_concurrency(self.image_loader.is_set());
+
+        while self.service.running_tasks().len() < concurrency {
+            let Some(task) = self.service.next_task(&now, timed_out) else {
+                break;
+            };
+
             tracing::trace!("Running task {}", task.id);
 
             match &task.kind {
@@ -796,12 +1025,26 @@ impl Application {
                         },
                     );
                 }
+                TaskKind::ResolveEpisodeRemotes {
+                    series_id,
+                    remote_id,
+                } => {
+                    self.commands.perform(
+                        ctxt_ref!(self).download_series_by_id(series_id, remote_id, true),
+                        move |result| {
+                            Message::TaskEpisodeRemotesResolved(
+                                result.map_err(Into::into),
+                                task.clone(),
+                            )
+                        },
+                    );
+                }
                 TaskKind::DownloadSeriesByRemoteId { remote_id } => {
                     if self.service.is_series_by_remote(remote_id) {
                         self.service.complete_task(&now, task);
                     } else {
                         self.commands.perform(
-                            self.service.download_series(remote_id, None, None),
+                            self.service.download_series(remote_id, None, &[], None),
                             move |result| {
                                 Message::TaskSeriesDownloaded(
                                     result.map_err(Into::into),
@@ -829,6 +1072,11 @@ impl Application {
             }
         }
 
+        // Once a running task completes the queue is processed again.
+        if self.service.running_tasks().len() >= concurrency {
+            return;
+        }
+
         let now = Utc::now();
 
         if let Some((seconds, id)) = self.service.next_task_sleep(&now) {
@@ -841,7 +1089,7 @@ impl Application {
         }
     }
 
-    fn render_page(&self) -> Result<Element<'static, Message>> {
+    fn render_page(&self) -> Result<Element<'_, Message>> {
         let page = match (&self.current, self.history.page()) {
             (Current::Dashboard(page), _) => page.view(ctxt_ref!(self)).map(Message::Dashboard),
             (Current::WatchNext(page), Some(Page::WatchNext(state))) => {
@@ -850,8 +1098,10 @@ impl Application {
             (Current::Search(page), Some(Page::Search(state))) => {
                 page.view(ctxt_ref!(self), state).map(Message::Search)
             }
+            (Current::CatchUp(page), _) => page.view(ctxt_ref!(self)).map(Message::CatchUp),
             (Current::SeriesList(page), _) => page.view(ctxt_ref!(self)).map(Message::SeriesList),
             (Current::MoviesList(page), _) => page.view(ctxt_ref!(self)).map(Message::MoviesList),
+            (Current::Collections(page), _) => page.view(ctxt_ref!(self)).map(Message::Collections),
             (Current::Series(page), Some(Page::Series(series_id))) => {
                 page.view(ctxt_ref!(self), series_id)?.map(Message::Series)
             }
@@ -883,14 +1133,20 @@ impl Application {
 
         let mut title = w::Row::new().push(title.size(SUB_MENU_SIZE));
 
-        if let Some(p) = watched.saturating_mul(100).checked_div(total) {
+        let ignored = self.service.is_ignored_season(series_id, &season.number);
+
+        if let Some(p) = watched
+            .saturating_mul(100)
+            .checked_div(total)
+            .filter(|_| !ignored)
+        {
             title = title.push(w::text(format_args!(" ({p}%)")).size(SUB_MENU_SIZE));
         }
 
         menu_item(
             page,
             title,
-            |p| matches!(p, Page::Season(page::season::State { series_id: a, season: b }) if *a == *series_id && *b == season.number),
+            |p| matches!(p, Page::Season(page::season::State { series_id: a, season: b, .. }) if *a == *series_id && *b == season.number),
             || page::season::page(*series_id, season.number),
         )
     }
@@ -1,4 +1,8 @@
+mod placeholder;
+
+use std::cell::RefCell;
 use std::collections::{HashMap, HashSet, VecDeque};
+use std::time::{Duration, Instant};
 
 use iced::advanced::image::Handle;
 use iced::Theme;
@@ -11,6 +15,12 @@ static MISSING_POSTER_LIGHT: &[u8] = include_bytes!("../assets/missing_poster_li
 static MISSING_BANNER: &[u8] = include_bytes!("../assets/missing_banner.png");
 static MISSING_SCRENCAP: &[u8] = include_bytes!("../assets/missing_screencap.png");
 
+/// Delay before an image which failed to load is retried, doubled with every
+/// failed attempt.
+const RETRY_DELAY: Duration = Duration::from_secs(30);
+/// Number of attempts at loading an image before giving up on it.
+const MAX_ATTEMPTS: u32 = 6;
+
 /// They key identifying an image.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub(crate) struct ImageKey {
@@ -18,6 +28,21 @@ pub(crate) struct ImageKey {
     pub(crate) hint: Option<ImageHint>,
 }
 
+/// Why an image failed to load.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ImageFailure {
+    /// The image can never be loaded, such as when its data can't be decoded.
+    pub(crate) permanent: bool,
+}
+
+/// An image which failed to load.
+struct Failed {
+    /// Number of failed attempts.
+    attempts: u32,
+    /// When the image should be retried, if ever.
+    retry_at: Option<Instant>,
+}
+
 /// Keeping track of assets that needs to be stored in-memory or loaded from the
 /// filesystem.
 pub(crate) struct Assets {
@@ -38,6 +63,10 @@ pub(crate) struct Assets {
     images: HashMap<ImageKey, Handle>,
     /// Assets to remove.
     to_remove: HashSet<ImageKey>,
+    /// Images which failed to load.
+    failed: HashMap<ImageKey, Failed>,
+    /// Generated placeholder posters by initials and if they are dark.
+    placeholders: RefCell<HashMap<(String, bool), Handle>>,
 }
 
 impl Assets {
@@ -57,6 +86,8 @@ impl Assets {
             marked: Vec::new(),
             images: HashMap::new(),
             to_remove: HashSet::new(),
+            failed: HashMap::new(),
+            placeholders: RefCell::new(HashMap::new()),
         }
     }
 
@@ -131,27 +162,97 @@ impl Assets {
             self.clear = false;
         }
 
+        let now = Instant::now();
+
         for (key, image) in self.marked.drain(..) {
-            if !self.images.contains_key(&key) {
-                self.image_queue.push_back((key, image));
+            if self.images.contains_key(&key) {
+                continue;
+            }
+
+            // Failed images are only retried once their backoff has expired.
+            if let Some(failed) = self.failed.get(&key) {
+                if !failed.retry_at.is_some_and(|at| at <= now) {
+                    continue;
+                }
             }
+
+            self.image_queue.push_back((key, image));
         }
 
         self.marked.clear();
     }
 
     /// Insert loaded images.
-    pub(crate) fn insert_images(&mut self, loaded: Vec<(ImageKey, Handle)>) {
-        for (id, handle) in loaded {
-            self.images.insert(id, handle);
+    pub(crate) fn insert_images(&mut self, loaded: Vec<(ImageKey, Result<Handle, ImageFailure>)>) {
+        let now = Instant::now();
+
+        for (key, result) in loaded {
+            match result {
+                Ok(handle) => {
+                    self.failed.remove(&key);
+                    self.images.insert(key, handle);
+                }
+                Err(failure) => {
+                    let failed = self.failed.entry(key).or_insert(Failed {
+                        attempts: 0,
+                        retry_at: None,
+                    });
+
+                    failed.attempts += 1;
+
+                    failed.retry_at = if failure.permanent || failed.attempts >= MAX_ATTEMPTS {
+                        tracing::warn!(?key, "Giving up on loading image");
+                        None
+                    } else {
+                        Some(now + RETRY_DELAY * 2u32.pow(failed.attempts - 1))
+                    };
+                }
+            }
         }
     }
 
+    /// Test if the given image has failed to load.
+    pub(crate) fn is_failed(&self, id: &ImageV2, hint: ImageHint) -> bool {
+        let key = ImageKey {
+            id: id.hash(),
+            hint: Some(hint),
+        };
+
+        self.failed.contains_key(&key)
+    }
+
+    /// Get a placeholder poster showing the initials of the given title.
+    pub(crate) fn placeholder_poster(&self, theme: &Theme, title: &str) -> Handle {
+        let dark = theme.extended_palette().is_dark;
+        let key = (placeholder::initials(title), dark);
+
+        if let Some(handle) = self.placeholders.borrow().get(&key) {
+            return handle.clone();
+        }
+
+        let colors = if dark {
+            placeholder::Colors {
+                background: [0x2e, 0x2e, 0x2e],
+                foreground: [0x9a, 0x9a, 0x9a],
+            }
+        } else {
+            placeholder::Colors {
+                background: [0xdd, 0xdd, 0xdd],
+                foreground: [0x70, 0x70, 0x70],
+            }
+        };
+
+        let handle = placeholder::render(&key.0, &colors);
+        self.placeholders.borrow_mut().insert(key, handle.clone());
+        handle
+    }
+
     /// Get a placeholder image for a missing poster.
     pub(crate) fn missing_poster(&self, theme: &Theme) -> Handle {
-        match theme {
-            Theme::Dark => self.missing_poster_dark.clone(),
-            _ => self.missing_poster_light.clone(),
+        if theme.extended_palette().is_dark {
+            self.missing_poster_dark.clone()
+        } else {
+            self.missing_poster_light.clone()
         }
     }
 
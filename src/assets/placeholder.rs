@@ -0,0 +1,112 @@
+use iced::advanced::image::Handle;
+
+/// Dimensions of a placeholder poster.
+const WIDTH: usize = 200;
+const HEIGHT: usize = 300;
+/// Size in pixels of a single glyph dot.
+const SCALE: usize = 12;
+/// Glyph dimensions in dots.
+const GLYPH_WIDTH: usize = 5;
+const GLYPH_HEIGHT: usize = 7;
+
+/// Colors of a placeholder poster.
+pub(super) struct Colors {
+    pub(super) background: [u8; 3],
+    pub(super) foreground: [u8; 3],
+}
+
+/// Get the initials of a title, such as `TO` for `The Office`.
+pub(super) fn initials(title: &str) -> String {
+    title
+        .split_whitespace()
+        .filter_map(|word| word.chars().next())
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_uppercase())
+        .take(2)
+        .collect()
+}
+
+/// Render a placeholder poster with the given initials.
+pub(super) fn render(initials: &str, colors: &Colors) -> Handle {
+    let mut pixels = Vec::with_capacity(WIDTH * HEIGHT * 4);
+
+    for _ in 0..WIDTH * HEIGHT {
+        pixels.extend_from_slice(&colors.background);
+        pixels.push(0xff);
+    }
+
+    let glyphs = initials.chars().filter_map(glyph).collect::<Vec<_>>();
+
+    let advance = (GLYPH_WIDTH + 1) * SCALE;
+    let width = (glyphs.len() * advance).saturating_sub(SCALE);
+    let left = WIDTH.saturating_sub(width) / 2;
+    let top = HEIGHT.saturating_sub(GLYPH_HEIGHT * SCALE) / 2;
+
+    for (n, rows) in glyphs.iter().enumerate() {
+        for (y, row) in rows.iter().enumerate() {
+            for x in 0..GLYPH_WIDTH {
+                if row & (1 << (GLYPH_WIDTH - 1 - x)) == 0 {
+                    continue;
+                }
+
+                let px = left + n * advance + x * SCALE;
+                let py = top + y * SCALE;
+
+                for y in py..(py + SCALE).min(HEIGHT) {
+                    for x in px..(px + SCALE).min(WIDTH) {
+                        let at = (y * WIDTH + x) * 4;
+                        pixels[at..at + 3].copy_from_slice(&colors.foreground);
+                    }
+                }
+            }
+        }
+    }
+
+    Handle::from_pixels(WIDTH as u32, HEIGHT as u32, pixels)
+}
+
+/// Get the dots making up the glyph for the given character, one row per
+/// element.
+fn glyph(c: char) -> Option<[u8; GLYPH_HEIGHT]> {
+    let rows = match c {
+        'A' => [0x0e, 0x11, 0x11, 0x1f, 0x11, 0x11, 0x11],
+        'B' => [0x1e, 0x11, 0x11, 0x1e, 0x11, 0x11, 0x1e],
+        'C' => [0x0e, 0x11, 0x10, 0x10, 0x10, 0x11, 0x0e],
+        'D' => [0x1e, 0x11, 0x11, 0x11, 0x11, 0x11, 0x1e],
+        'E' => [0x1f, 0x10, 0x10, 0x1e, 0x10, 0x10, 0x1f],
+        'F' => [0x1f, 0x10, 0x10, 0x1e, 0x10, 0x10, 0x10],
+        'G' => [0x0e, 0x11, 0x10, 0x17, 0x11, 0x11, 0x0f],
+        'H' => [0x11, 0x11, 0x11, 0x1f, 0x11, 0x11, 0x11],
+        'I' => [0x0e, 0x04, 0x04, 0x04, 0x04, 0x04, 0x0e],
+        'J' => [0x07, 0x02, 0x02, 0x02, 0x02, 0x12, 0x0c],
+        'K' => [0x11, 0x12, 0x14, 0x18, 0x14, 0x12, 0x11],
+        'L' => [0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x1f],
+        'M' => [0x11, 0x1b, 0x15, 0x15, 0x11, 0x11, 0x11],
+        'N' => [0x11, 0x11, 0x19, 0x15, 0x13, 0x11, 0x11],
+        'O' => [0x0e, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0e],
+        'P' => [0x1e, 0x11, 0x11, 0x1e, 0x10, 0x10, 0x10],
+        'Q' => [0x0e, 0x11, 0x11, 0x11, 0x15, 0x12, 0x0d],
+        'R' => [0x1e, 0x11, 0x11, 0x1e, 0x14, 0x12, 0x11],
+        'S' => [0x0f, 0x10, 0x10, 0x0e, 0x01, 0x01, 0x1e],
+        'T' => [0x1f, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04],
+        'U' => [0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0e],
+        'V' => [0x11, 0x11, 0x11, 0x11, 0x11, 0x0a, 0x04],
+        'W' => [0x11, 0x11, 0x11, 0x15, 0x15, 0x15, 0x0a],
+        'X' => [0x11, 0x11, 0x0a, 0x04, 0x0a, 0x11, 0x11],
+        'Y' => [0x11, 0x11, 0x0a, 0x04, 0x04, 0x04, 0x04],
+        'Z' => [0x1f, 0x01, 0x02, 0x04, 0x08, 0x10, 0x1f],
+        '0' => [0x0e, 0x11, 0x13, 0x15, 0x19, 0x11, 0x0e],
+        '1' => [0x04, 0x0c, 0x04, 0x04, 0x04, 0x04, 0x0e],
+        '2' => [0x0e, 0x11, 0x01, 0x02, 0x04, 0x08, 0x1f],
+        '3' => [0x1f, 0x02, 0x04, 0x02, 0x01, 0x11, 0x0e],
+        '4' => [0x02, 0x06, 0x0a, 0x12, 0x1f, 0x02, 0x02],
+        '5' => [0x1f, 0x10, 0x1e, 0x01, 0x01, 0x11, 0x0e],
+        '6' => [0x06, 0x08, 0x10, 0x1e, 0x11, 0x11, 0x0e],
+        '7' => [0x1f, 0x01, 0x02, 0x04, 0x08, 0x08, 0x08],
+        '8' => [0x0e, 0x11, 0x11, 0x0e, 0x11, 0x11, 0x0e],
+        '9' => [0x0e, 0x11, 0x11, 0x0f, 0x01, 0x02, 0x0c],
+        _ => return None,
+    };
+
+    Some(rows)
+}
@@ -1,15 +1,21 @@
+use std::collections::{BTreeMap, HashSet};
 use std::fmt;
+use std::fs;
 use std::future::Future;
 use std::io;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::pin::Pin;
 
-use anyhow::{bail, Result};
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
 use iced::advanced::image::Handle;
 use image_rs::imageops::FilterType;
 use image_rs::{DynamicImage, GenericImageView};
+use parking_lot::Mutex;
 use relative_path::RelativePath;
+use serde::{Deserialize, Serialize};
 
+use crate::api::omdb;
 use crate::api::themoviedb;
 use crate::api::thetvdb;
 use crate::model::{ImageExt, ImageHash};
@@ -52,6 +58,18 @@ impl CacheClient<RelativePath> for themoviedb::Client {
     }
 }
 
+impl CacheClient<RelativePath> for omdb::Client {
+    #[inline]
+    fn download_image(
+        &self,
+        path: &RelativePath,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<u8>>> + Send + 'static>> {
+        let client = self.clone();
+        let path: Box<RelativePath> = path.into();
+        Box::pin(async move { omdb::Client::download_image_path(&client, &path).await })
+    }
+}
+
 impl CacheClient<RelativePath> for thetvdb::Client {
     #[inline]
     fn download_image(
@@ -79,10 +97,225 @@ impl CacheId for RelativePath {
     }
 }
 
+/// Name of the index file in the images directory.
+const INDEX: &str = "index.json";
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct IndexEntry {
+    size: u64,
+    accessed: DateTime<Utc>,
+}
+
+/// Index of cached images, keeping track of their sizes and when they were
+/// last used so that the least recently used ones can be evicted.
+#[derive(Debug, Default)]
+pub(crate) struct Index {
+    entries: BTreeMap<String, IndexEntry>,
+    modified: bool,
+}
+
+impl Index {
+    /// Load the index for the given images directory.
+    ///
+    /// The directory is scanned so that the index reflects which files are
+    /// actually present. Files which are missing from the index are
+    /// considered to have last been used when they were modified.
+    pub(crate) fn load(dir: &Path) -> Result<Self> {
+        let path = dir.join(INDEX);
+
+        let mut stored = match fs::read(&path) {
+            Ok(bytes) => match serde_json::from_slice::<BTreeMap<String, IndexEntry>>(&bytes) {
+                Ok(entries) => entries,
+                Err(error) => {
+                    tracing::warn!("{}: Ignoring broken index: {error}", path.display());
+                    BTreeMap::new()
+                }
+            },
+            Err(e) if e.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(e).context(path.display().to_string()),
+        };
+
+        let mut entries = BTreeMap::new();
+
+        for e in fs::read_dir(dir)? {
+            let e = e?;
+            let m = e.metadata()?;
+
+            if !m.is_file() {
+                continue;
+            }
+
+            let Ok(name) = e.file_name().into_string() else {
+                continue;
+            };
+
+            if name == INDEX {
+                continue;
+            }
+
+            let accessed = match stored.remove(&name) {
+                Some(entry) => entry.accessed,
+                None => m
+                    .modified()
+                    .map(DateTime::from)
+                    .unwrap_or_else(|_| Utc::now()),
+            };
+
+            entries.insert(
+                name,
+                IndexEntry {
+                    size: m.len(),
+                    accessed,
+                },
+            );
+        }
+
+        Ok(Self {
+            entries,
+            modified: false,
+        })
+    }
+
+    /// Mark the given image as used.
+    fn touch(&mut self, name: String, size: u64) {
+        let accessed = Utc::now();
+        self.entries.insert(name, IndexEntry { size, accessed });
+        self.modified = true;
+    }
+
+    /// Evict the least recently used images until the cache fits within the
+    /// given number of bytes, returning the names of the files to remove.
+    pub(crate) fn evict(&mut self, max_bytes: u64) -> Vec<String> {
+        let mut total = self.entries.values().map(|e| e.size).sum::<u64>();
+
+        if total <= max_bytes {
+            return Vec::new();
+        }
+
+        let mut by_access = self
+            .entries
+            .iter()
+            .map(|(name, e)| (e.accessed, e.size, name.clone()))
+            .collect::<Vec<_>>();
+
+        by_access.sort();
+
+        let mut evicted = Vec::new();
+
+        for (_, size, name) in by_access {
+            if total <= max_bytes {
+                break;
+            }
+
+            total = total.saturating_sub(size);
+            self.entries.remove(&name);
+            evicted.push(name);
+        }
+
+        self.modified = true;
+        evicted
+    }
+
+    /// Remove the given image from the index.
+    fn remove(&mut self, name: &str) {
+        self.modified |= self.entries.remove(name).is_some();
+    }
+
+    /// Serialize the index if it has been modified.
+    pub(crate) fn take_modified(&mut self) -> Result<Option<Vec<u8>>> {
+        if !std::mem::take(&mut self.modified) {
+            return Ok(None);
+        }
+
+        Ok(Some(serde_json::to_vec(&self.entries)?))
+    }
+}
+
+/// Remove evicted images and store the image index.
+pub(crate) async fn save_index(
+    dir: PathBuf,
+    evicted: Vec<String>,
+    index: Option<Vec<u8>>,
+) -> Result<()> {
+    for name in evicted {
+        tracing::trace!("Evicting: {name}");
+
+        match tokio::fs::remove_file(dir.join(&name)).await {
+            Ok(()) => {}
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e).context(name),
+        }
+    }
+
+    if let Some(index) = index {
+        tokio::fs::write(dir.join(INDEX), index).await?;
+    }
+
+    Ok(())
+}
+
+/// Remove cached images whose hash isn't in the `referenced` set, returning
+/// the number of files removed and how many bytes they used.
+///
+/// Files whose names don't start with an image hash are left alone. If
+/// `dry_run` is set, nothing is removed but the files which would be are
+/// still counted.
+pub(crate) fn purge(
+    dir: &Path,
+    index: &Mutex<Index>,
+    referenced: &HashSet<u128>,
+    dry_run: bool,
+) -> Result<(usize, u64)> {
+    let mut count = 0;
+    let mut bytes = 0;
+
+    for e in fs::read_dir(dir).with_context(|| dir.display().to_string())? {
+        let e = e?;
+        let m = e.metadata()?;
+
+        if !m.is_file() {
+            continue;
+        }
+
+        let Ok(name) = e.file_name().into_string() else {
+            continue;
+        };
+
+        let Some(hash) = name
+            .get(..32)
+            .and_then(|hash| u128::from_str_radix(hash, 16).ok())
+        else {
+            continue;
+        };
+
+        if referenced.contains(&hash) {
+            continue;
+        }
+
+        tracing::trace!("Purging: {name}");
+
+        if !dry_run {
+            match fs::remove_file(e.path()) {
+                Ok(()) => {}
+                Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(e).context(name),
+            }
+
+            index.lock().remove(&name);
+        }
+
+        count += 1;
+        bytes += m.len();
+    }
+
+    Ok((count, bytes))
+}
+
 /// Helper to load a cached image, or download it using the provided client if
 /// needed.
 pub(crate) async fn image<C, I>(
     path: &Path,
+    index: &Mutex<Index>,
     client: &C,
     id: &I,
     hash: ImageHash,
@@ -93,31 +326,23 @@ where
     I: ?Sized + fmt::Display + CacheId,
 {
     use std::io::Cursor;
-    use tokio::fs;
 
     let format = match id.ext() {
         ImageExt::Jpg => image_rs::ImageFormat::Jpeg,
         ext => bail!("Unsupported image format: {ext:?}"),
     };
 
-    let (path, hint) = match hint {
-        Some(hint) => {
-            let resized_path = path.join(format!(
-                "{:032x}-{hint}.{ext}",
-                hash.as_u128(),
-                ext = id.ext()
-            ));
-            (resized_path, Some(hint))
-        }
-        None => {
-            let original_path = path.join(format!("{:032x}.{ext}", hash.as_u128(), ext = id.ext()));
-            (original_path, None)
-        }
+    let name = match hint {
+        Some(hint) => format!("{:032x}-{hint}.{ext}", hash.as_u128(), ext = id.ext()),
+        None => format!("{:032x}.{ext}", hash.as_u128(), ext = id.ext()),
     };
 
-    match fs::read(&path).await {
+    let path = path.join(&name);
+
+    match tokio::fs::read(&path).await {
         Ok(data) => {
             tracing::trace!(path = path.display().to_string(), "Reading from cache");
+            index.lock().touch(name, data.len() as u64);
             let image = image_rs::load_from_memory_with_format(&data, format)?;
             let (width, height) = image.dimensions();
             let pixels = image.to_rgba8();
@@ -151,7 +376,10 @@ where
 
     let mut buf = Cursor::new(Vec::with_capacity(1024));
     image.write_to(&mut buf, format)?;
-    fs::write(&path, buf.into_inner()).await?;
+    let data = buf.into_inner();
+    let size = data.len() as u64;
+    tokio::fs::write(&path, data).await?;
+    index.lock().touch(name, size);
 
     let (width, height) = image.dimensions();
     let pixels = image.to_rgba8();
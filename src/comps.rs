@@ -35,3 +35,6 @@ pub(crate) use self::movie_item::MovieItem;
 
 pub(crate) mod episode_or_movie;
 pub(crate) use self::episode_or_movie::EpisodeOrMovie;
+
+pub(crate) mod notes;
+pub(crate) use self::notes::Notes;
@@ -17,6 +17,9 @@ pub(crate) enum Kind {
         series_id: SeriesId,
         season: SeasonNumber,
     },
+    WatchCollection {
+        movie_id: MovieId,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -94,6 +97,21 @@ impl Confirm {
                         let now = Utc::now();
                         cx.service.remove_season_watches(&now, series_id, season);
                     }
+                    Kind::WatchCollection { movie_id } => {
+                        let collection = cx
+                            .service
+                            .movie(movie_id)
+                            .and_then(|m| m.collection.clone());
+
+                        if let Some(collection) = collection {
+                            let now = Utc::now();
+                            cx.service.watch_collection(
+                                &now,
+                                &collection,
+                                crate::service::RemainingSeason::Aired,
+                            );
+                        }
+                    }
                 }
             }
             Message::Cancel => {
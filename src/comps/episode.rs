@@ -1,9 +1,14 @@
 use crate::component::{Component, ComponentInitExt};
 use crate::comps;
-use crate::model::{EpisodeId, Watched};
+use crate::model::{EpisodeId, EpisodeKind, RemoteId, Watched, WatchedId};
 use crate::params::{GAP, SCREENCAP_HINT, SMALL_SIZE, SPACE};
 use crate::prelude::*;
+use crate::queue::TaskRef;
 use crate::service::PendingRef;
+use crate::style::EpisodeState;
+
+/// Ratings which can be given to a watch.
+const RATINGS: [u8; 10] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
 
 #[derive(Debug, Clone)]
 pub(crate) enum Message {
@@ -12,6 +17,11 @@ pub(crate) enum Message {
     Watch(comps::watch::Message),
     SelectPending(EpisodeId),
     ClearPending(EpisodeId),
+    CopyLink,
+    SetKind(Option<EpisodeKind>),
+    SkipPermanently(bool),
+    RefreshSeries(SeriesId, RemoteId),
+    SetRating(WatchedId, Option<u8>),
     Navigate(Page),
 }
 
@@ -122,11 +132,35 @@ impl Episode {
             }
             Message::SelectPending(episode) => {
                 let now = Utc::now();
-                cx.service.select_pending(&now, &episode);
+
+                if let Some(e) = cx.service.episode(&episode) {
+                    let series_id = *e.series();
+                    cx.service.set_current_episode(&now, &series_id, &episode);
+                }
             }
             Message::ClearPending(episode) => {
                 cx.service.clear_pending(&episode);
             }
+            Message::CopyLink => {
+                if let Some(url) = cx.service.episode_share_url(&self.episode_id) {
+                    cx.state.copy_to_clipboard(url);
+                }
+            }
+            Message::SetKind(kind) => {
+                let now = Utc::now();
+                cx.service.set_episode_kind(&now, &self.episode_id, kind);
+            }
+            Message::SkipPermanently(skipped) => {
+                let now = Utc::now();
+                cx.service
+                    .mark_episode_skipped(&now, &self.episode_id, skipped);
+            }
+            Message::RefreshSeries(series_id, remote_id) => {
+                cx.service.refresh_series(&series_id, remote_id);
+            }
+            Message::SetRating(watch_id, rating) => {
+                cx.service.set_watch_rating(&watch_id, rating);
+            }
             Message::Navigate(page) => {
                 cx.push_history(page);
             }
@@ -147,7 +181,7 @@ impl Episode {
                     .and_then(|image| cx.assets.image_with_hint(image, POSTER_HINT))
                 {
                     Some(handle) => handle,
-                    None => cx.missing_poster(),
+                    None => cx.poster_placeholder(p.poster(), p.title()),
                 };
 
                 (
@@ -169,7 +203,10 @@ impl Episode {
                 )
             };
 
-        let mut name = w::Row::new().spacing(SPACE);
+        let watched = cx.service.watched_by_episode(&episode.id);
+        let state = EpisodeState::new(&episode, watched.len(), cx.state.today());
+
+        let mut name = w::Row::new().spacing(SPACE).align_items(Alignment::Center);
 
         name = name.push(w::text(episode.number));
 
@@ -177,7 +214,29 @@ impl Episode {
             name = name.push(w::text(string).shaping(w::text::Shaping::Advanced));
         }
 
-        let watched = cx.service.watched_by_episode(&episode.id);
+        name = name.push(
+            w::text(format_args!("[{}]", state.label()))
+                .size(SMALL_SIZE)
+                .style(cx.episode_state_text(state)),
+        );
+
+        let metadata_pending = cx.service.is_metadata_pending(&episode, cx.state.today());
+
+        if metadata_pending {
+            name = name.push(
+                w::text("[metadata pending]")
+                    .size(SMALL_SIZE)
+                    .style(cx.warning_text()),
+            );
+        }
+
+        if let Some(kind) = episode.kind {
+            name = name.push(
+                w::text(format_args!("[{kind}]"))
+                    .size(SMALL_SIZE)
+                    .style(cx.warning_text()),
+            );
+        }
 
         let mut actions = w::Row::new().spacing(SPACE);
 
@@ -238,6 +297,65 @@ impl Episode {
                         .on_press(Message::SelectPending(episode.id)),
                 );
             }
+
+            actions = actions.push(
+                w::pick_list(&EpisodeKind::ALL[..], episode.kind, |kind| {
+                    Message::SetKind(Some(kind))
+                })
+                .placeholder("Flag as...")
+                .text_size(SMALL_SIZE),
+            );
+
+            if episode.kind.is_some() {
+                actions = actions.push(
+                    w::button(w::text("Clear flag").size(SMALL_SIZE))
+                        .style(theme::Button::Secondary)
+                        .on_press(Message::SetKind(None)),
+                );
+            }
+
+            if !self.include_series {
+                let (text, skipped) = if cx.service.is_permanently_skipped(&episode) {
+                    ("Stop skipping", false)
+                } else {
+                    ("Skip permanently", true)
+                };
+
+                actions = actions.push(
+                    w::button(w::text(text).size(SMALL_SIZE))
+                        .style(theme::Button::Secondary)
+                        .on_press(Message::SkipPermanently(skipped)),
+                );
+            }
+
+            if metadata_pending {
+                let series_id = *episode.series();
+
+                let remote_id = cx.service.series(&series_id).and_then(|s| s.remote_id);
+
+                let refreshing = cx
+                    .service
+                    .task_status(TaskRef::Series { series_id })
+                    .is_some();
+
+                if let (Some(remote_id), false) = (remote_id, refreshing) {
+                    actions = actions.push(
+                        w::button(w::text("Refresh").size(SMALL_SIZE))
+                            .style(theme::Button::Positive)
+                            .on_press(Message::RefreshSeries(series_id, remote_id)),
+                    );
+                }
+            }
+
+            // NB: The link is only built once it's copied, since looking it
+            // up is too expensive to do for every episode being rendered.
+            if cx.service.remotes_by_series(episode.series()).len() > 0 {
+                actions = actions.push(
+                    w::button(w::text("Copy link").size(SMALL_SIZE))
+                        .style(theme::Button::Secondary)
+                        .on_press(Message::CopyLink),
+                );
+            }
         }
 
         let mut info = w::Column::new();
@@ -276,6 +394,25 @@ impl Episode {
             }
         }
 
+        if !episode.directors.is_empty() {
+            info = info.push(
+                cx.style
+                    .text(format_args!(
+                        "Directed by: {}",
+                        episode.directors.join(", ")
+                    ))
+                    .sm(),
+            );
+        }
+
+        if !episode.writers.is_empty() {
+            info = info.push(
+                cx.style
+                    .text(format_args!("Written by: {}", episode.writers.join(", ")))
+                    .sm(),
+            );
+        }
+
         {
             let mut it = watched.clone();
             let len = it.len();
@@ -319,6 +456,24 @@ impl Episode {
                         .width(Length::Fill),
                 );
 
+                let watch_id = watch.id;
+
+                row = row.push(
+                    w::pick_list(&RATINGS[..], watch.rating, move |rating| {
+                        Message::SetRating(watch_id, Some(rating))
+                    })
+                    .placeholder("Rate")
+                    .text_size(SMALL_SIZE),
+                );
+
+                if watch.rating.is_some() {
+                    row = row.push(
+                        w::button(w::text("Clear rating").size(SMALL_SIZE))
+                            .style(theme::Button::Secondary)
+                            .on_press(Message::SetRating(watch_id, None)),
+                    );
+                }
+
                 row = row.push(
                     c.view("Remove", theme::Button::Destructive)
                         .map(move |m| Message::RemoveWatch(n, m)),
@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use crate::queue::{TaskKind, TaskRef, TaskStatus};
+use crate::queue::{TaskRef, TaskStatus};
 
 #[derive(Debug, Clone)]
 pub(crate) enum Message {
@@ -35,12 +35,7 @@ impl MovieActions {
     pub(crate) fn update(&mut self, cx: &mut Ctxt<'_>, message: Message) {
         match message {
             Message::RefreshMovie(remote_id) => {
-                cx.service.push_task_without_delay(TaskKind::DownloadMovie {
-                    movie_id: self.movie_id,
-                    remote_id,
-                    last_modified: None,
-                    force: true,
-                });
+                cx.service.refresh_movie(&self.movie_id, remote_id);
             }
             Message::RemoveMovie => {
                 cx.remove_movie(&self.movie_id);
@@ -56,21 +51,22 @@ impl MovieActions {
             .task_status(TaskRef::Movie { movie_id: movie.id });
 
         match status {
-            Some(TaskStatus::Pending) => {
-                row = row.push(
-                    w::button(w::text("Refresh").size(SMALL_SIZE)).style(theme::Button::Positive),
-                );
-            }
             Some(TaskStatus::Running) => {
                 row = row.push(
                     w::button(w::text("Downloading...").size(SMALL_SIZE))
                         .style(theme::Button::Primary),
                 );
             }
-            None => {
+            status => {
                 if let Some(remote_id) = movie.remote_id {
+                    // NB: A queued download is replaced with a forced one.
+                    let text = match status {
+                        Some(TaskStatus::Pending) => "Sync now (queued)",
+                        _ => "Sync now",
+                    };
+
                     row = row.push(
-                        w::button(w::text("Refresh").size(SMALL_SIZE))
+                        w::button(w::text(text).size(SMALL_SIZE))
                             .style(theme::Button::Positive)
                             .on_press(Message::RefreshMovie(remote_id)),
                     );
@@ -44,9 +44,13 @@ impl MovieBanner {
         )
         .on_press(Message::Navigate(page::movie::page(movie.id)));
 
-        w::Column::new()
-            .push(banner)
-            .push(title)
+        let mut column = w::Column::new().push(banner).push(title);
+
+        if let Some(rating) = &movie.content_rating {
+            column = column.push(w::text(rating).size(SMALL_SIZE));
+        }
+
+        column
             .spacing(GAP)
             .width(Length::Fill)
             .align_items(Alignment::Center)
@@ -27,6 +27,13 @@ pub(crate) struct MovieItem {
     remove_watches: Vec<comps::Confirm>,
 }
 
+impl MovieItem {
+    /// The movie this item is for.
+    pub(crate) fn movie_id(&self) -> &MovieId {
+        &self.movie_id
+    }
+}
+
 impl<'a, I> Component<Props<I>> for MovieItem
 where
     I: DoubleEndedIterator<Item = &'a Watched> + Clone,
@@ -130,7 +137,7 @@ impl MovieItem {
             .and_then(|image| cx.assets.image_with_hint(image, POSTER_HINT))
         {
             Some(handle) => handle,
-            None => cx.missing_poster(),
+            None => cx.poster_placeholder(movie.poster(), &movie.title),
         };
 
         let image = link(w::image(poster))
@@ -0,0 +1,94 @@
+use iced::widget::text_editor;
+
+use crate::prelude::*;
+
+#[derive(Debug, Clone)]
+pub(crate) enum Message {
+    Edit(text_editor::Action),
+}
+
+/// What the notes belong to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Kind {
+    Series(SeriesId),
+    Movie(MovieId),
+}
+
+/// An editable text box for the notes of a series or movie.
+pub(crate) struct Notes {
+    kind: Kind,
+    content: text_editor::Content,
+    loaded: bool,
+}
+
+impl Component<Kind> for Notes {
+    #[inline]
+    fn new(kind: Kind) -> Self {
+        Self {
+            kind,
+            content: text_editor::Content::new(),
+            loaded: false,
+        }
+    }
+
+    #[inline]
+    fn changed(&mut self, kind: Kind) {
+        if self.kind != kind {
+            self.kind = kind;
+            self.loaded = false;
+        }
+    }
+}
+
+impl Notes {
+    /// Load the current notes into the editor.
+    pub(crate) fn prepare(&mut self, cx: &mut Ctxt<'_>) {
+        if self.loaded {
+            return;
+        }
+
+        let notes = match &self.kind {
+            Kind::Series(id) => cx.service.series(id).map(|s| s.notes.as_str()),
+            Kind::Movie(id) => cx.service.movie(id).map(|m| m.notes.as_str()),
+        };
+
+        self.content = text_editor::Content::with_text(notes.unwrap_or_default());
+        self.loaded = true;
+    }
+
+    pub(crate) fn update(&mut self, cx: &mut Ctxt<'_>, message: Message) {
+        match message {
+            Message::Edit(action) => {
+                let is_edit = action.is_edit();
+                self.content.perform(action);
+
+                if !is_edit {
+                    return;
+                }
+
+                let notes = self.content.text().trim_end().to_owned();
+
+                match &self.kind {
+                    Kind::Series(id) => {
+                        cx.service.set_series_notes(id, notes);
+                    }
+                    Kind::Movie(id) => {
+                        cx.service.set_movie_notes(id, notes);
+                    }
+                }
+            }
+        }
+    }
+
+    pub(crate) fn view(&self) -> Element<'_, Message> {
+        w::Column::new()
+            .push(w::text("Notes").size(SMALL_SIZE))
+            .push(
+                w::text_editor(&self.content)
+                    .on_action(Message::Edit)
+                    .height(100.0),
+            )
+            .spacing(SPACE)
+            .into()
+    }
+}
@@ -13,6 +13,8 @@ pub(crate) struct SeasonInfo {
     season: SeasonNumber,
     watch_remaining: comps::WatchRemaining,
     remove_watches: comps::Confirm,
+    /// Summary of the last bulk action.
+    summary: Option<String>,
 }
 
 impl Component<(SeriesId, SeasonNumber)> for SeasonInfo {
@@ -27,11 +29,16 @@ impl Component<(SeriesId, SeasonNumber)> for SeasonInfo {
             remove_watches: comps::confirm::Confirm::new(comps::confirm::Props::new(
                 comps::confirm::Kind::RemoveSeason { series_id, season },
             )),
+            summary: None,
         }
     }
 
     #[inline]
     fn changed(&mut self, (series_id, season): (SeriesId, SeasonNumber)) {
+        if self.series_id != series_id || self.season != season {
+            self.summary = None;
+        }
+
         self.series_id = series_id;
         self.season = season;
         self.watch_remaining
@@ -44,6 +51,8 @@ impl Component<(SeriesId, SeasonNumber)> for SeasonInfo {
 
 impl SeasonInfo {
     pub(crate) fn update(&mut self, cx: &mut Ctxt<'_>, message: Message) {
+        let (before, _) = cx.service.season_watched(&self.series_id, &self.season);
+
         match message {
             Message::WatchRemaining(m) => {
                 self.watch_remaining.update(cx, m);
@@ -52,6 +61,18 @@ impl SeasonInfo {
                 self.remove_watches.update(cx, m);
             }
         }
+
+        let (after, _) = cx.service.season_watched(&self.series_id, &self.season);
+
+        self.summary = match after.cmp(&before) {
+            std::cmp::Ordering::Greater => {
+                Some(format!("Marked {} as watched", episodes(after - before)))
+            }
+            std::cmp::Ordering::Less => {
+                Some(format!("Removed watches from {}", episodes(before - after)))
+            }
+            std::cmp::Ordering::Equal => self.summary.take(),
+        };
     }
 
     pub(crate) fn view(&self, cx: &CtxtRef<'_>) -> Element<'static, Message> {
@@ -80,21 +101,37 @@ impl SeasonInfo {
             );
         }
 
-        let plural = match total {
-            1 => "episode",
-            _ => "episodes",
+        let info = if cx.service.is_ignored_season(&self.series_id, &self.season) {
+            w::text(format_args!(
+                "Specials are ignored, watched {watched} out of {}",
+                episodes(total)
+            ))
+        } else {
+            w::text(format_args!(
+                "Watched {watched} out of {} ({percentage}%)",
+                episodes(total),
+                percentage = (watched * 100).checked_div(total).unwrap_or(0),
+            ))
         };
 
-        let info = w::text(format_args!(
-            "Watched {watched} out of {total} {plural} ({percentage}%)",
-            percentage = (watched * 100).checked_div(total).unwrap_or(0),
-        ));
+        let mut column = w::Column::new().push(actions).push(info);
+
+        if let Some(rating) = cx.service.season_rating(&self.series_id, &self.season) {
+            column = column
+                .push(w::text(format_args!("Average rating: {rating:.1} / 10")).size(SMALL_SIZE));
+        }
+
+        if let Some(summary) = &self.summary {
+            column = column.push(w::text(summary).size(SMALL_SIZE));
+        }
+
+        column.spacing(GAP).width(Length::Fill).into()
+    }
+}
 
-        w::Column::new()
-            .push(actions)
-            .push(info)
-            .spacing(GAP)
-            .width(Length::Fill)
-            .into()
+fn episodes(count: usize) -> String {
+    match count {
+        1 => String::from("1 episode"),
+        n => format!("{n} episodes"),
     }
 }
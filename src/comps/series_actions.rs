@@ -6,7 +6,13 @@ pub(crate) enum Message {
     Untrack,
     Track,
     RefreshSeries(RemoteId),
+    ResolveEpisodeRemotes(RemoteId),
+    ClearWatchOrder,
     RemoveSeries,
+    ConfirmRemove,
+    CancelRemove,
+    RestoreSeries,
+    DeleteSeries,
 }
 
 #[derive(Debug, Clone)]
@@ -43,23 +49,57 @@ impl SeriesActions {
                 cx.service.track(&self.series_id);
             }
             Message::RefreshSeries(remote_id) => {
+                cx.service.refresh_series(&self.series_id, remote_id);
+            }
+            Message::ResolveEpisodeRemotes(remote_id) => {
                 cx.service
-                    .push_task_without_delay(TaskKind::DownloadSeries {
+                    .push_task_without_delay(TaskKind::ResolveEpisodeRemotes {
                         series_id: self.series_id,
                         remote_id,
-                        last_modified: None,
-                        force: true,
                     });
             }
+            Message::ClearWatchOrder => {
+                cx.service
+                    .set_watch_order(&Utc::now(), &self.series_id, Vec::new());
+            }
             Message::RemoveSeries => {
+                self.confirm = true;
+            }
+            Message::ConfirmRemove => {
+                self.confirm = false;
                 cx.remove_series(&self.series_id);
             }
+            Message::CancelRemove => {
+                self.confirm = false;
+            }
+            Message::RestoreSeries => {
+                cx.service.restore_series(&self.series_id);
+            }
+            Message::DeleteSeries => {
+                cx.delete_series(&self.series_id);
+            }
         }
     }
 
     pub(crate) fn view(&self, cx: &CtxtRef<'_>, series: &Series) -> Element<'static, Message> {
         let mut row = w::Row::new();
 
+        if series.removed.is_some() {
+            row = row.push(
+                w::button(w::text("Undo removal").size(SMALL_SIZE))
+                    .style(theme::Button::Positive)
+                    .on_press(Message::RestoreSeries),
+            );
+
+            row = row.push(
+                w::button(w::text("Delete permanently").size(SMALL_SIZE))
+                    .style(theme::Button::Destructive)
+                    .on_press(Message::DeleteSeries),
+            );
+
+            return row.spacing(SPACE).into();
+        }
+
         if series.tracked {
             row = row.push(
                 w::button(w::text("Untrack").size(SMALL_SIZE))
@@ -80,9 +120,13 @@ impl SeriesActions {
 
         match status {
             Some(TaskStatus::Pending) => {
-                row = row.push(
-                    w::button(w::text("Refresh").size(SMALL_SIZE)).style(theme::Button::Positive),
-                );
+                let refresh =
+                    w::button(w::text("Refresh").size(SMALL_SIZE)).style(theme::Button::Positive);
+
+                row = row.push(match series.remote_id {
+                    Some(remote_id) => refresh.on_press(Message::RefreshSeries(remote_id)),
+                    None => refresh,
+                });
             }
             Some(TaskStatus::Running) => {
                 row = row.push(
@@ -97,15 +141,47 @@ impl SeriesActions {
                             .style(theme::Button::Positive)
                             .on_press(Message::RefreshSeries(remote_id)),
                     );
+
+                    row = row.push(
+                        w::button(w::text("Re-resolve episode ids").size(SMALL_SIZE))
+                            .style(theme::Button::Secondary)
+                            .on_press(Message::ResolveEpisodeRemotes(remote_id)),
+                    );
                 }
             }
         }
 
-        row = row.push(
-            w::button(w::text("Remove").size(SMALL_SIZE))
-                .style(theme::Button::Destructive)
-                .on_press(Message::RemoveSeries),
-        );
+        if !series.watch_order.is_empty() {
+            row = row.push(
+                w::button(w::text("Clear watch order").size(SMALL_SIZE))
+                    .style(theme::Button::Secondary)
+                    .on_press(Message::ClearWatchOrder),
+            );
+        }
+
+        if self.confirm {
+            row = row.push(
+                w::button(w::text("Remove").size(SMALL_SIZE)).style(theme::Button::Secondary),
+            );
+
+            row = row.push(
+                w::button(w::text("Confirm").size(SMALL_SIZE))
+                    .style(theme::Button::Destructive)
+                    .on_press(Message::ConfirmRemove),
+            );
+
+            row = row.push(
+                w::button(w::text("Cancel").size(SMALL_SIZE))
+                    .style(theme::Button::Secondary)
+                    .on_press(Message::CancelRemove),
+            );
+        } else {
+            row = row.push(
+                w::button(w::text("Remove").size(SMALL_SIZE))
+                    .style(theme::Button::Destructive)
+                    .on_press(Message::RemoveSeries),
+            );
+        }
 
         row.spacing(SPACE).into()
     }
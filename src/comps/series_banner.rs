@@ -44,9 +44,13 @@ impl SeriesBanner {
         )
         .on_press(Message::Navigate(page::series::page(series.id)));
 
-        w::Column::new()
-            .push(banner)
-            .push(title)
+        let mut column = w::Column::new().push(banner).push(title);
+
+        if let Some(rating) = &series.content_rating {
+            column = column.push(w::text(rating).size(SMALL_SIZE));
+        }
+
+        column
             .spacing(GAP)
             .width(Length::Fill)
             .align_items(Alignment::Center)
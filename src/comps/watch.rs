@@ -1,3 +1,5 @@
+use chrono::{Local, NaiveDate, NaiveTime, TimeZone};
+
 use crate::comps::ordering::Ordering;
 use crate::prelude::*;
 use crate::service::RemainingSeason;
@@ -6,6 +8,8 @@ use crate::service::RemainingSeason;
 pub(crate) enum Message {
     RightNow,
     AirDate,
+    Date(String),
+    OnDate,
     Cancel,
     Start,
 }
@@ -35,6 +39,7 @@ impl Props {
 pub(crate) struct Watch {
     props: Props,
     confirm: bool,
+    date: String,
 }
 
 impl Component<Props> for Watch {
@@ -43,6 +48,7 @@ impl Component<Props> for Watch {
         Self {
             props,
             confirm: false,
+            date: String::new(),
         }
     }
 
@@ -51,6 +57,7 @@ impl Component<Props> for Watch {
         if self.props != props {
             self.props = props;
             self.confirm = false;
+            self.date.clear();
         }
     }
 }
@@ -88,8 +95,40 @@ impl Watch {
                     }
                 }
             }
+            Message::Date(date) => {
+                self.date = date;
+            }
+            Message::OnDate => {
+                let Ok(date) = NaiveDate::parse_from_str(self.date.trim(), "%Y-%m-%d") else {
+                    return;
+                };
+
+                self.confirm = false;
+                self.date.clear();
+
+                let now = Utc::now();
+
+                let Some(timestamp) = Local
+                    .from_local_datetime(&date.and_time(NaiveTime::MIN))
+                    .earliest()
+                else {
+                    return;
+                };
+
+                let remaining = RemainingSeason::At(timestamp.with_timezone(&Utc));
+
+                match &self.props.kind {
+                    Kind::Episode(id) => {
+                        cx.service.watch(&now, id, remaining);
+                    }
+                    Kind::Movie(id) => {
+                        cx.service.watch_movie(&now, id, remaining);
+                    }
+                }
+            }
             Message::Cancel => {
                 self.confirm = false;
+                self.date.clear();
             }
             Message::Start => {
                 self.confirm = true;
@@ -109,13 +148,27 @@ impl Watch {
         let mut row = w::Row::new().width(width);
 
         if self.confirm {
-            let buttons = [
+            let date = NaiveDate::parse_from_str(self.date.trim(), "%Y-%m-%d").ok();
+
+            let buttons: [Element<'static, Message>; 5] = [
                 w::button(w::text("Now").size(SMALL_SIZE))
                     .style(right_now)
-                    .on_press(Message::RightNow),
+                    .on_press(Message::RightNow)
+                    .into(),
                 w::button(w::text("Air date").size(SMALL_SIZE))
                     .style(air_date)
-                    .on_press(Message::AirDate),
+                    .on_press(Message::AirDate)
+                    .into(),
+                w::text_input("YYYY-MM-DD", &self.date)
+                    .on_input(Message::Date)
+                    .on_submit(Message::OnDate)
+                    .size(SMALL_SIZE)
+                    .width(100.0)
+                    .into(),
+                w::button(w::text("On date").size(SMALL_SIZE))
+                    .style(theme::Button::Secondary)
+                    .on_press_maybe(date.map(|_| Message::OnDate))
+                    .into(),
                 w::button(
                     w::text("Cancel")
                         .horizontal_alignment(Horizontal::Center)
@@ -123,11 +176,16 @@ impl Watch {
                 )
                 .style(theme::Button::Secondary)
                 .width(Length::Fill)
-                .on_press(Message::Cancel),
+                .on_press(Message::Cancel)
+                .into(),
             ];
 
             let head = if reminder {
-                Some(w::button(w::text(title).size(SMALL_SIZE)).style(theme::Button::Secondary))
+                Some(
+                    w::button(w::text(title).size(SMALL_SIZE))
+                        .style(theme::Button::Secondary)
+                        .into(),
+                )
             } else {
                 None
             };
@@ -14,7 +14,7 @@ pub(crate) enum Message {
 pub(crate) struct Props {
     ordering: Ordering,
     series_id: SeriesId,
-    season: SeasonNumber,
+    season: Option<SeasonNumber>,
 }
 
 impl Props {
@@ -23,7 +23,17 @@ impl Props {
         Self {
             ordering: Ordering::Right,
             series_id,
-            season,
+            season: Some(season),
+        }
+    }
+
+    /// Watch the remaining episodes in every season of a series.
+    #[inline]
+    pub(crate) fn series(series_id: SeriesId) -> Self {
+        Self {
+            ordering: Ordering::Right,
+            series_id,
+            season: None,
         }
     }
 }
@@ -60,23 +70,11 @@ impl WatchRemaining {
         match message {
             Message::RightNow => {
                 self.confirm = false;
-                let now = Utc::now();
-                cx.service.watch_remaining_season(
-                    &now,
-                    &self.props.series_id,
-                    &self.props.season,
-                    RemainingSeason::Aired,
-                );
+                self.watch(cx, RemainingSeason::Aired);
             }
             Message::AirDate => {
                 self.confirm = false;
-                let now = Utc::now();
-                cx.service.watch_remaining_season(
-                    &now,
-                    &self.props.series_id,
-                    &self.props.season,
-                    RemainingSeason::AirDate,
-                );
+                self.watch(cx, RemainingSeason::AirDate);
             }
             Message::Cancel => {
                 self.confirm = false;
@@ -87,6 +85,21 @@ impl WatchRemaining {
         }
     }
 
+    fn watch(&self, cx: &mut Ctxt<'_>, remaining: RemainingSeason) {
+        let now = Utc::now();
+
+        match &self.props.season {
+            Some(season) => {
+                cx.service
+                    .watch_remaining_season(&now, &self.props.series_id, season, remaining);
+            }
+            None => {
+                cx.service
+                    .watch_remaining_series(&now, &self.props.series_id, remaining);
+            }
+        }
+    }
+
     pub(crate) fn view(
         &self,
         title: &str,
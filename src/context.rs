@@ -1,13 +1,15 @@
 use std::future::Future;
 
 use anyhow::Result;
+use chrono::Utc;
 
 use crate::assets::Assets;
 use crate::history::{HistoryMutations, Page};
-use crate::model::{MovieId, RemoteId, SeriesId};
+use crate::model::{ImageV2, MovieId, RemoteId, SeriesId};
+use crate::params::POSTER_HINT;
 use crate::service::{NewMovie, NewSeries, Service};
 use crate::state::State;
-use crate::style::Style;
+use crate::style::{EpisodeState, Style};
 
 /// Context reference.
 pub(crate) struct CtxtRef<'a> {
@@ -23,11 +25,31 @@ impl CtxtRef<'_> {
         crate::style::warning_text(self.service.theme())
     }
 
+    #[inline]
+    pub(crate) fn episode_state_text(&self, state: EpisodeState) -> iced::theme::Text {
+        state.text(self.service.theme())
+    }
+
     #[inline]
     pub(crate) fn missing_poster(&self) -> iced::advanced::image::Handle {
         self.assets.missing_poster(self.service.theme())
     }
 
+    /// Get a placeholder for a poster which isn't loaded, showing the initials
+    /// of the title if the poster failed to load.
+    pub(crate) fn poster_placeholder(
+        &self,
+        poster: Option<&ImageV2>,
+        title: &str,
+    ) -> iced::advanced::image::Handle {
+        match poster {
+            Some(poster) if self.assets.is_failed(poster, POSTER_HINT) => {
+                self.assets.placeholder_poster(self.service.theme(), title)
+            }
+            _ => self.missing_poster(),
+        }
+    }
+
     /// Refresh series data.
     #[tracing::instrument(skip(self))]
     pub(crate) fn download_series_by_id(
@@ -36,14 +58,21 @@ impl CtxtRef<'_> {
         remote_id: &RemoteId,
         force: bool,
     ) -> impl Future<Output = Result<Option<NewSeries>>> {
-        let none_if_match = if force {
-            None
+        let (none_if_match, none_if_match_episodes) = if force {
+            (None, &[][..])
         } else {
-            self.service.last_etag(remote_id).cloned()
+            (
+                self.service.last_etag(remote_id).cloned(),
+                self.service.last_episode_etags(remote_id),
+            )
         };
 
-        self.service
-            .download_series(remote_id, none_if_match.as_ref(), Some(series_id))
+        self.service.download_series(
+            remote_id,
+            none_if_match.as_ref(),
+            none_if_match_episodes,
+            Some(series_id),
+        )
     }
 
     /// Refresh movie data.
@@ -84,7 +113,13 @@ impl Ctxt<'_> {
 
     /// Remove a series.
     pub(crate) fn remove_series(&mut self, id: &SeriesId) {
-        self.service.remove_series(id);
+        let now = Utc::now();
+        self.service.remove_series(&now, id);
+    }
+
+    /// Permanently delete a series.
+    pub(crate) fn delete_series(&mut self, id: &SeriesId) {
+        self.service.delete_series(id);
     }
 
     /// Remove a movie.
@@ -1,6 +1,9 @@
+mod backup;
+mod collections;
 mod episodes;
 mod format;
 mod iter;
+mod migrations;
 mod movies;
 mod pending;
 mod remotes;
@@ -20,9 +23,10 @@ use tracing_futures::Instrument;
 pub(crate) use self::episodes::EpisodeRef;
 pub(crate) use self::seasons::SeasonRef;
 use crate::model::{
-    Config, Episode, Movie, MovieId, Pending, RemoteIds, Season, Series, SeriesId, Watched,
+    Collection, Config, Episode, Movie, MovieId, Pending, RemoteIds, Season, Series, SeriesId,
+    Watched,
 };
-use crate::queue::Queue;
+use crate::queue::{Queue, QueueDump, Task};
 use crate::service::paths;
 
 #[derive(Default)]
@@ -43,12 +47,16 @@ pub(crate) struct Database {
     pub(crate) watched: watched::Database,
     /// Ordered list of things to watch.
     pub(crate) pending: pending::Database,
+    /// Collections of related series and movies.
+    pub(crate) collections: collections::Database,
     /// Synchronization state.
     pub(crate) sync: sync::Database,
     /// Keeping track of changes to be saved.
     pub(crate) changes: Changes,
     /// Download queue.
     pub(crate) tasks: Queue,
+    /// Notes about data that was upgraded when loading.
+    pub(crate) migration_notes: Vec<&'static str>,
 }
 
 impl Database {
@@ -64,6 +72,9 @@ impl Database {
             if matches!(format, format::Format::Json) {
                 db.changes.change(Change::Config);
             }
+        } else {
+            // Nothing has been stored yet, so there is nothing to migrate.
+            db.config.data_version = migrations::DATA_VERSION;
         }
 
         if let Some((format, remotes)) = format::load_array::<RemoteIds>(&paths.remotes)? {
@@ -137,6 +148,29 @@ impl Database {
             }
         }
 
+        if let Some((format, collections)) = format::load_array::<Collection>(&paths.collections)? {
+            for c in collections {
+                db.collections.insert(c);
+            }
+
+            if matches!(format, format::Format::Json) {
+                db.changes.change(Change::Collections);
+            }
+        }
+
+        if let Some((format, tasks)) = format::load_array::<Task>(&paths.queue)? {
+            db.tasks
+                .restore(QueueDump {
+                    pending: tasks,
+                    ..QueueDump::default()
+                })
+                .with_context(|| anyhow!("{}", paths.queue.display()))?;
+
+            if matches!(format, format::Format::Json) {
+                db.changes.change(Change::Queue);
+            }
+        }
+
         if let Some(episodes) = format::load_directory::<_, SeriesId, Episode>(&paths.episodes)? {
             for (id, _format, mut episodes) in episodes {
                 for e in &mut episodes {
@@ -163,6 +197,7 @@ impl Database {
             }
         }
 
+        migrations::run(&mut db);
         Ok(db)
     }
 
@@ -194,6 +229,16 @@ impl Database {
             .contains(Change::Pending)
             .then(|| self.pending.export());
 
+        let collections = changes
+            .set
+            .contains(Change::Collections)
+            .then(|| self.collections.export());
+
+        let queue = changes
+            .set
+            .contains(Change::Queue)
+            .then(|| self.tasks.export());
+
         let series = changes
             .set
             .contains(Change::Series)
@@ -222,6 +267,18 @@ impl Database {
             None
         };
 
+        let backup = self.config.backup_path.clone().map(|path| {
+            let config = self.config.backup_exclude_secrets.then(|| {
+                let mut config = self.config.clone();
+                config.tvdb_legacy_apikey.clear();
+                config.tmdb_api_key.clear();
+                config.omdb_api_key.clear();
+                config
+            });
+
+            (path, self.config.backup_keep, config)
+        });
+
         let paths = paths.clone();
         let changes = changes.set;
 
@@ -266,6 +323,18 @@ impl Database {
                     .context("pending")?;
             }
 
+            if let Some(collections) = collections {
+                format::save_array("collections", &paths.collections, collections)
+                    .await
+                    .context("collections")?;
+            }
+
+            if let Some(queue) = queue {
+                format::save_array("queue", &paths.queue, queue)
+                    .await
+                    .context("queue")?;
+            }
+
             if let Some(remotes) = remotes {
                 format::save_array("remotes", &paths.remotes, remotes).await?;
             }
@@ -286,6 +355,12 @@ impl Database {
                 let _ = tokio::try_join!(a, b)?;
             }
 
+            if let Some((path, keep, config)) = backup {
+                backup::snapshot(paths.root.to_path_buf(), path, keep, config)
+                    .await
+                    .context("backup")?;
+            }
+
             drop(guard);
             Ok(())
         };
@@ -310,8 +385,12 @@ pub(crate) enum Change {
     Movie,
     // Remotes list has changed.
     Remotes,
+    // Collections have changed.
+    Collections,
     // Schedule changed.
     Schedule,
+    // Task queue has changed.
+    Queue,
 }
 
 #[derive(Default)]
@@ -349,7 +428,7 @@ impl Changes {
             return true;
         }
 
-        if !self.remove_movies.is_empty() && !self.add_movies.is_empty() {
+        if !self.remove_movies.is_empty() || !self.add_movies.is_empty() {
             return true;
         }
 
@@ -438,3 +517,48 @@ fn migrate_series(db: &mut Database, s: &mut Series) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::Utc;
+
+    use crate::model::RemoteId;
+    use crate::queue::TaskKind;
+    use crate::service::paths::Paths;
+
+    use super::{Change, Database};
+
+    #[tokio::test]
+    async fn test_queue_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let paths = Arc::new(Paths::new(
+            &dir.path().join("config"),
+            &dir.path().join("cache"),
+        ));
+
+        let now = Utc::now();
+        let mut db = Database::load(&paths).unwrap();
+
+        for id in [1, 2] {
+            assert!(db.tasks.push(
+                &now,
+                TaskKind::DownloadMovieByRemoteId {
+                    remote_id: RemoteId::Tmdb { id }
+                }
+            ));
+        }
+
+        let last = db.tasks.pending().last().unwrap().id;
+        assert!(db.tasks.move_task(last, -1));
+        db.changes.change(Change::Queue);
+
+        let expected = db.tasks.pending().cloned().collect::<Vec<_>>();
+        db.save_changes(&paths, false).await.unwrap();
+
+        let db = Database::load(&paths).unwrap();
+        let actual = db.tasks.pending().cloned().collect::<Vec<_>>();
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,118 @@
+//! Timestamped snapshots of the database written to a backup directory.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+use chrono::{Local, NaiveDateTime};
+
+use crate::model::Config;
+
+/// Format used to name snapshot directories.
+const FORMAT: &str = "%Y%m%dT%H%M%S";
+
+/// Write a snapshot of the database in `root` to a timestamped directory in
+/// `backup`, keeping at most `keep` snapshots around.
+///
+/// If `config` is specified it is written in place of the stored
+/// configuration, which is used to exclude secrets.
+pub(crate) async fn snapshot(
+    root: PathBuf,
+    backup: PathBuf,
+    keep: usize,
+    config: Option<Config>,
+) -> Result<()> {
+    let task = tokio::task::spawn_blocking(move || {
+        let name = Local::now().format(FORMAT).to_string();
+        let target = backup.join(&name);
+
+        tracing::info!("Writing backup to {}", target.display());
+
+        copy_dir(&root, &target, &backup, config.is_some())
+            .with_context(|| anyhow!("{}", target.display()))?;
+
+        if let Some(config) = config {
+            let path = target.join("config.yaml");
+            let f = fs::File::create(&path).with_context(|| anyhow!("{}", path.display()))?;
+            serde_yaml::to_writer(f, &config)?;
+        }
+
+        prune(&backup, keep).with_context(|| anyhow!("{}", backup.display()))?;
+        Ok(())
+    });
+
+    task.await?
+}
+
+/// Recursively copy a directory, skipping the backup directory itself in case
+/// it's located inside of the directory being copied, and any git repository.
+fn copy_dir(from: &Path, to: &Path, backup: &Path, skip_config: bool) -> io::Result<()> {
+    fs::create_dir_all(to)?;
+
+    for e in fs::read_dir(from)? {
+        let e = e?;
+        let path = e.path();
+
+        if path == backup || e.file_name() == ".git" {
+            continue;
+        }
+
+        let file_type = e.file_type()?;
+
+        if file_type.is_dir() {
+            copy_dir(&path, &to.join(e.file_name()), backup, false)?;
+            continue;
+        }
+
+        if skip_config && matches!(e.file_name().to_str(), Some("config.yaml" | "config.json")) {
+            continue;
+        }
+
+        if file_type.is_file() {
+            fs::copy(&path, to.join(e.file_name()))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// List the names of existing snapshots, oldest first.
+fn snapshots(backup: &Path) -> io::Result<Vec<String>> {
+    let mut snapshots = Vec::new();
+
+    let dir = match fs::read_dir(backup) {
+        Ok(dir) => dir,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(snapshots),
+        Err(e) => return Err(e),
+    };
+
+    for e in dir {
+        let e = e?;
+
+        let Some(name) = e.file_name().to_str().map(str::to_owned) else {
+            continue;
+        };
+
+        if NaiveDateTime::parse_from_str(&name, FORMAT).is_ok() && e.file_type()?.is_dir() {
+            snapshots.push(name);
+        }
+    }
+
+    snapshots.sort();
+    Ok(snapshots)
+}
+
+/// Remove the oldest snapshots so that at most `keep` remain.
+fn prune(backup: &Path, keep: usize) -> io::Result<()> {
+    let snapshots = snapshots(backup)?;
+
+    let remove = snapshots.len().saturating_sub(keep.max(1));
+
+    for name in snapshots.into_iter().take(remove) {
+        tracing::info!("Removing old backup {name}");
+        fs::remove_dir_all(backup.join(name))?;
+    }
+
+    Ok(())
+}
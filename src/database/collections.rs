@@ -0,0 +1,76 @@
+use std::collections::{BTreeSet, HashMap};
+
+use crate::database::iter::Iter;
+use crate::model::{Collection, CollectionId};
+
+#[derive(Default)]
+pub(crate) struct Database {
+    // Collections indexed by id.
+    data: HashMap<CollectionId, Collection>,
+    // Collections indexed by name.
+    by_name: BTreeSet<(String, CollectionId)>,
+}
+
+impl Database {
+    /// Get a collection immutably.
+    pub(crate) fn get(&self, id: &CollectionId) -> Option<&Collection> {
+        self.data.get(id)
+    }
+
+    /// Get a collection mutably.
+    pub(crate) fn get_mut(&mut self, id: &CollectionId) -> Option<&mut Collection> {
+        self.data.get_mut(id)
+    }
+
+    /// Iterate mutably over all collections.
+    pub(crate) fn iter_mut(&mut self) -> impl Iterator<Item = &mut Collection> {
+        self.data.values_mut()
+    }
+
+    /// Remove the collection with the given identifier.
+    pub(crate) fn remove(&mut self, id: &CollectionId) -> Option<Collection> {
+        let collection = self.data.remove(id)?;
+        let _ = self
+            .by_name
+            .remove(&(collection.name.clone(), collection.id));
+        Some(collection)
+    }
+
+    /// Insert the given collection.
+    pub(crate) fn insert(&mut self, collection: Collection) {
+        self.by_name
+            .insert((collection.name.clone(), collection.id));
+        self.data.insert(collection.id, collection);
+    }
+
+    /// Rename the given collection.
+    pub(crate) fn rename(&mut self, id: &CollectionId, name: String) -> bool {
+        let Some(collection) = self.data.get_mut(id) else {
+            return false;
+        };
+
+        self.by_name
+            .remove(&(collection.name.clone(), collection.id));
+        self.by_name.insert((name.clone(), collection.id));
+        collection.name = name;
+        true
+    }
+
+    /// Iterate over all collections in the database ordered by name.
+    pub(crate) fn iter_by_name(&self) -> impl DoubleEndedIterator<Item = &Collection> {
+        Iter::new(self.by_name.iter().map(|(_, key)| key), &self.data)
+    }
+
+    /// Export collection data.
+    pub(crate) fn export(&self) -> impl IntoIterator<Item = Collection> + 'static {
+        let mut out = Vec::with_capacity(self.by_name.len());
+
+        for (_, id) in &self.by_name {
+            if let Some(collection) = self.data.get(id) {
+                out.push(collection.clone());
+            }
+        }
+
+        out
+    }
+}
@@ -156,6 +156,13 @@ impl Database {
         Some(data.as_episode_ref(&self.data))
     }
 
+    /// Get an episode mutably.
+    ///
+    /// Nothing which affects the order of episodes may be modified.
+    pub(crate) fn get_mut(&mut self, id: &EpisodeId) -> Option<&mut Episode> {
+        Some(&mut self.data.get_mut(id)?.episode)
+    }
+
     /// Get episodes by series.
     pub(crate) fn by_series(&self, id: &SeriesId) -> Iter<'_> {
         let state = self.by_series.get(id).copied().unwrap_or_default();
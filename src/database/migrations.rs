@@ -0,0 +1,80 @@
+use crate::database::{Change, Database};
+use crate::model::ScheduleWindow;
+
+/// A migration of the stored data.
+pub(super) struct Migration {
+    /// The version the data is at once this migration has been applied.
+    version: u32,
+    /// Short description of the migration.
+    name: &'static str,
+    /// Note to show once after the migration has been applied.
+    note: Option<&'static str>,
+    /// Apply the migration. This must leave data which is already migrated
+    /// untouched.
+    apply: fn(&mut Database),
+}
+
+/// Migrations to apply in order.
+const MIGRATIONS: &[Migration] = &[
+    // NB: The first version marks data which is versioned, and doesn't
+    // change anything.
+    Migration {
+        version: 1,
+        name: "baseline",
+        note: None,
+        apply: |_| {},
+    },
+    Migration {
+        version: 2,
+        name: "schedule window",
+        note: Some(
+            "The schedule duration has been replaced by a schedule window, \
+             which can be set in either days or weeks under Settings.",
+        ),
+        apply: schedule_window,
+    },
+];
+
+/// Move the schedule duration in days over to the schedule window.
+#[allow(deprecated)]
+fn schedule_window(db: &mut Database) {
+    if let Some(days) = db.config.compat_schedule_duration_days.take() {
+        db.config.schedule_window = ScheduleWindow::Days(days);
+    }
+}
+
+/// The current version of the stored data.
+pub(super) const DATA_VERSION: u32 = MIGRATIONS[MIGRATIONS.len() - 1].version;
+
+/// Apply all migrations which are newer than the version of the loaded data.
+///
+/// Data which doesn't have a version is treated as version 0.
+pub(super) fn run(db: &mut Database) {
+    let from = db.config.data_version;
+
+    if from > DATA_VERSION {
+        tracing::warn!(
+            from,
+            to = DATA_VERSION,
+            "Data is from a newer version, not migrating"
+        );
+        return;
+    }
+
+    for migration in MIGRATIONS.iter().filter(|m| m.version > from) {
+        tracing::info!(
+            version = migration.version,
+            "Migrating data: {}",
+            migration.name
+        );
+
+        (migration.apply)(db);
+
+        if let Some(note) = migration.note {
+            db.migration_notes.push(note);
+        }
+
+        db.config.data_version = migration.version;
+        db.changes.change(Change::Config);
+    }
+}
@@ -45,6 +45,11 @@ impl Database {
         self.data.values()
     }
 
+    /// Iterate mutably over all movies in the database in some random order.
+    pub(crate) fn iter_mut(&mut self) -> impl ExactSizeIterator<Item = &mut Movie> {
+        self.data.values_mut()
+    }
+
     /// Export movie data.
     pub(crate) fn export(&self) -> impl IntoIterator<Item = Movie> + 'static {
         let mut out = Vec::with_capacity(self.by_name.len());
@@ -48,6 +48,17 @@ impl Database {
         Some(p)
     }
 
+    /// Snooze the pending entry of a series until the given time, returns
+    /// `true` if the series has a pending entry.
+    pub(crate) fn snooze(&mut self, id: &SeriesId, until: Option<DateTime<Utc>>) -> bool {
+        let Some(p) = self.data.get_mut(id.id()) else {
+            return false;
+        };
+
+        p.snoozed_until = until;
+        true
+    }
+
     /// Iterate immutably over pending entries in timestamp order.
     #[inline]
     pub(crate) fn iter(&self) -> impl DoubleEndedIterator<Item = &Pending> + Clone {
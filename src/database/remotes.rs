@@ -1,4 +1,5 @@
 use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::hash::Hash;
 use std::sync::Arc;
 
 use parking_lot::Mutex;
@@ -18,6 +19,7 @@ pub(crate) struct Database {
     inner: Arc<Mutex<Inner>>,
     by_series: HashMap<SeriesId, BTreeSet<RemoteId>>,
     by_movie: HashMap<MovieId, BTreeSet<RemoteId>>,
+    by_episode: HashMap<EpisodeId, BTreeSet<RemoteEpisodeId>>,
 }
 
 impl Database {
@@ -31,6 +33,11 @@ impl Database {
         Some(*self.inner.lock().movies.get(remote_id)?)
     }
 
+    /// Get a remote episode identifier.
+    pub(crate) fn get_episode(&self, remote_id: &RemoteEpisodeId) -> Option<EpisodeId> {
+        Some(*self.inner.lock().episodes.get(remote_id)?)
+    }
+
     /// Get remote by series.
     pub(crate) fn get_by_series(
         &self,
@@ -47,6 +54,14 @@ impl Database {
         OptionIter::new(self.by_movie.get(series_id).map(|it| it.iter())).copied()
     }
 
+    /// Get remotes by episode.
+    pub(crate) fn get_by_episode(
+        &self,
+        episode_id: &EpisodeId,
+    ) -> impl ExactSizeIterator<Item = RemoteEpisodeId> + '_ {
+        OptionIter::new(self.by_episode.get(episode_id).map(|it| it.iter())).copied()
+    }
+
     /// Insert a series remote.
     pub(crate) fn insert_series(&mut self, remote_id: RemoteId, series_id: SeriesId) -> bool {
         let mut inner = self.inner.lock();
@@ -60,6 +75,36 @@ impl Database {
         !matches!(replaced, Some(id) if id == series_id)
     }
 
+    /// Replace all remotes associated with a series.
+    pub(crate) fn replace_series<I>(&mut self, series_id: SeriesId, remote_ids: I) -> bool
+    where
+        I: IntoIterator<Item = RemoteId>,
+    {
+        let remote_ids = remote_ids.into_iter().collect::<BTreeSet<_>>();
+
+        let mut inner = self.inner.lock();
+        let mut changed = false;
+
+        for remote_id in self.by_series.remove(&series_id).unwrap_or_default() {
+            if !remote_ids.contains(&remote_id) {
+                inner.series.remove(&remote_id);
+                changed = true;
+            }
+        }
+
+        for &remote_id in &remote_ids {
+            let replaced = inner.series.insert(remote_id, series_id);
+            unindex(&mut self.by_series, replaced, series_id, &remote_id);
+            changed |= !matches!(replaced, Some(id) if id == series_id);
+        }
+
+        if !remote_ids.is_empty() {
+            self.by_series.insert(series_id, remote_ids);
+        }
+
+        changed
+    }
+
     /// Insert a movie remote.
     pub(crate) fn insert_movie(&mut self, remote_id: RemoteId, movie_id: MovieId) -> bool {
         let mut inner = self.inner.lock();
@@ -68,6 +113,71 @@ impl Database {
         !matches!(replaced, Some(id) if id == movie_id)
     }
 
+    /// Replace all remotes associated with a movie.
+    pub(crate) fn replace_movie<I>(&mut self, movie_id: MovieId, remote_ids: I) -> bool
+    where
+        I: IntoIterator<Item = RemoteId>,
+    {
+        let remote_ids = remote_ids.into_iter().collect::<BTreeSet<_>>();
+
+        let mut inner = self.inner.lock();
+        let mut changed = false;
+
+        for remote_id in self.by_movie.remove(&movie_id).unwrap_or_default() {
+            if !remote_ids.contains(&remote_id) {
+                inner.movies.remove(&remote_id);
+                changed = true;
+            }
+        }
+
+        for &remote_id in &remote_ids {
+            let replaced = inner.movies.insert(remote_id, movie_id);
+            unindex(&mut self.by_movie, replaced, movie_id, &remote_id);
+            changed |= !matches!(replaced, Some(id) if id == movie_id);
+        }
+
+        if !remote_ids.is_empty() {
+            self.by_movie.insert(movie_id, remote_ids);
+        }
+
+        changed
+    }
+
+    /// Move all remotes of one movie over to another, returning if anything
+    /// changed.
+    pub(crate) fn merge_movie(&mut self, keep: MovieId, drop: MovieId) -> bool {
+        let mut inner = self.inner.lock();
+        let mut changed = false;
+
+        for id in inner.movies.values_mut() {
+            if *id == drop {
+                *id = keep;
+                changed = true;
+            }
+        }
+
+        if let Some(remote_ids) = self.by_movie.remove(&drop) {
+            let by_movie = self.by_movie.entry(keep).or_default();
+
+            for remote_id in remote_ids {
+                // NB: The remote might since have been claimed by some other
+                // movie.
+                match inner.movies.get(&remote_id) {
+                    Some(id) if *id != keep => continue,
+                    Some(..) => {}
+                    None => {
+                        inner.movies.insert(remote_id, keep);
+                        changed = true;
+                    }
+                }
+
+                by_movie.insert(remote_id);
+            }
+        }
+
+        changed
+    }
+
     /// Insert an episode remote.
     pub(crate) fn insert_episode(
         &mut self,
@@ -76,9 +186,36 @@ impl Database {
     ) -> bool {
         let mut inner = self.inner.lock();
         let replaced = inner.episodes.insert(remote_id, episode_id);
+        index_episode(&mut self.by_episode, replaced, remote_id, episode_id);
         !matches!(replaced, Some(id) if id == episode_id)
     }
 
+    /// Replace all remotes associated with an episode.
+    pub(crate) fn replace_episode<I>(&mut self, episode_id: EpisodeId, remote_ids: I) -> bool
+    where
+        I: IntoIterator<Item = RemoteEpisodeId>,
+    {
+        let remote_ids = remote_ids.into_iter().collect::<BTreeSet<_>>();
+
+        let mut inner = self.inner.lock();
+        let mut changed = false;
+
+        for remote_id in self.by_episode.remove(&episode_id).unwrap_or_default() {
+            if !remote_ids.contains(&remote_id) {
+                inner.episodes.remove(&remote_id);
+                changed = true;
+            }
+        }
+
+        for remote_id in remote_ids {
+            let replaced = inner.episodes.insert(remote_id, episode_id);
+            index_episode(&mut self.by_episode, replaced, remote_id, episode_id);
+            changed |= !matches!(replaced, Some(id) if id == episode_id);
+        }
+
+        changed
+    }
+
     /// Construct a data proxy used when querying remote sources.
     pub(crate) fn proxy(&self) -> Proxy {
         Proxy {
@@ -122,6 +259,38 @@ impl Database {
     }
 }
 
+/// Update the episode index after a remote has been inserted, where `replaced`
+/// is the episode the remote previously belonged to.
+fn index_episode(
+    by_episode: &mut HashMap<EpisodeId, BTreeSet<RemoteEpisodeId>>,
+    replaced: Option<EpisodeId>,
+    remote_id: RemoteEpisodeId,
+    episode_id: EpisodeId,
+) {
+    unindex(by_episode, replaced, episode_id, &remote_id);
+    by_episode.entry(episode_id).or_default().insert(remote_id);
+}
+
+/// Remove a remote from the index of whatever it belonged to before it was
+/// replaced by `id`.
+fn unindex<K, V>(index: &mut HashMap<K, BTreeSet<V>>, replaced: Option<K>, id: K, remote_id: &V)
+where
+    K: Copy + Eq + Hash,
+    V: Ord,
+{
+    let Some(replaced) = replaced.filter(|r| *r != id) else {
+        return;
+    };
+
+    if let Some(remote_ids) = index.get_mut(&replaced) {
+        remote_ids.remove(remote_id);
+
+        if remote_ids.is_empty() {
+            index.remove(&replaced);
+        }
+    }
+}
+
 pub(crate) struct Proxy {
     inner: Arc<Mutex<Inner>>,
 }
@@ -35,14 +35,23 @@ impl Database {
         self.data.insert(series.id, series);
     }
 
-    /// Iterate over all series in the database in some random.
-    pub(crate) fn iter(&self) -> impl ExactSizeIterator<Item = &Series> {
-        self.data.values()
+    /// Iterate over all series in the database in some random, excluding
+    /// series which have been removed.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &Series> {
+        self.data.values().filter(|s| s.removed.is_none())
     }
 
-    /// Iterate over all series in the database in some order.
+    /// Iterate over all series in the database in some order, excluding series
+    /// which have been removed.
     pub(crate) fn iter_by_name(&self) -> impl DoubleEndedIterator<Item = &Series> {
         Iter::new(self.by_name.iter().map(|(_, key)| key), &self.data)
+            .filter(|s| s.removed.is_none())
+    }
+
+    /// Iterate over all series which have been removed, but not yet deleted.
+    pub(crate) fn iter_removed(&self) -> impl DoubleEndedIterator<Item = &Series> {
+        Iter::new(self.by_name.iter().map(|(_, key)| key), &self.data)
+            .filter(|s| s.removed.is_some())
     }
 
     /// Export series data.
@@ -15,12 +15,18 @@ struct Entry {
     last_sync: Option<DateTime<Utc>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     last_modified: Option<DateTime<Utc>>,
+    /// Etags of each page of episodes, for remotes which list them separately.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    episode_etags: Vec<Etag>,
 }
 
 impl Entry {
     /// Test if entry is empty.
     fn is_empty(&self) -> bool {
-        self.etag.is_none() && self.last_sync.is_none() && self.last_modified.is_none()
+        self.etag.is_none()
+            && self.last_sync.is_none()
+            && self.last_modified.is_none()
+            && self.episode_etags.is_empty()
     }
 }
 
@@ -105,6 +111,19 @@ impl Database {
         true
     }
 
+    /// Insert last episode etags.
+    #[must_use]
+    pub(crate) fn update_episode_etags(&mut self, id: RemoteId, etags: Vec<Etag>) -> bool {
+        let e = self.data.entry(id).or_default();
+
+        if e.episode_etags == etags {
+            return false;
+        }
+
+        e.episode_etags = etags;
+        true
+    }
+
     /// Get last sync for the given time series.
     pub(crate) fn last_sync(&self, id: &RemoteId) -> Option<&DateTime<Utc>> {
         self.data.get(id)?.last_sync.as_ref()
@@ -121,6 +140,14 @@ impl Database {
         entry.etag.as_ref()
     }
 
+    /// Last episode etags for the given series id.
+    pub(crate) fn episode_etags(&self, id: &RemoteId) -> &[Etag] {
+        self.data
+            .get(id)
+            .map(|e| e.episode_etags.as_slice())
+            .unwrap_or_default()
+    }
+
     /// Clear all sync data.
     pub(crate) fn clear(&mut self) {
         self.data.clear();
@@ -42,9 +42,22 @@ pub(crate) struct Database {
     by_episode: HashMap<EpisodeId, Vec<WatchedId>>,
     by_series: HashMap<SeriesId, Vec<WatchedId>>,
     by_movie: HashMap<MovieId, Vec<WatchedId>>,
+    /// Incremented every time the watch history is modified.
+    revision: u64,
 }
 
 impl Database {
+    /// Get the current revision of the watch history.
+    #[inline]
+    pub(crate) fn revision(&self) -> u64 {
+        self.revision
+    }
+
+    /// Iterate over all watches.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &Watched> {
+        self.data.values()
+    }
+
     /// Get all watches for the given episode.
     pub(crate) fn by_episode(
         &self,
@@ -83,10 +96,18 @@ impl Database {
         Iter::new(indexes.iter(), &self.data)
     }
 
+    /// Get a mutable reference to a single watch.
+    pub(crate) fn get_mut(&mut self, id: &WatchedId) -> Option<&mut Watched> {
+        let w = self.data.get_mut(id)?;
+        self.revision += 1;
+        Some(w)
+    }
+
     /// Insert a new entry into watch history.
     pub(crate) fn insert(&mut self, w: Watched) {
         let id = w.id;
         let kind = w.kind;
+        self.revision += 1;
 
         if let Some(w) = self.data.insert(id, w) {
             match &w.kind {
@@ -117,6 +138,8 @@ impl Database {
             return;
         };
 
+        self.revision += 1;
+
         for id in indexes {
             let Some(w) = self.data.remove(&id) else {
                 continue;
@@ -141,6 +164,7 @@ impl Database {
         };
 
         let len = removed.len();
+        self.revision += 1;
 
         for id in removed {
             let Some(w) = self.data.remove(&id) else {
@@ -164,6 +188,7 @@ impl Database {
     /// Remove a single watch by id.
     pub(crate) fn remove_watch(&mut self, id: &WatchedId) -> Option<Watched> {
         let w = self.data.remove(id)?;
+        self.revision += 1;
 
         match w.kind {
             WatchedKind::Series { series, episode } => {
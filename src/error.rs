@@ -4,6 +4,8 @@ use anyhow::Error;
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
+use crate::api::common::{self, RateLimited};
+
 /// Identifier used to look up errors caused by specific actions..
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub(crate) enum ErrorId {
@@ -17,6 +19,11 @@ pub(crate) struct ErrorInfo {
     pub(crate) timestamp: DateTime<Utc>,
     pub(crate) message: String,
     pub(crate) causes: Vec<String>,
+    /// Number of seconds to wait before retrying, if the error was caused by
+    /// a remote rate limiting us.
+    pub(crate) retry_after: Option<u64>,
+    /// If the error is transient, so that trying again might succeed.
+    pub(crate) transient: bool,
 }
 
 impl ErrorInfo {
@@ -34,11 +41,20 @@ impl ErrorInfo {
             causes.push(cause.to_string());
         }
 
+        let retry_after = error
+            .chain()
+            .find_map(|e| e.downcast_ref::<RateLimited>())
+            .map(|e| e.retry_after);
+
+        let transient = common::is_transient(&error);
+
         Self {
             id,
             timestamp: Utc::now(),
             message,
             causes,
+            retry_after,
+            transient,
         }
     }
 }
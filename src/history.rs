@@ -10,9 +10,11 @@ use crate::page;
 pub(crate) enum Page {
     Dashboard,
     WatchNext(page::watch_next::State),
+    CatchUp,
     Search(page::search::State),
     SeriesList,
     MoviesList,
+    Collections,
     Series(page::series::State),
     Movie(page::movie::State),
     Settings,
@@ -1,33 +1,208 @@
-use std::fs::File;
-use std::path::Path;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Write as _;
+use std::fs::OpenOptions;
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
-use anyhow::{Context, Result};
-use chrono::{DateTime, Utc};
+use anyhow::{anyhow, bail, Context, Result};
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use tokio::runtime;
 
-use crate::model::{Raw, RemoteId, SeasonNumber, SeriesId};
-use crate::search::Tokens;
+use crate::api::common::RateLimited;
+use crate::model::{
+    Raw, RemoteEpisodeId, RemoteId, RemoteLink, SearchSeries, SeasonNumber, SeriesId, WatchedKind,
+};
+use crate::queue::TaskKind;
+use crate::search::{self, Tokens};
 use crate::service::Service;
 
+/// The minimum score a search result needs to be automatically picked when
+/// matching a show by title.
+const MATCH_THRESHOLD: f32 = 0.8;
+
+/// Progress of an import, stored next to the file being imported so that an
+/// aborted import can resume where it stopped.
+#[derive(Debug, Deserialize, Serialize)]
+struct Checkpoint {
+    /// Hash of the file being imported.
+    hash: String,
+    /// The filter used for the import.
+    #[serde(default)]
+    filter: Option<String>,
+    /// If existing watch history was removed.
+    #[serde(default)]
+    remove: bool,
+    /// If missing shows were imported.
+    #[serde(default)]
+    missing: bool,
+    /// Index of the next entry to import.
+    next: usize,
+}
+
+impl Checkpoint {
+    fn path(path: &Path) -> PathBuf {
+        path.with_extension("checkpoint.json")
+    }
+
+    /// Load a checkpoint, ignoring it if it belongs to different input or
+    /// options, since entries before it might not have been imported.
+    fn load(path: &Path, key: &Checkpoint) -> Result<Option<Self>> {
+        let bytes = match std::fs::read(path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e).with_context(|| anyhow!("{}", path.display())),
+        };
+
+        let checkpoint: Self =
+            serde_json::from_slice(&bytes).with_context(|| anyhow!("{}", path.display()))?;
+
+        if checkpoint.hash != key.hash
+            || checkpoint.filter != key.filter
+            || checkpoint.remove != key.remove
+            || checkpoint.missing != key.missing
+        {
+            tracing::info!(
+                "Input or options have changed, ignoring checkpoint {}",
+                path.display()
+            );
+            return Ok(None);
+        }
+
+        Ok(Some(checkpoint))
+    }
+
+    fn save(&self, path: &Path) -> Result<()> {
+        let bytes = serde_json::to_vec(self)?;
+        std::fs::write(path, bytes).with_context(|| anyhow!("{}", path.display()))
+    }
+}
+
+/// How an import is performed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportMode {
+    /// Only resolve what would be imported without changing anything.
+    DryRun,
+    /// Import into the database.
+    Apply,
+}
+
+/// A show which would be downloaded by an import.
+#[derive(Debug)]
+struct MissingShow {
+    title: String,
+    imdb: Option<String>,
+}
+
+/// Summary of an import.
+#[derive(Debug, Default)]
+pub struct ImportReport {
+    /// Episodes which get a watch added.
+    episodes: usize,
+    /// Shows which need to be downloaded.
+    missing: Vec<MissingShow>,
+    /// Entries which were skipped.
+    skipped: usize,
+}
+
+impl ImportReport {
+    /// Record a show which would be downloaded and its episodes.
+    fn add_missing(&mut self, entry: &Entry) {
+        self.episodes += entry
+            .seasons
+            .iter()
+            .map(|s| s.episodes.len())
+            .sum::<usize>();
+        self.missing.push(MissingShow {
+            title: entry.show.to_string(),
+            imdb: entry.show.ids.imdb.clone().filter(|id| !id.is_empty()),
+        });
+    }
+}
+
+impl std::fmt::Display for ImportReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Episodes to add: {}", self.episodes)?;
+        writeln!(f, "Shows to download: {}", self.missing.len())?;
+        writeln!(f, "Skipped: {}", self.skipped)?;
+
+        for show in &self.missing {
+            match &show.imdb {
+                Some(imdb) => writeln!(f, "  {} ({imdb})", show.title)?,
+                None => writeln!(f, "  {} (no imdb id)", show.title)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
 /// Import trakt watched history from the given path.
+///
+/// Shows which couldn't be matched are listed in the `review` file.
+///
+/// Progress is recorded in a checkpoint file next to the input once an entry
+/// has been saved, so that an aborted import with the same options resumes
+/// after it. The checkpoint is removed once the import completes.
+///
+/// With [`ImportMode::DryRun`] nothing is changed or saved, and the returned
+/// report describes what would be imported.
 pub fn import_trakt_watched(
     service: &mut Service,
     path: &Path,
     filter: Option<&str>,
     remove: bool,
     import_missing: bool,
-) -> Result<()> {
+    review: &Path,
+    mode: ImportMode,
+) -> Result<ImportReport> {
     let runtime = runtime::Builder::new_current_thread()
         .enable_all()
         .build()?;
 
+    let dry_run = matches!(mode, ImportMode::DryRun);
+    // Progress can only be recorded if it's actually saved.
+    let checkpoints = !dry_run && !service.is_do_not_save();
+
+    let bytes = std::fs::read(path).with_context(|| anyhow!("{}", path.display()))?;
+    let rows: Vec<serde_json::Value> = serde_json::from_slice(&bytes)?;
+
+    let mut checkpoint = Checkpoint {
+        hash: format!("{:032x}", crate::cache::hash128(&bytes)),
+        filter: filter.map(str::to_owned),
+        remove,
+        missing: import_missing,
+        next: 0,
+    };
+
     let filter = filter.map(Tokens::new);
+    let checkpoint_path = Checkpoint::path(path);
+
+    let start = if checkpoints {
+        Checkpoint::load(&checkpoint_path, &checkpoint)?.map_or(0, |c| c.next)
+    } else {
+        0
+    };
 
-    let f = File::open(path)?;
-    let rows: Vec<serde_json::Value> = serde_json::from_reader(f)?;
+    if start > 0 {
+        tracing::info!("Resuming import at entry {start} of {}", rows.len());
+    }
 
-    for (index, row) in rows.into_iter().enumerate() {
+    // Shows from an earlier run are already listed when resuming.
+    if start == 0 && !dry_run {
+        if let Err(e) = std::fs::remove_file(review) {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                return Err(e).with_context(|| anyhow!("{}", review.display()));
+            }
+        }
+    }
+
+    let mut unmatched = String::new();
+    let mut any_unmatched = false;
+    let mut report = ImportReport::default();
+
+    'rows: for (index, row) in rows.into_iter().enumerate().skip(start) {
         let entry: Entry = serde_json::from_value(row.clone())?;
 
         if let Some(filter) = &filter {
@@ -38,59 +213,163 @@ pub fn import_trakt_watched(
 
         tracing::trace!("{index}: {row}");
 
-        let mut ids = Vec::new();
+        let now = Utc::now();
 
-        let tmdb_remote_id = RemoteId::Tmdb {
-            id: entry.show.ids.tmdb,
-        };
+        // NB: Rows which can't be resolved to a series are counted as skipped.
+        let series_id = 'resolve: {
+            let mut ids = Vec::new();
 
-        ids.push(RemoteId::Tvdb {
-            id: entry.show.ids.tvdb,
-        });
-        ids.push(tmdb_remote_id);
-        ids.push(RemoteId::Imdb {
-            id: Raw::new(&entry.show.ids.imdb).context("imdb id")?,
-        });
+            let tvdb_remote_id = entry
+                .show
+                .ids
+                .tvdb
+                .filter(|&id| id != 0)
+                .map(|id| RemoteId::Tvdb { id });
 
-        let now = Utc::now();
+            let mut tmdb_remote_id = entry
+                .show
+                .ids
+                .tmdb
+                .filter(|&id| id != 0)
+                .map(|id| RemoteId::Tmdb { id });
 
-        let series_id = match service.existing_by_remote_ids(ids) {
-            Some(series_id) => {
-                if service.series(&series_id).is_none() && import_missing {
-                    let Some(..) = runtime.block_on(download_series(
-                        service,
-                        &now,
-                        &entry,
-                        &tmdb_remote_id,
-                    ))?
-                    else {
-                        continue;
-                    };
-                }
+            let imdb_remote_id = match entry.show.ids.imdb.as_deref().filter(|id| !id.is_empty()) {
+                Some(imdb) => Some(RemoteId::Imdb {
+                    id: Raw::new(imdb).context("imdb id")?,
+                }),
+                None => None,
+            };
 
-                series_id
-            }
-            None => {
-                if !import_missing {
-                    tracing::warn!(
-                        "show `{}` is not a local series and not configured to import missing",
-                        entry.show.title
-                    );
-                    continue;
-                };
+            ids.extend(tvdb_remote_id);
+            ids.extend(tmdb_remote_id);
+            ids.extend(imdb_remote_id);
 
-                let Some(id) =
-                    runtime.block_on(download_series(service, &now, &entry, &tmdb_remote_id))?
-                else {
-                    continue;
+            if ids.is_empty() {
+                let results = runtime.block_on(service.search_series_tmdb(&entry.show.title));
+
+                let results = match results {
+                    Ok(results) => results,
+                    Err(error) => {
+                        tracing::error!("Failed to search for `{}`: {error}", entry.show.title);
+                        writeln!(unmatched, "{}: search failed: {error}", entry.show)?;
+                        break 'resolve None;
+                    }
                 };
 
-                id
+                match best_match(&entry.show, &results) {
+                    Some((score, series)) if score >= MATCH_THRESHOLD => {
+                        tracing::info!(
+                            "Matched `{}` to `{}` ({}) with score {score:.2}",
+                            entry.show,
+                            series.name,
+                            series.id
+                        );
+
+                        tmdb_remote_id = Some(series.id);
+                        ids.push(series.id);
+                    }
+                    Some((score, series)) => {
+                        tracing::warn!(
+                            "Low confidence match for `{}`: `{}` ({}) with score {score:.2}",
+                            entry.show,
+                            series.name,
+                            series.id
+                        );
+
+                        writeln!(
+                            unmatched,
+                            "{}: low confidence match `{}` ({}) with score {score:.2}",
+                            entry.show, series.name, series.id
+                        )?;
+                        break 'resolve None;
+                    }
+                    None => {
+                        tracing::warn!("No match for `{}`", entry.show);
+                        writeln!(unmatched, "{}: no match", entry.show)?;
+                        break 'resolve None;
+                    }
+                }
+            }
+
+            let download_remote_id = tmdb_remote_id.or(tvdb_remote_id).or(imdb_remote_id);
+
+            match service.existing_by_remote_ids(ids) {
+                Some(series_id) => {
+                    if service.series(&series_id).is_none() && import_missing {
+                        let Some(remote_id) = &download_remote_id else {
+                            writeln!(unmatched, "{}: no id to download with", entry.show)?;
+                            break 'resolve None;
+                        };
+
+                        if dry_run {
+                            report.add_missing(&entry);
+                            continue 'rows;
+                        }
+
+                        let Some(..) =
+                            runtime.block_on(add_series(service, &now, &entry.show, remote_id))?
+                        else {
+                            break 'resolve None;
+                        };
+                    }
+
+                    Some(series_id)
+                }
+                None => {
+                    if !import_missing {
+                        tracing::warn!(
+                            "show `{}` is not a local series and not configured to import missing",
+                            entry.show.title
+                        );
+                        break 'resolve None;
+                    };
+
+                    let Some(remote_id) = &download_remote_id else {
+                        writeln!(unmatched, "{}: no id to download with", entry.show)?;
+                        break 'resolve None;
+                    };
+
+                    if dry_run {
+                        report.add_missing(&entry);
+                        continue 'rows;
+                    }
+
+                    let Some(id) =
+                        runtime.block_on(add_series(service, &now, &entry.show, remote_id))?
+                    else {
+                        break 'resolve None;
+                    };
+
+                    Some(id)
+                }
             }
         };
 
+        let Some(series_id) = series_id else {
+            report.skipped += 1;
+            continue;
+        };
+
         tracing::trace!("{index}: {series_id}: {entry:?}");
 
+        if dry_run {
+            for season in &entry.seasons {
+                for import in &season.episodes {
+                    let Some(episode) = service.find_episode_by(&series_id, |e| {
+                        e.season == season.season_number() && e.number == import.number
+                    }) else {
+                        continue;
+                    };
+
+                    if remove || service.watched_by_episode(&episode.id).next().is_none() {
+                        report.episodes += 1;
+                    }
+                }
+            }
+
+            continue;
+        }
+
         if remove {
             service.clear_watches(&series_id);
         }
@@ -100,7 +379,7 @@ pub fn import_trakt_watched(
         for season in &entry.seasons {
             for import in &season.episodes {
                 let Some(episode) = service.find_episode_by(&series_id, |e| {
-                    e.season == SeasonNumber::Number(season.number) && e.number == import.number
+                    e.season == season.season_number() && e.number == import.number
                 }) else {
                     continue;
                 };
@@ -121,27 +400,466 @@ pub fn import_trakt_watched(
 
         service.populate_pending(&now, &series_id);
         runtime.block_on(service.save_changes())?;
+
+        if checkpoints {
+            any_unmatched |= write_review(review, &mut unmatched)?;
+            checkpoint.next = index + 1;
+            checkpoint.save(&checkpoint_path)?;
+        }
+    }
+
+    runtime.shutdown_background();
+
+    if dry_run {
+        return Ok(report);
+    }
+
+    any_unmatched |= write_review(review, &mut unmatched)?;
+
+    if any_unmatched {
+        tracing::warn!("Some shows could not be matched, see: {}", review.display());
+    }
+
+    if checkpoints {
+        if let Err(e) = std::fs::remove_file(&checkpoint_path) {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                return Err(e).with_context(|| anyhow!("{}", checkpoint_path.display()));
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+/// Import watch history from a CSV file with the columns
+/// `imdb_id,season,episode,watched_at`.
+///
+/// The IMDb id can either identify the episode or the series, in which case
+/// the episode is looked up by season and episode number. Episodes which
+/// already have a watch are skipped, so the import can safely be re-run.
+/// Rows which couldn't be imported are reported once the import is done.
+pub fn import_csv_watched(
+    service: &mut Service,
+    path: &Path,
+    remove: bool,
+    import_missing: bool,
+) -> Result<()> {
+    let runtime = runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?;
+
+    let input = std::fs::read_to_string(path).with_context(|| anyhow!("{}", path.display()))?;
+
+    let now = Utc::now();
+    let mut errors = Vec::new();
+    let mut series_by_imdb = HashMap::<Raw<16>, Option<SeriesId>>::new();
+    let mut touched = HashSet::new();
+    let mut imported = 0usize;
+
+    for (index, line) in input.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.trim();
+
+        if line.is_empty() || (index == 0 && line.starts_with("imdb_id")) {
+            continue;
+        }
+
+        let row = match CsvRow::parse(line) {
+            Ok(row) => row,
+            Err(error) => {
+                errors.push((line_number, error.to_string()));
+                continue;
+            }
+        };
+
+        let (series_id, episode_id) = match service
+            .existing_episode_by_remote_id(&RemoteEpisodeId::Imdb { id: row.imdb_id })
+            .and_then(|id| service.episode(&id))
+        {
+            Some(e) => (*e.series(), e.id),
+            None => {
+                let series_id = match series_by_imdb.get(&row.imdb_id) {
+                    Some(series_id) => *series_id,
+                    None => {
+                        let series_id = runtime.block_on(resolve_imdb(
+                            service,
+                            &now,
+                            row.imdb_id,
+                            import_missing,
+                        ))?;
+                        series_by_imdb.insert(row.imdb_id, series_id);
+                        series_id
+                    }
+                };
+
+                let Some(series_id) = series_id else {
+                    errors.push((line_number, format!("{}: no matching series", row.imdb_id)));
+                    continue;
+                };
+
+                let Some(e) = service.find_episode_by(&series_id, |e| {
+                    e.season == row.season && e.number == row.episode
+                }) else {
+                    errors.push((
+                        line_number,
+                        format!(
+                            "{}: no episode {} in {}",
+                            row.imdb_id, row.episode, row.season
+                        ),
+                    ));
+                    continue;
+                };
+
+                (series_id, e.id)
+            }
+        };
+
+        if touched.insert(series_id) && remove {
+            service.clear_watches(&series_id);
+        }
+
+        if service.watched_by_episode(&episode_id).next().is_some() {
+            continue;
+        }
+
+        tracing::trace!(?line_number, ?episode_id, "Watch");
+        service.insert_new_watch(series_id, episode_id, row.watched_at);
+        imported += 1;
+    }
+
+    for series_id in &touched {
+        service.populate_pending(&now, series_id);
     }
 
+    runtime.block_on(service.save_changes())?;
     runtime.shutdown_background();
+
+    tracing::info!("Imported {imported} watches from {}", path.display());
+
+    for (line_number, error) in &errors {
+        tracing::warn!("{}:{line_number}: {error}", path.display());
+    }
+
+    if !errors.is_empty() {
+        tracing::warn!("{} rows could not be imported", errors.len());
+    }
+
     Ok(())
 }
 
+/// Add series by remote ids like `imdb:tt0903747`, `tmdb:1396` or
+/// `tvdb:81189`.
+///
+/// A download task is queued for every series, and the queue is processed
+/// until all of them have completed. Series which already exist are marked as
+/// tracked.
+pub fn add_series_by_remote(service: &mut Service, remotes: &[String]) -> Result<()> {
+    let runtime = runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?;
+
+    let mut remote_ids = HashSet::new();
+
+    for remote in remotes {
+        let remote_id = remote
+            .parse::<RemoteId>()
+            .with_context(|| anyhow!("{remote}: bad remote id"))?;
+
+        service.push_task_without_delay(TaskKind::DownloadSeriesByRemoteId { remote_id });
+        remote_ids.insert(remote_id);
+    }
+
+    let mut added = 0;
+    let mut failed = 0;
+
+    loop {
+        let Some(id) = service
+            .pending_tasks()
+            .next()
+            .filter(|t| {
+                matches!(
+                    &t.kind,
+                    TaskKind::DownloadSeriesByRemoteId { remote_id } if remote_ids.contains(remote_id)
+                )
+            })
+            .map(|t| t.id)
+        else {
+            break;
+        };
+
+        // NB: Honor any backoff requested by a remote which rate limited us.
+        if let Some((seconds, _)) = service.next_task_sleep(&Utc::now()) {
+            runtime.block_on(tokio::time::sleep(Duration::from_secs(seconds)));
+        }
+
+        let now = Utc::now();
+
+        let Some(task) = service.next_task(&now, Some(id)) else {
+            break;
+        };
+
+        let TaskKind::DownloadSeriesByRemoteId { remote_id } = task.kind else {
+            service.complete_task(&now, task);
+            continue;
+        };
+
+        // NB: This marks an existing series as tracked.
+        if service.is_series_by_remote(&remote_id) {
+            tracing::info!("{remote_id}: Already added, marked as tracked");
+            service.complete_task(&now, task);
+            continue;
+        }
+
+        tracing::info!("{remote_id}: Downloading");
+
+        let result = runtime.block_on(service.download_series(&remote_id, None, &[], None));
+        let now = Utc::now();
+
+        match result {
+            Ok(Some(new_series)) => {
+                service.complete_task(&now, task);
+                service.insert_series(&now, new_series);
+                added += 1;
+            }
+            Ok(None) => {
+                tracing::warn!("{remote_id}: Not found");
+                service.complete_task(&now, task);
+                failed += 1;
+            }
+            Err(error) => {
+                let retry_after = error
+                    .chain()
+                    .find_map(|e| e.downcast_ref::<RateLimited>())
+                    .map(|e| e.retry_after);
+
+                if let Some(seconds) = retry_after {
+                    service.retry_task_after(&now, task, seconds);
+                    continue;
+                }
+
+                tracing::error!("{remote_id}: Failed to download: {error}");
+                service.complete_task(&now, task);
+                failed += 1;
+            }
+        }
+    }
+
+    runtime.block_on(service.save_changes())?;
+    runtime.shutdown_background();
+
+    tracing::info!("Added {added} series");
+
+    if failed > 0 {
+        bail!("Failed to add {failed} series");
+    }
+
+    Ok(())
+}
+
+/// Resolve a series by its IMDb id, downloading it if it's missing and
+/// `import_missing` is set.
+async fn resolve_imdb(
+    service: &mut Service,
+    now: &DateTime<Utc>,
+    imdb_id: Raw<16>,
+    import_missing: bool,
+) -> Result<Option<SeriesId>> {
+    if let Some(series_id) = service.existing_by_remote_ids([RemoteId::Imdb { id: imdb_id }]) {
+        if service.series(&series_id).is_some() {
+            return Ok(Some(series_id));
+        }
+    }
+
+    if !import_missing {
+        tracing::warn!("{imdb_id} is not a local series and not configured to import missing");
+        return Ok(None);
+    }
+
+    let remote_id = match service.find_imdb(imdb_id).await {
+        Ok(Some(RemoteLink::Series(remote_id))) => remote_id,
+        Ok(..) => return Ok(None),
+        Err(error) => {
+            tracing::error!("Failed to look up {imdb_id}: {error}");
+            return Ok(None);
+        }
+    };
+
+    if let Some(series_id) = service.existing_by_remote_ids([remote_id]) {
+        if service.series(&series_id).is_some() {
+            return Ok(Some(series_id));
+        }
+    }
+
+    download_series(service, now, &imdb_id.to_string(), &remote_id).await
+}
+
+/// Export watch history to the given path in the same format as the trakt
+/// backup which is read by [`import_trakt_watched`].
+///
+/// Only shows matching `filter` are exported if it's specified. Shows without
+/// any remote identifier can't be matched by other trackers and are skipped.
+pub fn export_trakt_watched(service: &Service, path: &Path, filter: Option<&str>) -> Result<()> {
+    let filter = filter.map(Tokens::new);
+
+    let mut by_series = BTreeMap::<SeriesId, BTreeMap<(u32, u32), Episode>>::new();
+
+    for w in service.watched() {
+        let WatchedKind::Series { series, episode } = &w.kind else {
+            continue;
+        };
+
+        let Some(e) = service.episode(episode) else {
+            tracing::warn!("{series}: skipping watch of missing episode {episode}");
+            continue;
+        };
+
+        let season = match e.season {
+            SeasonNumber::Specials => 0,
+            SeasonNumber::Number(n) => n,
+        };
+
+        let episode = by_series
+            .entry(*series)
+            .or_default()
+            .entry((season, e.number))
+            .or_insert(Episode {
+                last_watched_at: w.timestamp,
+                number: e.number,
+                plays: Some(0),
+            });
+
+        episode.last_watched_at = episode.last_watched_at.max(w.timestamp);
+        *episode.plays.get_or_insert(0) += 1;
+    }
+
+    let mut entries = Vec::new();
+
+    for series in service.series_by_name() {
+        let Some(episodes) = by_series.remove(&series.id) else {
+            continue;
+        };
+
+        if let Some(filter) = &filter {
+            if !filter.matches(&series.title) {
+                continue;
+            }
+        }
+
+        let mut ids = Ids::default();
+
+        for remote_id in series
+            .remote_id
+            .into_iter()
+            .chain(service.remotes_by_series(&series.id))
+        {
+            match remote_id {
+                RemoteId::Tvdb { id } => {
+                    ids.tvdb.get_or_insert(id);
+                }
+                RemoteId::Tmdb { id } => {
+                    ids.tmdb.get_or_insert(id);
+                }
+                RemoteId::Imdb { id } => {
+                    ids.imdb.get_or_insert_with(|| id.to_string());
+                }
+            }
+        }
+
+        if ids.tvdb.is_none() && ids.tmdb.is_none() && ids.imdb.is_none() {
+            tracing::warn!(
+                "Skipping `{}` since it doesn't have any remote ids",
+                series.title
+            );
+            continue;
+        }
+
+        let mut seasons = Vec::<Season>::new();
+
+        for ((number, _), episode) in episodes {
+            match seasons.last_mut() {
+                Some(season) if season.number == number => season.episodes.push(episode),
+                _ => seasons.push(Season {
+                    number,
+                    episodes: vec![episode],
+                }),
+            }
+        }
+
+        entries.push(Entry {
+            show: Show {
+                title: series.title.clone(),
+                year: series.first_air_date.map(|d| d.year()),
+                ids,
+            },
+            seasons,
+        });
+    }
+
+    tracing::info!("Exporting {} shows to {}", entries.len(), path.display());
+
+    let bytes = serde_json::to_vec_pretty(&entries)?;
+    std::fs::write(path, bytes).with_context(|| anyhow!("{}", path.display()))?;
+    Ok(())
+}
+
+/// Append unmatched shows to the review file, returning if any were written.
+fn write_review(review: &Path, unmatched: &mut String) -> Result<bool> {
+    if unmatched.is_empty() {
+        return Ok(false);
+    }
+
+    let mut f = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(review)
+        .with_context(|| anyhow!("{}", review.display()))?;
+
+    f.write_all(unmatched.as_bytes())
+        .with_context(|| anyhow!("{}", review.display()))?;
+
+    unmatched.clear();
+    Ok(true)
+}
+
+/// Find the search result which best matches the given show, and its score.
+fn best_match<'a>(show: &Show, results: &'a [SearchSeries]) -> Option<(f32, &'a SearchSeries)> {
+    let mut best = None::<(f32, &SearchSeries)>;
+
+    for series in results {
+        let mut score = search::similarity(&show.title, &series.name);
+
+        let aired = series.first_aired.map(|d| d.year());
+
+        score *= match (show.year, aired) {
+            (Some(a), Some(b)) if a == b => 1.0,
+            (Some(a), Some(b)) if a.abs_diff(b) == 1 => 0.9,
+            (Some(..), Some(..)) => 0.5,
+            _ => 0.9,
+        };
+
+        if best.map(|(s, _)| score > s).unwrap_or(true) {
+            best = Some((score, series));
+        }
+    }
+
+    best
+}
+
 async fn download_series(
     service: &mut Service,
     now: &DateTime<Utc>,
-    entry: &Entry,
+    title: &str,
     remote_id: &RemoteId,
 ) -> Result<Option<SeriesId>> {
-    tracing::info!("Downloading `{}`", entry.show.title);
+    tracing::info!("Downloading `{title}`");
 
-    let new_series = match service.download_series(remote_id, None, None).await {
+    let new_series = match service.download_series(remote_id, None, &[], None).await {
         Ok(Some(new_series)) => new_series,
         Ok(None) => {
             anyhow::bail!("Empty response")
         }
         Err(error) => {
-            tracing::error!("Failed to download `{}`: {error}", entry.show.title);
+            tracing::error!("Failed to download `{title}`: {error}");
             return Ok(None);
         }
     };
@@ -152,10 +870,83 @@ async fn download_series(
     Ok(Some(series_id))
 }
 
+/// Add a missing show from an import.
+///
+/// Shows which are only known by IMDb are added with just their title if
+/// their metadata can't be downloaded.
+async fn add_series(
+    service: &mut Service,
+    now: &DateTime<Utc>,
+    show: &Show,
+    remote_id: &RemoteId,
+) -> Result<Option<SeriesId>> {
+    let RemoteId::Imdb { .. } = remote_id else {
+        return download_series(service, now, &show.title, remote_id).await;
+    };
+
+    if service.has_omdb_api_key() {
+        if let Some(series_id) = download_series(service, now, &show.title, remote_id).await? {
+            return Ok(Some(series_id));
+        }
+    }
+
+    tracing::info!("Adding `{show}` by title only");
+    let series_id = service.insert_title_only_series(now, *remote_id, &show.title, show.year);
+    service.save_changes().await?;
+    Ok(Some(series_id))
+}
+
+/// A single row in a CSV watch history.
+struct CsvRow {
+    imdb_id: Raw<16>,
+    season: SeasonNumber,
+    episode: u32,
+    watched_at: DateTime<Utc>,
+}
+
+impl CsvRow {
+    fn parse(line: &str) -> Result<Self> {
+        let mut it = line.split(',').map(str::trim);
+
+        let (Some(imdb_id), Some(season), Some(episode), Some(watched_at), None) =
+            (it.next(), it.next(), it.next(), it.next(), it.next())
+        else {
+            anyhow::bail!("expected four columns");
+        };
+
+        let imdb_id = Raw::new(imdb_id).context("bad imdb id")?;
+
+        let season = match season.parse().context("bad season")? {
+            0 => SeasonNumber::Specials,
+            n => SeasonNumber::Number(n),
+        };
+
+        let episode = episode.parse().context("bad episode")?;
+
+        let watched_at = match DateTime::parse_from_rfc3339(watched_at) {
+            Ok(watched_at) => watched_at.with_timezone(&Utc),
+            Err(..) => NaiveDate::parse_from_str(watched_at, "%Y-%m-%d")
+                .ok()
+                .and_then(|d| d.and_hms_opt(0, 0, 0))
+                .context("bad timestamp")?
+                .and_utc(),
+        };
+
+        Ok(Self {
+            imdb_id,
+            season,
+            episode,
+            watched_at,
+        })
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 struct Episode {
     last_watched_at: DateTime<Utc>,
     number: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    plays: Option<u32>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -164,13 +955,28 @@ struct Season {
     episodes: Vec<Episode>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+impl Season {
+    /// Get the season number, where season `0` holds specials.
+    fn season_number(&self) -> SeasonNumber {
+        match self.number {
+            0 => SeasonNumber::Specials,
+            n => SeasonNumber::Number(n),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
 struct Ids {
-    imdb: String,
-    slug: String,
-    tmdb: u32,
-    trakt: u32,
-    tvdb: u32,
+    #[serde(default)]
+    imdb: Option<String>,
+    #[serde(default)]
+    slug: Option<String>,
+    #[serde(default)]
+    tmdb: Option<u32>,
+    #[serde(default)]
+    trakt: Option<u32>,
+    #[serde(default)]
+    tvdb: Option<u32>,
     #[serde(default)]
     tvrage: Option<u32>,
 }
@@ -178,11 +984,67 @@ struct Ids {
 #[derive(Debug, Deserialize, Serialize)]
 struct Show {
     title: String,
+    #[serde(default)]
+    year: Option<i32>,
+    #[serde(default)]
     ids: Ids,
 }
 
+impl std::fmt::Display for Show {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.year {
+            Some(year) => write!(f, "{} ({year})", self.title),
+            None => self.title.fmt(f),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 struct Entry {
     show: Show,
     seasons: Vec<Season>,
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use crate::model::{SeasonNumber, SeriesId};
+    use crate::service::tests::{new_episode, new_series};
+    use crate::service::Service;
+
+    use super::{export_trakt_watched, import_trakt_watched, ImportMode};
+
+    #[test]
+    fn test_trakt_specials_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let now = Utc::now();
+        let series_id = SeriesId::random();
+
+        let mut special = new_episode(1);
+        special.episode.season = SeasonNumber::Specials;
+        let special_id = special.episode.id;
+
+        let mut service = Service::new(&root.join("config"), &root.join("cache")).unwrap();
+        service.insert_series(&now, new_series(series_id, vec![special, new_episode(1)]));
+        service.insert_new_watch(series_id, special_id, now);
+
+        let path = root.join("watched.json");
+        export_trakt_watched(&service, &path, None).unwrap();
+        service.clear_watches(&series_id);
+
+        import_trakt_watched(
+            &mut service,
+            &path,
+            None,
+            false,
+            false,
+            &root.join("review.txt"),
+            ImportMode::Apply,
+        )
+        .unwrap();
+
+        assert_eq!(service.watched_by_episode(&special_id).len(), 1);
+    }
+}
@@ -88,6 +88,17 @@
 //!
 //! This will take a while, so go get a ☕.
 //!
+//! Add `--import-dry-run` to see how many episodes would be added and which
+//! shows would be downloaded without changing anything.
+//!
+//! Your history can be written back out in the same format, which is useful
+//! if you want to move it to another tracker. `--import-filter` can be used to
+//! only export a single show:
+//!
+//! ```text
+//! $ ontv --export-trakt-watched watched_shows.txt
+//! ```
+//!
 //! <br>
 //!
 //! ## Storing your database in git
@@ -131,6 +142,7 @@ mod error;
 mod history;
 pub mod import;
 pub mod lock;
+pub mod log;
 mod model;
 mod page;
 mod params;
@@ -141,7 +153,7 @@ mod state;
 pub mod style;
 mod utils;
 
-pub use self::service::Service;
+pub use self::service::{Service, StatsReport};
 
 mod prelude {
     pub(crate) use anyhow::{anyhow, bail, Context, Result};
@@ -0,0 +1,175 @@
+//! Logging to a file which is rotated by size and by day.
+
+use std::borrow::Cow;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::{anyhow, Context, Result};
+use chrono::{Local, NaiveDate};
+use parking_lot::Mutex;
+use tracing_subscriber::fmt::MakeWriter;
+
+/// Number of rotated log files to keep around.
+const KEEP: usize = 5;
+
+/// Query parameters and fields whose values are never written to the log.
+const SECRETS: &[(&str, &[u8])] = &[
+    ("api_key=", b"&)\"' \r\n"),
+    ("apikey=", b"&)\"' \r\n"),
+    ("\"apikey\":\"", b"\""),
+    ("Bearer ", b"\"' \r\n"),
+];
+
+struct Inner {
+    path: PathBuf,
+    file: File,
+    size: u64,
+    day: NaiveDate,
+    max_size: u64,
+}
+
+impl Inner {
+    fn rotate(&mut self) -> io::Result<()> {
+        for n in (1..KEEP).rev() {
+            let from = rotated(&self.path, n);
+
+            if from.is_file() {
+                fs::rename(&from, rotated(&self.path, n + 1))?;
+            }
+        }
+
+        fs::rename(&self.path, rotated(&self.path, 1))?;
+        self.file = open(&self.path)?;
+        self.size = 0;
+        Ok(())
+    }
+}
+
+/// A log file which is rotated once it grows beyond a given size or when the
+/// day changes.
+///
+/// Every log line is written straight to the file without buffering, so
+/// nothing is lost if the process crashes.
+#[derive(Clone)]
+pub struct RotatingFile {
+    inner: Arc<Mutex<Inner>>,
+}
+
+impl RotatingFile {
+    /// Open the log file at the given path.
+    pub fn open(path: &Path, max_size: u64) -> Result<Self> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).with_context(|| anyhow!("{}", parent.display()))?;
+        }
+
+        let file = open(path).with_context(|| anyhow!("{}", path.display()))?;
+        let meta = file.metadata()?;
+
+        let day = match meta.modified() {
+            Ok(modified) => chrono::DateTime::<Local>::from(modified).date_naive(),
+            Err(..) => Local::now().date_naive(),
+        };
+
+        Ok(Self {
+            inner: Arc::new(Mutex::new(Inner {
+                path: path.to_owned(),
+                file,
+                size: meta.len(),
+                day,
+                max_size,
+            })),
+        })
+    }
+}
+
+impl<'a> MakeWriter<'a> for RotatingFile {
+    type Writer = RotatingFile;
+
+    #[inline]
+    fn make_writer(&'a self) -> Self::Writer {
+        self.clone()
+    }
+}
+
+impl Write for RotatingFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut inner = self.inner.lock();
+        let today = Local::now().date_naive();
+
+        if inner.size > 0 && (inner.size + buf.len() as u64 > inner.max_size || inner.day != today)
+        {
+            inner.rotate()?;
+        }
+
+        inner.day = today;
+
+        let string = String::from_utf8_lossy(buf);
+        let string = redact(&string);
+        inner.file.write_all(string.as_bytes())?;
+        inner.size += string.len() as u64;
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.lock().file.flush()
+    }
+}
+
+fn open(path: &Path) -> io::Result<File> {
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+fn rotated(path: &Path, n: usize) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(format!(".{n}"));
+    PathBuf::from(path)
+}
+
+/// Redact any secrets in the given string.
+fn redact(string: &str) -> Cow<'_, str> {
+    if !SECRETS.iter().any(|(prefix, _)| string.contains(prefix)) {
+        return Cow::Borrowed(string);
+    }
+
+    let mut out = string.to_owned();
+
+    for (prefix, terminators) in SECRETS {
+        let mut from = 0;
+
+        while let Some(n) = out[from..].find(prefix) {
+            let start = from + n + prefix.len();
+
+            let end = out[start..]
+                .bytes()
+                .position(|b| terminators.contains(&b))
+                .map(|n| start + n)
+                .unwrap_or(out.len());
+
+            out.replace_range(start..end, "REDACTED");
+            from = start + "REDACTED".len();
+        }
+    }
+
+    Cow::Owned(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::redact;
+
+    #[test]
+    fn test_redact() {
+        assert_eq!(
+            redact("error sending request for url (https://api.themoviedb.org/3/tv/1?api_key=secret&a=b)"),
+            "error sending request for url (https://api.themoviedb.org/3/tv/1?api_key=REDACTED&a=b)"
+        );
+        assert_eq!(
+            redact("login: {\"apikey\":\"secret\"} Bearer token"),
+            "login: {\"apikey\":\"REDACTED\"} Bearer REDACTED"
+        );
+        assert_eq!(redact("nothing to see"), "nothing to see");
+    }
+}
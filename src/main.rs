@@ -1,15 +1,27 @@
 #![cfg_attr(all(not(feature = "cli"), windows), windows_subsystem = "windows")]
 
+use std::future::Future;
+use std::io::Write;
 use std::path::PathBuf;
 
 use anyhow::{Context, Result};
 use clap::Parser;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Layer};
+
+/// Default maximum size of the log file in megabytes.
+const DEFAULT_LOG_MAX_SIZE: u64 = 10;
 
 #[derive(Parser)]
 struct Opts {
     /// Import watch history from trakt.
     #[arg(long, name = "path")]
     import_trakt_watched: Option<PathBuf>,
+    /// Import watch history from a CSV file with the columns
+    /// `imdb_id,season,episode,watched_at`.
+    #[arg(long, name = "csv-path")]
+    import_csv_watched: Option<PathBuf>,
     /// Only import a show matching the given filter.
     #[arg(long, name = "string")]
     import_filter: Option<String>,
@@ -19,6 +31,14 @@ struct Opts {
     /// Import any missing shows encountered.
     #[arg(long)]
     import_missing: bool,
+    /// Only report what importing trakt history would do, without changing
+    /// anything.
+    #[arg(long)]
+    import_dry_run: bool,
+    /// File to write shows which couldn't be matched during import to.
+    /// Defaults to a file next to the imported file.
+    #[arg(long, name = "review-path")]
+    import_review: Option<PathBuf>,
     /// Ensure that import history is saved.
     #[arg(long)]
     import_test: bool,
@@ -31,19 +51,76 @@ struct Opts {
     /// Print project paths.
     #[arg(long)]
     paths: bool,
+    /// Write logs to a file which is rotated daily or when it grows too large.
+    #[arg(long)]
+    log: bool,
+    /// Path of the log file, defaults to a file in the cache directory.
+    #[arg(long, name = "log-path")]
+    log_path: Option<PathBuf>,
+    /// Maximum size of the log file in megabytes before it's rotated.
+    #[arg(long, name = "megabytes", default_value_t = DEFAULT_LOG_MAX_SIZE)]
+    log_max_size: u64,
+    /// Print watch statistics and exit.
+    #[arg(long)]
+    stats: bool,
+    /// Print the task queue as JSON and exit.
+    #[arg(long)]
+    dump_queue: bool,
+    /// Restore the task queue from a JSON dump, save it and exit.
+    #[arg(long, name = "queue-path")]
+    load_queue: Option<PathBuf>,
+    /// Allow `--load-queue` to replace a non-empty queue.
+    #[arg(long)]
+    load_queue_force: bool,
+    /// Print all data for the series with the given id as JSON and exit.
+    #[arg(long, name = "series-id")]
+    export_series: Option<String>,
+    /// Don't include watch history in `--export-series`.
+    #[arg(long)]
+    export_no_watched: bool,
+    /// Write a Markdown checklist of everything pending to the given path and
+    /// exit.
+    #[arg(long, name = "pending-path")]
+    export_pending: Option<PathBuf>,
+    /// Write watch history in the format read by `--import-trakt-watched` to
+    /// the given path and exit. Honors `--import-filter`.
+    #[arg(long, name = "export-path")]
+    export_trakt_watched: Option<PathBuf>,
+    /// Write a snapshot of the whole database as JSON to the given path and
+    /// exit.
+    #[arg(long, name = "export-json-path")]
+    export_json: Option<PathBuf>,
+    /// Import a snapshot previously written with `--export-json`.
+    #[arg(long, name = "import-json-path")]
+    import_json: Option<PathBuf>,
+    /// Import a series previously exported with `--export-series`.
+    #[arg(long, name = "series-path")]
+    import_series: Option<PathBuf>,
+    /// Import a custom watch order for the series specified with
+    /// `--watch-order-series`, with one episode like `S01E02` or `tmdb:1234`
+    /// per line.
+    #[arg(long, name = "order-path", requires = "watch_order_series")]
+    import_watch_order: Option<PathBuf>,
+    /// The series to import a custom watch order for.
+    #[arg(long, name = "watch_order_series", value_name = "series-id")]
+    watch_order_series: Option<String>,
+    /// Remove all series which aren't tracked.
+    #[arg(long)]
+    prune_untracked: bool,
+    /// Remove cached images which aren't used by any series or movie.
+    #[arg(long)]
+    gc_images: bool,
+    /// Add and download the series with the given remote id, like
+    /// `imdb:tt0903747`, `tmdb:1396` or `tvdb:81189`, and exit. Can be
+    /// specified multiple times.
+    #[arg(long, name = "remote")]
+    add: Vec<String>,
+    /// Remove duplicate watches of the same episode or movie.
+    #[arg(long)]
+    dedupe_watches: bool,
 }
 
 pub fn main() -> Result<()> {
-    tracing_subscriber::fmt()
-        .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
-        .try_init()
-        .map_err(|e| anyhow::anyhow!("{e}"))?;
-
-    let Some(_lock) = ontv::lock::try_global_lock("se.tedro.OnTV")? else {
-        tracing::error!("Failed to lock process, it's possible multiple processes are running",);
-        return Ok(());
-    };
-
     let opts = Opts::try_parse()?;
 
     let dirs = directories_next::ProjectDirs::from("se.tedro", "setbac", "OnTV")
@@ -56,9 +133,41 @@ pub fn main() -> Result<()> {
 
     let cache = dirs.cache_dir();
 
+    let log_path = match &opts.log_path {
+        Some(path) => path.clone(),
+        None => cache.join("logs").join("ontv.log"),
+    };
+
+    let file_layer = if opts.log {
+        let file = ontv::log::RotatingFile::open(&log_path, opts.log_max_size * 1024 * 1024)?;
+        let filter =
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("ontv=info"));
+
+        Some(
+            tracing_subscriber::fmt::layer()
+                .with_ansi(false)
+                .with_writer(file)
+                .with_filter(filter),
+        )
+    } else {
+        None
+    };
+
+    tracing_subscriber::registry()
+        .with(tracing_subscriber::fmt::layer().with_filter(EnvFilter::from_default_env()))
+        .with(file_layer)
+        .try_init()
+        .map_err(|e| anyhow::anyhow!("{e}"))?;
+
+    let Some(_lock) = ontv::lock::try_global_lock("se.tedro.OnTV")? else {
+        tracing::error!("Failed to lock process, it's possible multiple processes are running",);
+        return Ok(());
+    };
+
     if opts.paths {
         tracing::info!("Config: {}", config.display());
         tracing::info!("Cache: {}", cache.display());
+        tracing::info!("Log: {}", log_path.display());
     }
 
     let mut service = ontv::Service::new(config, cache)?;
@@ -68,15 +177,147 @@ pub fn main() -> Result<()> {
     }
 
     if let Some(path) = opts.import_trakt_watched {
-        ontv::import::import_trakt_watched(
+        let review = match opts.import_review {
+            Some(review) => review,
+            None => path.with_extension("review.txt"),
+        };
+
+        let mode = if opts.import_dry_run {
+            ontv::import::ImportMode::DryRun
+        } else {
+            ontv::import::ImportMode::Apply
+        };
+
+        let report = ontv::import::import_trakt_watched(
             &mut service,
             &path,
             opts.import_filter.as_deref(),
             opts.import_remove,
             opts.import_missing,
+            &review,
+            mode,
+        )?;
+
+        if opts.import_dry_run {
+            print!("{report}");
+            return Ok(());
+        }
+    }
+
+    if let Some(path) = &opts.import_csv_watched {
+        ontv::import::import_csv_watched(
+            &mut service,
+            path,
+            opts.import_remove,
+            opts.import_missing,
         )?;
     }
 
+    // Options which modify the database or the image cache are saved right
+    // away, after which we exit instead of starting the application.
+    let modified = opts.load_queue.is_some()
+        || opts.import_json.is_some()
+        || opts.import_series.is_some()
+        || opts.import_watch_order.is_some()
+        || opts.prune_untracked
+        || opts.dedupe_watches
+        || opts.gc_images;
+
+    if let Some(path) = &opts.load_queue {
+        service.load_queue(path, opts.load_queue_force)?;
+    }
+
+    if let Some(path) = &opts.import_json {
+        let f = std::fs::File::open(path).with_context(|| path.display().to_string())?;
+        service
+            .import_database_json(std::io::BufReader::new(f))
+            .with_context(|| path.display().to_string())?;
+    }
+
+    if let Some(path) = &opts.import_series {
+        service.import_series_json(path)?;
+    }
+
+    if let (Some(path), Some(id)) = (&opts.import_watch_order, &opts.watch_order_series) {
+        service.import_watch_order(id, path)?;
+    }
+
+    if opts.prune_untracked {
+        let count = service.prune_untracked();
+        tracing::info!("Removed {count} untracked series");
+    }
+
+    if opts.dedupe_watches {
+        let count = service.dedupe_watches();
+        tracing::info!("Removed {count} duplicate watches");
+    }
+
+    if opts.gc_images {
+        block_on(service.purge_orphaned_images())??;
+    }
+
+    if modified {
+        block_on(service.save_changes())??;
+    }
+
+    if !opts.add.is_empty() {
+        ontv::import::add_series_by_remote(&mut service, &opts.add)?;
+        return Ok(());
+    }
+
+    if let Some(id) = &opts.export_series {
+        println!(
+            "{}",
+            service.export_series_json(id, !opts.export_no_watched)?
+        );
+        return Ok(());
+    }
+
+    if let Some(path) = &opts.export_pending {
+        let markdown = service.export_pending_markdown()?;
+        std::fs::write(path, markdown).with_context(|| path.display().to_string())?;
+        return Ok(());
+    }
+
+    if let Some(path) = &opts.export_trakt_watched {
+        ontv::import::export_trakt_watched(&service, path, opts.import_filter.as_deref())?;
+        return Ok(());
+    }
+
+    if let Some(path) = &opts.export_json {
+        let f = std::fs::File::create(path).with_context(|| path.display().to_string())?;
+        let mut f = std::io::BufWriter::new(f);
+        service.export_database_json(&mut f)?;
+        f.flush().with_context(|| path.display().to_string())?;
+        return Ok(());
+    }
+
+    if opts.stats {
+        print!("{}", service.text_stats());
+        return Ok(());
+    }
+
+    if opts.dump_queue {
+        println!("{}", service.dump_queue()?);
+        return Ok(());
+    }
+
+    if modified {
+        return Ok(());
+    }
+
     ontv::run(service)?;
     Ok(())
 }
+
+/// Run the given future to completion.
+fn block_on<F>(future: F) -> Result<F::Output>
+where
+    F: Future,
+{
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?;
+
+    Ok(runtime.block_on(future))
+}
@@ -1,21 +1,26 @@
 mod etag;
+mod link;
 mod raw;
+mod watch_order;
 
 use core::cmp::Ordering;
 use std::collections::btree_map;
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
+use std::path::PathBuf;
 use std::str::FromStr;
 
 use anyhow::Result;
-use chrono::{DateTime, NaiveDate, Utc};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc, Weekday};
 use relative_path::RelativePath;
 use serde::de::IntoDeserializer;
 use serde::{de, ser, Deserialize, Serialize};
 use uuid::Uuid;
 
 pub(crate) use self::etag::Etag;
+pub(crate) use self::link::RemoteLink;
 pub(crate) use self::raw::Raw;
+pub(crate) use self::watch_order::{parse_watch_order, WatchOrderEntry};
 
 macro_rules! id {
     ($name:ident) => {
@@ -65,6 +70,7 @@ id!(EpisodeId);
 id!(MovieId);
 id!(WatchedId);
 id!(TaskId);
+id!(CollectionId);
 
 #[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
@@ -74,9 +80,55 @@ pub(crate) enum ThemeType {
     Dark,
 }
 
-#[inline]
-fn default_days() -> u64 {
-    7
+/// How far ahead the schedule looks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "unit", content = "count", rename_all = "kebab-case")]
+pub(crate) enum ScheduleWindow {
+    Days(u64),
+    Weeks(u64),
+}
+
+impl ScheduleWindow {
+    /// The number of days covered by the window.
+    pub(crate) fn days(&self) -> u64 {
+        match *self {
+            ScheduleWindow::Days(days) => days,
+            ScheduleWindow::Weeks(weeks) => weeks.saturating_mul(7),
+        }
+    }
+
+    /// The number of days or weeks in the window.
+    pub(crate) fn count(&self) -> u64 {
+        match *self {
+            ScheduleWindow::Days(n) | ScheduleWindow::Weeks(n) => n,
+        }
+    }
+
+    /// Construct a window of the same unit with a different count.
+    pub(crate) fn with_count(&self, count: u64) -> Self {
+        match self {
+            ScheduleWindow::Days(..) => ScheduleWindow::Days(count),
+            ScheduleWindow::Weeks(..) => ScheduleWindow::Weeks(count),
+        }
+    }
+}
+
+impl Default for ScheduleWindow {
+    #[inline]
+    fn default() -> Self {
+        ScheduleWindow::Days(7)
+    }
+}
+
+/// How pending episodes and movies are ordered.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum PendingOrder {
+    /// Order by when the item became pending.
+    #[default]
+    Pending,
+    /// Order by when the episode aired or the movie was released.
+    Released,
 }
 
 #[inline]
@@ -99,17 +151,111 @@ fn default_schedule_page() -> usize {
     7
 }
 
+#[inline]
+fn default_series_removal_grace_days() -> u64 {
+    14
+}
+
+#[inline]
+fn default_catch_up_days() -> u64 {
+    14
+}
+
+#[inline]
+fn default_week_start() -> Weekday {
+    Weekday::Sun
+}
+
+#[inline]
+fn default_activity_weeks() -> usize {
+    12
+}
+
+#[inline]
+fn default_recently_added_days() -> u64 {
+    7
+}
+
+#[inline]
+fn default_runtime_minutes() -> u32 {
+    42
+}
+
+#[inline]
+fn default_tmdb_episode_concurrency() -> usize {
+    4
+}
+
+#[inline]
+fn default_task_concurrency() -> usize {
+    4
+}
+
+#[inline]
+fn default_task_concurrency_loading_images() -> usize {
+    1
+}
+
+#[inline]
+fn default_image_cache_max_mb() -> u64 {
+    1024
+}
+
+#[inline]
+fn default_max_task_attempts() -> u8 {
+    3
+}
+
+#[inline]
+fn default_image_concurrency() -> usize {
+    10
+}
+
+#[inline]
+fn default_include_specials() -> bool {
+    true
+}
+
+#[inline]
+fn default_backup_keep() -> usize {
+    10
+}
+
+#[inline]
+fn default_movie_release_country() -> String {
+    String::from("US")
+}
+
+fn default_movie_release_kinds() -> Vec<MovieReleaseKind> {
+    vec![
+        MovieReleaseKind::Digital,
+        MovieReleaseKind::Physical,
+        MovieReleaseKind::Tv,
+    ]
+}
+
 /// The state for the settings page.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct Config {
     #[serde(default)]
     pub(crate) theme: ThemeType,
+    /// Custom accent color as RGB, layered on top of the theme.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) accent: Option<[u8; 3]>,
     #[serde(default)]
     pub(crate) tvdb_legacy_apikey: String,
     #[serde(default)]
     pub(crate) tmdb_api_key: String,
-    #[serde(default = "default_days")]
-    pub(crate) schedule_duration_days: u64,
+    /// Key for the Open Movie Database, used for shows which are only known
+    /// by their IMDb id.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub(crate) omdb_api_key: String,
+    /// How far ahead the schedule looks.
+    #[serde(default)]
+    pub(crate) schedule_window: ScheduleWindow,
+    #[serde(default, rename = "schedule_duration_days", skip_serializing)]
+    #[deprecated = "replaced by .schedule_window"]
+    pub(crate) compat_schedule_duration_days: Option<u64>,
     #[serde(default = "default_dashboard_limit")]
     pub(crate) dashboard_limit: usize,
     #[serde(default = "default_dashboard_page")]
@@ -118,6 +264,118 @@ pub(crate) struct Config {
     pub(crate) schedule_limit: usize,
     #[serde(default = "default_schedule_page")]
     pub(crate) schedule_page: usize,
+    /// Only show what hasn't aired yet on the dashboard.
+    #[serde(default)]
+    pub(crate) dashboard_upcoming_only: bool,
+    /// Include today in the upcoming only dashboard.
+    #[serde(default)]
+    pub(crate) dashboard_upcoming_include_today: bool,
+    /// Number of days a removed series is kept around before it's deleted.
+    #[serde(default = "default_series_removal_grace_days")]
+    pub(crate) series_removal_grace_days: u64,
+    /// The version of the data this configuration was last stored with.
+    #[serde(default)]
+    pub(crate) data_version: u32,
+    /// Release kinds which count when scheduling movies.
+    #[serde(default = "default_movie_release_kinds")]
+    pub(crate) movie_release_kinds: Vec<MovieReleaseKind>,
+    /// Country whose release dates are preferred when scheduling movies, as an
+    /// ISO 3166-1 country code.
+    #[serde(default = "default_movie_release_country")]
+    pub(crate) movie_release_country: String,
+    /// Language to download episode titles in, like `en-US`. Defaults to the
+    /// original language of the series.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) episode_language: Option<String>,
+    /// Number of hours after the start of the air date until an episode is
+    /// considered to have aired.
+    #[serde(default)]
+    pub(crate) air_grace_hours: u32,
+    /// Directory to write a snapshot of the database to every time it's saved.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) backup_path: Option<PathBuf>,
+    /// Number of backup snapshots to keep.
+    #[serde(default = "default_backup_keep")]
+    pub(crate) backup_keep: usize,
+    /// Leave API keys out of the configuration stored in backups.
+    #[serde(default)]
+    pub(crate) backup_exclude_secrets: bool,
+    /// Number of days to look back for aired episodes to catch up on.
+    #[serde(default = "default_catch_up_days")]
+    pub(crate) catch_up_days: u64,
+    /// Include specials when catching up.
+    #[serde(default)]
+    pub(crate) catch_up_include_specials: bool,
+    /// How episodes and movies are ordered when showing what to watch next.
+    #[serde(default)]
+    pub(crate) watch_next_order: PendingOrder,
+    /// Collapse each series on the watch next page into a header with the
+    /// number of available episodes in its season.
+    #[serde(default)]
+    pub(crate) group_watch_next: bool,
+    /// Count skipped episodes as watched when showing completion.
+    #[serde(default)]
+    pub(crate) count_skipped_as_watched: bool,
+    /// Pass over recaps and other flagged episodes when picking what to watch
+    /// next.
+    #[serde(default)]
+    pub(crate) skip_filler: bool,
+    /// Include specials in what to watch next and the schedule.
+    #[serde(default = "default_include_specials")]
+    pub(crate) include_specials: bool,
+    /// Remove series from what to watch next once nothing more is upcoming,
+    /// rather than keeping an episode without an air date pending.
+    #[serde(default)]
+    pub(crate) remove_finished_pending: bool,
+    /// Ask before starting the next season of a series once a season has been
+    /// finished.
+    #[serde(default)]
+    pub(crate) confirm_next_season: bool,
+    /// Hide watched episodes on the season page by default.
+    #[serde(default)]
+    pub(crate) season_hide_watched: bool,
+    /// The first day of the week.
+    #[serde(default = "default_week_start")]
+    pub(crate) week_start: Weekday,
+    /// Show days without anything scheduled in the weeks of the schedule.
+    #[serde(default)]
+    pub(crate) schedule_show_empty_days: bool,
+    /// Number of weeks of watch activity to show on the dashboard.
+    #[serde(default = "default_activity_weeks")]
+    pub(crate) activity_weeks: usize,
+    /// Number of days episodes added to tracked series are shown on the
+    /// dashboard.
+    #[serde(default = "default_recently_added_days")]
+    pub(crate) recently_added_days: u64,
+    /// Runtime in minutes used for episodes which don't have one.
+    #[serde(default = "default_runtime_minutes")]
+    pub(crate) default_runtime_minutes: u32,
+    /// Number of episodes to download details for at the same time from
+    /// TheMovieDB.
+    #[serde(default = "default_tmdb_episode_concurrency")]
+    pub(crate) tmdb_episode_concurrency: usize,
+    /// Number of background tasks which are allowed to run at the same time.
+    #[serde(default = "default_task_concurrency")]
+    pub(crate) task_concurrency: usize,
+    /// Number of background tasks which are allowed to run at the same time
+    /// while images for the current page are loading.
+    #[serde(default = "default_task_concurrency_loading_images")]
+    pub(crate) task_concurrency_loading_images: usize,
+    /// Number of images which are allowed to be downloaded at the same time.
+    #[serde(default = "default_image_concurrency")]
+    pub(crate) image_concurrency: usize,
+    /// Size in megabytes the image cache is allowed to grow to before the
+    /// least recently used images are evicted. Zero means no limit.
+    #[serde(default = "default_image_cache_max_mb")]
+    pub(crate) image_cache_max_mb: u64,
+    /// Number of times a background task which fails with a transient error,
+    /// like a timeout, is attempted before its error is reported.
+    #[serde(default = "default_max_task_attempts")]
+    pub(crate) max_task_attempts: u8,
+    /// Watches of the same episode or movie within this many hours of each
+    /// other are considered duplicates. If unset, watches on the same day are.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) dedupe_window_hours: Option<u32>,
 }
 
 impl Config {
@@ -125,6 +383,28 @@ impl Config {
         self.dashboard_limit.max(1) * self.dashboard_page.max(1)
     }
 
+    /// Number of background tasks which may run at the same time, depending
+    /// on if images are being loaded.
+    pub(crate) fn task_concurrency(&self, loading_images: bool) -> usize {
+        let concurrency = if loading_images {
+            self.task_concurrency_loading_images
+        } else {
+            self.task_concurrency
+        };
+
+        concurrency.max(1)
+    }
+
+    /// Maximum size of the image cache in bytes, if limited.
+    pub(crate) fn image_cache_max_bytes(&self) -> Option<u64> {
+        (self.image_cache_max_mb > 0).then(|| self.image_cache_max_mb.saturating_mul(1024 * 1024))
+    }
+
+    /// Number of images which may be downloaded at the same time.
+    pub(crate) fn image_concurrency(&self) -> usize {
+        self.image_concurrency.max(1)
+    }
+
     pub(crate) fn dashboard_page(&self) -> usize {
         self.dashboard_page.max(1)
     }
@@ -133,28 +413,92 @@ impl Config {
         self.schedule_page.max(1)
     }
 
+    /// Get the date which is used to determine what has aired at the given
+    /// time, taking the air grace period into account.
+    pub(crate) fn air_today<Tz>(&self, now: &DateTime<Tz>) -> NaiveDate
+    where
+        Tz: chrono::TimeZone,
+    {
+        let grace = chrono::Duration::hours(i64::from(self.air_grace_hours));
+        (now.clone() - grace).date_naive()
+    }
+
+    /// Test if the given scheduled date should be shown on the dashboard.
+    pub(crate) fn is_dashboard_scheduled(&self, date: &NaiveDate, today: &NaiveDate) -> bool {
+        if !self.dashboard_upcoming_only {
+            return true;
+        }
+
+        date > today || self.dashboard_upcoming_include_today && date == today
+    }
+
     /// Build iced theme.
     #[inline]
     pub(crate) fn iced_theme(&self) -> iced::Theme {
-        match self.theme {
+        let theme = match self.theme {
             ThemeType::Light => iced::Theme::Light,
             ThemeType::Dark => iced::Theme::Dark,
-        }
+        };
+
+        let Some([r, g, b]) = self.accent else {
+            return theme;
+        };
+
+        let mut palette = theme.palette();
+        palette.primary = iced::Color::from_rgb8(r, g, b);
+        iced::Theme::custom(format!("{:?} with accent", self.theme), palette)
     }
 }
 
 impl Default for Config {
     #[inline]
+    #[allow(deprecated)]
     fn default() -> Self {
         Self {
             theme: Default::default(),
+            accent: None,
             tvdb_legacy_apikey: Default::default(),
             tmdb_api_key: Default::default(),
-            schedule_duration_days: default_days(),
+            omdb_api_key: Default::default(),
+            schedule_window: ScheduleWindow::default(),
+            compat_schedule_duration_days: None,
             dashboard_limit: default_dashboard_limit(),
             dashboard_page: default_dashboard_page(),
             schedule_limit: default_schedule_limit(),
             schedule_page: default_schedule_page(),
+            dashboard_upcoming_only: false,
+            dashboard_upcoming_include_today: false,
+            series_removal_grace_days: default_series_removal_grace_days(),
+            data_version: 0,
+            movie_release_kinds: default_movie_release_kinds(),
+            movie_release_country: default_movie_release_country(),
+            episode_language: None,
+            air_grace_hours: 0,
+            backup_path: None,
+            backup_keep: default_backup_keep(),
+            backup_exclude_secrets: false,
+            catch_up_days: default_catch_up_days(),
+            catch_up_include_specials: false,
+            watch_next_order: PendingOrder::default(),
+            group_watch_next: false,
+            count_skipped_as_watched: false,
+            skip_filler: false,
+            include_specials: default_include_specials(),
+            remove_finished_pending: false,
+            confirm_next_season: false,
+            season_hide_watched: false,
+            week_start: default_week_start(),
+            schedule_show_empty_days: false,
+            activity_weeks: default_activity_weeks(),
+            recently_added_days: default_recently_added_days(),
+            default_runtime_minutes: default_runtime_minutes(),
+            tmdb_episode_concurrency: default_tmdb_episode_concurrency(),
+            task_concurrency: default_task_concurrency(),
+            task_concurrency_loading_images: default_task_concurrency_loading_images(),
+            image_concurrency: default_image_concurrency(),
+            image_cache_max_mb: default_image_cache_max_mb(),
+            max_task_attempts: default_max_task_attempts(),
+            dedupe_window_hours: None,
         }
     }
 }
@@ -250,6 +594,51 @@ impl RemoteId {
         }
     }
 
+    /// Get the URL of this remote when it refers to a movie.
+    pub(crate) fn movie_url(&self) -> String {
+        match self {
+            RemoteId::Tmdb { id } => {
+                format!("https://www.themoviedb.org/movie/{id}")
+            }
+            remote_id => remote_id.url(),
+        }
+    }
+
+    /// Get the URL of an episode in the series this remote refers to, if
+    /// the remote supports linking to episodes.
+    pub(crate) fn episode_url(&self, season: SeasonNumber, number: u32) -> Option<String> {
+        match self {
+            RemoteId::Tmdb { id } => {
+                let season = match season {
+                    SeasonNumber::Specials => 0,
+                    SeasonNumber::Number(n) => n,
+                };
+
+                Some(format!(
+                    "https://www.themoviedb.org/tv/{id}/season/{season}/episode/{number}"
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// Preference of the remote when picking one to share, lower is better.
+    fn share_preference(&self) -> u32 {
+        match self {
+            RemoteId::Tmdb { .. } => 0,
+            RemoteId::Tvdb { .. } => 1,
+            RemoteId::Imdb { .. } => 2,
+        }
+    }
+
+    /// Pick the remote to share, preferring the primary remote if present.
+    pub(crate) fn share<I>(primary: Option<RemoteId>, remotes: I) -> Option<RemoteId>
+    where
+        I: IntoIterator<Item = RemoteId>,
+    {
+        primary.or_else(|| remotes.into_iter().min_by_key(RemoteId::share_preference))
+    }
+
     /// Test if the remote is supported for syncing.
     pub(crate) fn is_supported(&self) -> bool {
         matches!(self, RemoteId::Tmdb { .. } | RemoteId::Tvdb { .. })
@@ -272,6 +661,26 @@ impl fmt::Display for RemoteId {
     }
 }
 
+impl FromStr for RemoteId {
+    type Err = anyhow::Error;
+
+    /// Parse a remote id like `tmdb:1396`, `tvdb:81189` or `imdb:tt0903747`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((head, tail)) = s.split_once(':') else {
+            anyhow::bail!("missing `:`");
+        };
+
+        match head {
+            "tmdb" => Ok(RemoteId::Tmdb { id: tail.parse()? }),
+            "tvdb" => Ok(RemoteId::Tvdb { id: tail.parse()? }),
+            "imdb" => Ok(RemoteId::Imdb {
+                id: Raw::new(tail).ok_or_else(|| anyhow::anyhow!("overflowing imdb identifier"))?,
+            }),
+            kind => anyhow::bail!("unsupported remote `{kind}`"),
+        }
+    }
+}
+
 impl Serialize for RemoteId {
     #[inline]
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -303,23 +712,7 @@ impl<'de> Deserialize<'de> for RemoteId {
             where
                 E: de::Error,
             {
-                let (head, tail) = v
-                    .split_once(':')
-                    .ok_or_else(|| de::Error::custom("missing `:`"))?;
-
-                match head {
-                    "tmdb" => Ok(RemoteId::Tmdb {
-                        id: tail.parse().map_err(E::custom)?,
-                    }),
-                    "tvdb" => Ok(RemoteId::Tvdb {
-                        id: tail.parse().map_err(E::custom)?,
-                    }),
-                    "imdb" => Ok(RemoteId::Imdb {
-                        id: Raw::new(tail)
-                            .ok_or_else(|| de::Error::custom("overflowing imdb identifier"))?,
-                    }),
-                    kind => Err(de::Error::invalid_value(de::Unexpected::Str(kind), &self)),
-                }
+                v.parse().map_err(E::custom)
             }
 
             #[inline]
@@ -544,6 +937,17 @@ impl MovieGraphics {
     fn is_empty(&self) -> bool {
         self.poster.is_none() && self.banner.is_none() && self.fanart.is_none()
     }
+
+    /// Iterate over all images referenced by the movie.
+    pub(crate) fn images(&self) -> impl Iterator<Item = &ImageV2> {
+        self.poster
+            .iter()
+            .chain(&self.posters)
+            .chain(&self.banner)
+            .chain(&self.banners)
+            .chain(&self.fanart)
+            .chain(&self.screen_capture)
+    }
 }
 
 /// Associated series graphics.
@@ -588,6 +992,48 @@ impl SeriesGraphics {
     fn is_empty(&self) -> bool {
         self.poster.is_none() && self.banner.is_none() && self.fanart.is_none()
     }
+
+    /// Iterate over all images referenced by the series.
+    pub(crate) fn images(&self) -> impl Iterator<Item = &ImageV2> {
+        self.poster
+            .iter()
+            .chain(&self.posters)
+            .chain(&self.banner)
+            .chain(&self.banners)
+            .chain(&self.fanart)
+    }
+}
+
+/// New content discovered for a series which was previously caught up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub(crate) enum NewContent {
+    /// A new season was announced.
+    Season { season: SeasonNumber },
+    /// New episodes were added to an existing season.
+    Episodes,
+}
+
+/// The next season of a series which is waiting to be started.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) struct NextSeason {
+    /// The first episode of the next season.
+    pub(crate) episode: EpisodeId,
+    /// If starting the season has been declined.
+    #[serde(default)]
+    pub(crate) declined: bool,
+}
+
+/// How an episode has been skipped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Skip {
+    /// The episode was skipped over when it was pending.
+    Once,
+    /// The episode should never be watched, which counts as watched and is
+    /// passed over when picking what to watch next.
+    Permanent,
 }
 
 /// A series.
@@ -613,6 +1059,46 @@ pub(crate) struct Series {
     /// The remote identifier that is used to synchronize this series.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub(crate) remote_id: Option<RemoteId>,
+    /// Override for how often the series is checked for updates, in hours.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) refresh_interval_hours: Option<u32>,
+    /// When the series was removed. Removed series are kept around until
+    /// their grace period has expired.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) removed: Option<DateTime<Utc>>,
+    /// Custom order in which episodes are watched. Episodes not listed follow
+    /// in their default order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) watch_order: Vec<EpisodeId>,
+    /// Episodes which have been skipped and how.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub(crate) skipped: BTreeMap<EpisodeId, Skip>,
+    /// New content which was discovered after the series was caught up.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) new_content: Option<NewContent>,
+    /// Leave specials out of what to watch next, the schedule and completion.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub(crate) ignore_specials: bool,
+    /// Episode which has been manually set as the next one to watch. It's kept
+    /// until it has been watched or is explicitly changed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) current_episode: Option<EpisodeId>,
+    /// The next season, if it has to be started before it shows up in what to
+    /// watch next.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) next_season: Option<NextSeason>,
+    /// Content rating of the series, like `TV-MA`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) content_rating: Option<String>,
+    /// Free-text notes about the series.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub(crate) notes: String,
+    /// Genres of the series.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) genres: Vec<String>,
+    /// Favorite series are shown first among what's pending.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub(crate) favorite: bool,
     /// Inline poster image.
     #[serde(default, rename = "poster", skip_serializing)]
     #[deprecated = "replaced by .graphics"]
@@ -651,6 +1137,18 @@ impl Series {
             graphics: update.graphics,
             remote_id: Some(update.remote_id),
             tracked: true,
+            refresh_interval_hours: None,
+            removed: None,
+            watch_order: Vec::new(),
+            skipped: BTreeMap::new(),
+            new_content: None,
+            ignore_specials: false,
+            current_episode: None,
+            next_season: None,
+            content_rating: update.content_rating,
+            notes: String::new(),
+            genres: update.genres,
+            favorite: false,
             compat_poster: None,
             compat_banner: None,
             compat_fanart: None,
@@ -667,6 +1165,8 @@ impl Series {
         self.overview = other.overview;
         self.graphics.merge_from(other.graphics);
         self.remote_id = Some(other.remote_id);
+        self.content_rating = other.content_rating;
+        self.genres = other.genres;
     }
 
     /// Get the poster of the series.
@@ -678,6 +1178,12 @@ impl Series {
     pub(crate) fn banner(&self) -> Option<&ImageV2> {
         self.graphics.banner.as_ref()
     }
+
+    /// Get when a removed series will be permanently deleted.
+    pub(crate) fn deleted_at(&self, config: &Config) -> Option<DateTime<Utc>> {
+        let removed = self.removed?;
+        removed.checked_add_days(chrono::Days::new(config.series_removal_grace_days))
+    }
 }
 
 /// Movie release kind.
@@ -693,6 +1199,16 @@ pub(crate) enum MovieReleaseKind {
 }
 
 impl MovieReleaseKind {
+    /// All release kinds.
+    pub(crate) const ALL: [MovieReleaseKind; 6] = [
+        MovieReleaseKind::Premiere,
+        MovieReleaseKind::TheatricalLimited,
+        MovieReleaseKind::Theatrical,
+        MovieReleaseKind::Digital,
+        MovieReleaseKind::Physical,
+        MovieReleaseKind::Tv,
+    ];
+
     /// Test if release kind is digital or equivalent.
     pub(crate) fn is_digital(&self) -> bool {
         matches!(
@@ -764,12 +1280,22 @@ pub(crate) struct Movie {
     pub(crate) release_dates: Vec<MovieReleaseDates>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub(crate) earliest_releases: Vec<MovieEarliestReleaseDate>,
+    /// The name of the collection the movie belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) collection: Option<String>,
+    /// Content rating of the movie, like `PG-13`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) content_rating: Option<String>,
+    /// Free-text notes about the movie.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub(crate) notes: String,
 }
 
 impl Movie {
-    /// Construct a new series from a series update.
-    pub(crate) fn new_movie(update: crate::service::UpdateMovie) -> Self {
-        let earliest_releases = build_earliest_releases(&update.release_dates);
+    /// Construct a new movie from a movie update, preferring release dates
+    /// from the given country.
+    pub(crate) fn new_movie(update: crate::service::UpdateMovie, country: Option<&str>) -> Self {
+        let earliest_releases = build_earliest_releases(&update.release_dates, country);
 
         Self {
             id: update.id,
@@ -780,6 +1306,9 @@ impl Movie {
             remote_id: Some(update.remote_id),
             release_dates: update.release_dates,
             earliest_releases,
+            collection: update.collection,
+            content_rating: update.content_rating,
+            notes: String::new(),
         }
     }
 
@@ -797,14 +1326,50 @@ impl Movie {
             .min()
     }
 
+    /// Get the release date used when scheduling the movie, which is the
+    /// earliest release of any of the configured kinds.
+    ///
+    /// Release dates for the configured country are preferred if there are
+    /// any.
+    pub(crate) fn scheduled_release_date(&self, config: &Config) -> Option<DateTime<Utc>> {
+        let kinds = &config.movie_release_kinds;
+
+        let date = self
+            .release_dates
+            .iter()
+            .filter(|r| {
+                r.country
+                    .eq_ignore_ascii_case(&config.movie_release_country)
+            })
+            .flat_map(|r| &r.dates)
+            .filter(|d| kinds.contains(&d.kind))
+            .map(|d| d.date)
+            .min();
+
+        if date.is_some() {
+            return date;
+        }
+
+        self.earliest_by_kind()
+            .iter()
+            .filter(|e| kinds.contains(&e.kind))
+            .map(|e| e.date)
+            .min()
+    }
+
     /// Get a batch of earliest release dates.
     pub(crate) fn earliest_by_kind(&self) -> &[MovieEarliestReleaseDate] {
         &self.earliest_releases
     }
 
+    /// Rebuild the earliest release dates, preferring the given country.
+    pub(crate) fn rebuild_earliest_releases(&mut self, country: Option<&str>) {
+        self.earliest_releases = build_earliest_releases(&self.release_dates, country);
+    }
+
     /// Merge this movie from an update.
-    pub(crate) fn merge_from(&mut self, other: crate::service::UpdateMovie) {
-        let earliest_releases = build_earliest_releases(&other.release_dates);
+    pub(crate) fn merge_from(&mut self, other: crate::service::UpdateMovie, country: Option<&str>) {
+        let earliest_releases = build_earliest_releases(&other.release_dates, country);
 
         self.title = other.title;
         self.release_date = other.release_date;
@@ -813,6 +1378,37 @@ impl Movie {
         self.remote_id = Some(other.remote_id);
         self.release_dates = other.release_dates;
         self.earliest_releases = earliest_releases;
+        self.collection = other.collection;
+        self.content_rating = other.content_rating;
+    }
+
+    /// Take release information from another copy of the same movie where it
+    /// is more complete than ours.
+    pub(crate) fn merge_releases_from(&mut self, other: &Movie) {
+        fn count(movie: &Movie) -> usize {
+            movie.release_dates.iter().map(|r| r.dates.len()).sum()
+        }
+
+        if count(other) > count(self) {
+            self.release_dates = other.release_dates.clone();
+            self.earliest_releases = other.earliest_releases.clone();
+        }
+
+        if self.release_date.is_none() {
+            self.release_date = other.release_date;
+        }
+
+        if self.collection.is_none() {
+            self.collection = other.collection.clone();
+        }
+
+        if self.content_rating.is_none() {
+            self.content_rating = other.content_rating.clone();
+        }
+
+        if self.notes.is_empty() {
+            self.notes = other.notes.clone();
+        }
     }
 
     /// Get the poster of the movie.
@@ -854,15 +1450,52 @@ impl Movie {
     }
 }
 
-fn build_earliest_releases(release_dates: &[MovieReleaseDates]) -> Vec<MovieEarliestReleaseDate> {
-    fn country_to_prio(country: &str) -> u32 {
-        match country {
-            "US" => 10,
-            "GB" => 9,
-            _ => 0,
+fn country_to_prio(country: &str) -> u32 {
+    match country {
+        "US" => 10,
+        "GB" => 9,
+        _ => 0,
+    }
+}
+
+/// Select a content rating out of `(country, rating)` pairs.
+///
+/// Like with release dates, the configured country is preferred if it has a
+/// rating, otherwise the most important country with one is used.
+pub(crate) fn select_content_rating<'a, I>(ratings: I, country: Option<&str>) -> Option<String>
+where
+    I: IntoIterator<Item = (&'a str, &'a str)>,
+{
+    let mut preferred = None;
+    let mut best = None::<(&str, &str)>;
+
+    for (c, rating) in ratings {
+        let rating = rating.trim();
+
+        if rating.is_empty() {
+            continue;
+        }
+
+        if country.is_some_and(|country| c.eq_ignore_ascii_case(country)) {
+            preferred.get_or_insert(rating);
+        }
+
+        if best.map_or(true, |(b, _)| country_to_prio(b) < country_to_prio(c)) {
+            best = Some((c, rating));
         }
     }
 
+    Some(preferred.or(best.map(|(_, r)| r))?.to_owned())
+}
+
+/// Build the earliest release date of each kind.
+///
+/// Dates from the given country are used for every kind it has a release of,
+/// other kinds fall back to the earliest date of any country.
+fn build_earliest_releases(
+    release_dates: &[MovieReleaseDates],
+    country: Option<&str>,
+) -> Vec<MovieEarliestReleaseDate> {
     fn less_important(a: &str, b: &str) -> bool {
         country_to_prio(a) < country_to_prio(b)
     }
@@ -897,6 +1530,29 @@ fn build_earliest_releases(release_dates: &[MovieReleaseDates]) -> Vec<MovieEarl
         }
     }
 
+    if let Some(country) = country {
+        let mut preferred = BTreeMap::<MovieReleaseKind, MovieEarliestReleaseDate>::new();
+
+        for r in release_dates
+            .iter()
+            .filter(|r| r.country.eq_ignore_ascii_case(country))
+        {
+            for date in &r.dates {
+                let e = preferred
+                    .entry(date.kind)
+                    .or_insert_with(|| MovieEarliestReleaseDate {
+                        country: r.country.clone(),
+                        date: date.date,
+                        kind: date.kind,
+                    });
+
+                e.date = e.date.min(date.date);
+            }
+        }
+
+        by_kind.extend(preferred);
+    }
+
     by_kind.into_values().collect()
 }
 
@@ -990,6 +1646,9 @@ pub(crate) struct Watched {
     /// Watched kind.
     #[serde(flatten)]
     pub(crate) kind: WatchedKind,
+    /// Rating given when watching, from 1 to 10.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) rating: Option<u8>,
 }
 
 /// Season number.
@@ -1128,9 +1787,37 @@ pub(crate) struct Episode {
     /// The remote identifier that is used to synchronize this episode.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub(crate) remote_id: Option<RemoteEpisodeId>,
+    /// Directors credited for the episode.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) directors: Vec<String>,
+    /// Writers credited for the episode.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) writers: Vec<String>,
+    /// User assigned kind of the episode, such as a recap.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) kind: Option<EpisodeKind>,
+    /// When the episode was added to an already tracked series.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) added: Option<DateTime<Utc>>,
+    /// Runtime of the episode in minutes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) runtime: Option<u32>,
+    /// Wall-clock time of day the episode airs in the timezone of its network.
+    ///
+    /// This is only used for display and ordering episodes airing the same day.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) air_time: Option<NaiveTime>,
 }
 
 impl Episode {
+    /// Get the runtime of the episode in minutes, falling back to the
+    /// configured default if it isn't known.
+    pub(crate) fn runtime_or(&self, config: &Config) -> u32 {
+        self.runtime
+            .filter(|&r| r > 0)
+            .unwrap_or(config.default_runtime_minutes)
+    }
+
     /// Get filename for episode.
     pub(crate) fn filename(&self) -> Option<&ImageV2> {
         self.graphics.filename.as_ref()
@@ -1157,6 +1844,9 @@ impl Episode {
     }
 
     /// Get aired timestamp.
+    ///
+    /// NB: This doesn't take [`Episode::air_time`] into account, since it is
+    /// local to the network the episode airs on.
     pub(crate) fn aired_timestamp(&self) -> Option<DateTime<Utc>> {
         self.aired.as_ref().and_then(|&d| {
             Some(DateTime::from_naive_utc_and_offset(
@@ -1184,6 +1874,38 @@ impl fmt::Display for Episode {
     }
 }
 
+/// A user assigned kind of episode which isn't part of the main story.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum EpisodeKind {
+    /// Recaps earlier episodes.
+    Recap,
+    /// Mostly made up of clips from earlier episodes.
+    ClipShow,
+    /// Doesn't advance the story.
+    Filler,
+}
+
+impl EpisodeKind {
+    /// All episode kinds.
+    pub(crate) const ALL: [EpisodeKind; 3] = [
+        EpisodeKind::Recap,
+        EpisodeKind::ClipShow,
+        EpisodeKind::Filler,
+    ];
+}
+
+impl fmt::Display for EpisodeKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EpisodeKind::Recap => write!(f, "Recap"),
+            EpisodeKind::ClipShow => write!(f, "Clip show"),
+            EpisodeKind::Filler => write!(f, "Filler"),
+        }
+    }
+}
+
 /// A key used to sort episodes.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub(crate) struct WatchOrderKey {
@@ -1238,6 +1960,8 @@ pub(crate) enum ImageV2 {
     Tvdb { uri: Box<RelativePath> },
     /// An image from themoviedb.org
     Tmdb { uri: Box<RelativePath> },
+    /// An image from IMDb, as referenced by omdbapi.com
+    Imdb { uri: Box<RelativePath> },
 }
 
 impl ImageV2 {
@@ -1246,6 +1970,7 @@ impl ImageV2 {
         ImageHash(match self {
             ImageV2::Tvdb { uri } => crate::cache::hash128(&(0xd410b8f4u32, uri)),
             ImageV2::Tmdb { uri } => crate::cache::hash128(&(0xc66bff3eu32, uri)),
+            ImageV2::Imdb { uri } => crate::cache::hash128(&(0x5e1f07a2u32, uri)),
         })
     }
 
@@ -1268,6 +1993,16 @@ impl ImageV2 {
             .filter(|s| !s.is_empty())
             .map(|uri| Self::Tmdb { uri: uri.into() })
     }
+
+    /// Construct a new imdb image.
+    pub(crate) fn imdb<S>(string: &S) -> Option<Self>
+    where
+        S: ?Sized + AsRef<str>,
+    {
+        Some(string.as_ref().trim_start_matches('/'))
+            .filter(|s| !s.is_empty())
+            .map(|uri| Self::Imdb { uri: uri.into() })
+    }
 }
 
 impl fmt::Display for ImageV2 {
@@ -1275,6 +2010,7 @@ impl fmt::Display for ImageV2 {
         match self {
             ImageV2::Tvdb { uri } => write!(f, "tvdb:{uri}"),
             ImageV2::Tmdb { uri } => write!(f, "tmdb:{uri}"),
+            ImageV2::Imdb { uri } => write!(f, "imdb:{uri}"),
         }
     }
 }
@@ -1317,6 +2053,7 @@ impl<'de> Deserialize<'de> for ImageV2 {
                 match head {
                     "tmdb" => Ok(ImageV2::Tmdb { uri: uri.into() }),
                     "tvdb" => Ok(ImageV2::Tvdb { uri: uri.into() }),
+                    "imdb" => Ok(ImageV2::Imdb { uri: uri.into() }),
                     kind => Err(de::Error::invalid_value(de::Unexpected::Str(kind), &self)),
                 }
             }
@@ -1345,6 +2082,9 @@ pub(crate) struct Pending {
     pub(crate) timestamp: DateTime<Utc>,
     #[serde(flatten)]
     pub(crate) kind: PendingKind,
+    /// The item is hidden from what's pending until this time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) snoozed_until: Option<DateTime<Utc>>,
 }
 
 impl Pending {
@@ -1355,6 +2095,11 @@ impl Pending {
             PendingKind::Movie { movie } => movie.id(),
         }
     }
+
+    /// Test if the item is snoozed at the given time.
+    pub(crate) fn is_snoozed(&self, now: &DateTime<Utc>) -> bool {
+        self.snoozed_until.is_some_and(|until| until > *now)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -1394,8 +2139,37 @@ pub(crate) struct ScheduledSeries {
     pub(crate) episodes: Vec<EpisodeId>,
 }
 
+/// An item on the schedule.
+pub(crate) enum Scheduled {
+    /// Episodes of a series airing.
+    Series(ScheduledSeries),
+    /// A movie being released.
+    Movie(MovieId),
+}
+
 /// A scheduled day.
 pub(crate) struct ScheduledDay {
     pub(crate) date: NaiveDate,
-    pub(crate) schedule: Vec<ScheduledSeries>,
+    pub(crate) schedule: Vec<Scheduled>,
+}
+
+/// A member of a collection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub(crate) enum CollectionMember {
+    Series { id: SeriesId },
+    Movie { id: MovieId },
+}
+
+/// A named collection of related series and movies.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) struct Collection {
+    /// Unique identifier of the collection.
+    pub(crate) id: CollectionId,
+    /// Name of the collection.
+    pub(crate) name: String,
+    /// Members of the collection in the order they were added.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) members: Vec<CollectionMember>,
 }
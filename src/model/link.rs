@@ -0,0 +1,184 @@
+use anyhow::{anyhow, bail, Context, Result};
+use reqwest::Url;
+
+use super::{Raw, RemoteId};
+
+/// A remote reference parsed from a URL or an identifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RemoteLink {
+    /// A link to a series.
+    Series(RemoteId),
+    /// A link to a movie.
+    Movie(RemoteId),
+    /// An IMDb title, which could either be a series or a movie.
+    Imdb(Raw<16>),
+}
+
+impl RemoteLink {
+    /// Parse a remote link out of the given input.
+    ///
+    /// Returns `None` if the input doesn't look like a link or identifier at
+    /// all, and an error if it does but couldn't be recognized.
+    pub(crate) fn parse(input: &str) -> Result<Option<Self>> {
+        let input = input.trim();
+
+        if let Some(id) = input.strip_prefix("tmdb:") {
+            return Ok(Some(RemoteLink::Series(RemoteId::Tmdb {
+                id: parse_id(id)?,
+            })));
+        }
+
+        if let Some(id) = input.strip_prefix("tvdb:") {
+            return Ok(Some(RemoteLink::Series(RemoteId::Tvdb {
+                id: parse_id(id)?,
+            })));
+        }
+
+        if let Some(id) = input.strip_prefix("imdb:") {
+            return Ok(Some(RemoteLink::Imdb(parse_imdb(id)?)));
+        }
+
+        if is_imdb(input) {
+            return Ok(Some(RemoteLink::Imdb(parse_imdb(input)?)));
+        }
+
+        if !input.contains("://") && !input.starts_with("www.") {
+            return Ok(None);
+        }
+
+        let url = if input.contains("://") {
+            Url::parse(input)
+        } else {
+            Url::parse(&format!("https://{input}"))
+        };
+
+        let url = url.with_context(|| anyhow!("Bad URL `{input}`"))?;
+
+        let Some(host) = url.host_str() else {
+            bail!("Missing host in URL `{input}`");
+        };
+
+        let host = host.strip_prefix("www.").unwrap_or(host);
+        let mut segments = url.path_segments().into_iter().flatten();
+
+        match host {
+            "themoviedb.org" => match (segments.next(), segments.next()) {
+                (Some("tv"), Some(id)) => {
+                    return Ok(Some(RemoteLink::Series(RemoteId::Tmdb {
+                        id: parse_id(slug_id(id))?,
+                    })));
+                }
+                (Some("movie"), Some(id)) => {
+                    return Ok(Some(RemoteLink::Movie(RemoteId::Tmdb {
+                        id: parse_id(slug_id(id))?,
+                    })));
+                }
+                _ => {}
+            },
+            "thetvdb.com" => {
+                for (key, value) in url.query_pairs() {
+                    if matches!(key.as_ref(), "query" | "id") {
+                        return Ok(Some(RemoteLink::Series(RemoteId::Tvdb {
+                            id: parse_id(&value)?,
+                        })));
+                    }
+                }
+
+                if let (Some("dereferrer"), Some("series"), Some(id)) =
+                    (segments.next(), segments.next(), segments.next())
+                {
+                    return Ok(Some(RemoteLink::Series(RemoteId::Tvdb {
+                        id: parse_id(id)?,
+                    })));
+                }
+            }
+            "imdb.com" | "m.imdb.com" => {
+                if let (Some("title"), Some(id)) = (segments.next(), segments.next()) {
+                    return Ok(Some(RemoteLink::Imdb(parse_imdb(id)?)));
+                }
+            }
+            _ => {}
+        }
+
+        bail!("Unrecognized URL `{input}`, expected a link to themoviedb.org, thetvdb.com or imdb.com")
+    }
+}
+
+/// Strip the slug from an identifier like `1399-game-of-thrones`.
+fn slug_id(id: &str) -> &str {
+    id.split_once('-').map(|(id, _)| id).unwrap_or(id)
+}
+
+fn parse_id(id: &str) -> Result<u32> {
+    id.trim()
+        .parse()
+        .with_context(|| anyhow!("Bad identifier `{id}`"))
+}
+
+fn is_imdb(id: &str) -> bool {
+    matches!(id.strip_prefix("tt"), Some(rest) if !rest.is_empty() && rest.bytes().all(|b| b.is_ascii_digit()))
+}
+
+fn parse_imdb(id: &str) -> Result<Raw<16>> {
+    let id = id.trim();
+
+    if !is_imdb(id) {
+        bail!("Bad IMDb identifier `{id}`");
+    }
+
+    Raw::new(id).with_context(|| anyhow!("IMDb identifier `{id}` is too long"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Raw, RemoteId, RemoteLink};
+
+    fn parse(input: &str) -> Option<RemoteLink> {
+        RemoteLink::parse(input).unwrap()
+    }
+
+    #[test]
+    fn test_parse_links() {
+        let imdb = RemoteLink::Imdb(Raw::new("tt0944947").unwrap());
+        let tmdb = RemoteId::Tmdb { id: 1399 };
+        let tvdb = RemoteId::Tvdb { id: 121361 };
+
+        assert_eq!(parse(&tmdb.url()), Some(RemoteLink::Series(tmdb)));
+        assert_eq!(parse(&tvdb.url()), Some(RemoteLink::Series(tvdb)));
+        assert_eq!(
+            parse(
+                &RemoteId::Imdb {
+                    id: Raw::new("tt0944947").unwrap()
+                }
+                .url()
+            ),
+            Some(imdb)
+        );
+
+        assert_eq!(
+            parse("https://www.themoviedb.org/tv/1399-game-of-thrones/season/1"),
+            Some(RemoteLink::Series(tmdb))
+        );
+        assert_eq!(
+            parse("https://www.themoviedb.org/movie/603-the-matrix"),
+            Some(RemoteLink::Movie(RemoteId::Tmdb { id: 603 }))
+        );
+        assert_eq!(
+            parse("https://thetvdb.com/?tab=series&id=121361"),
+            Some(RemoteLink::Series(tvdb))
+        );
+        assert_eq!(
+            parse("https://thetvdb.com/dereferrer/series/121361"),
+            Some(RemoteLink::Series(tvdb))
+        );
+        assert_eq!(parse("www.imdb.com/title/tt0944947/?ref_=nv"), Some(imdb));
+        assert_eq!(parse("tt0944947"), Some(imdb));
+        assert_eq!(parse("tmdb:1399"), Some(RemoteLink::Series(tmdb)));
+        assert_eq!(parse("tvdb:121361"), Some(RemoteLink::Series(tvdb)));
+        assert_eq!(parse("imdb:tt0944947"), Some(imdb));
+
+        assert_eq!(parse("game of thrones"), None);
+        assert!(RemoteLink::parse("https://example.com/tv/1399").is_err());
+        assert!(RemoteLink::parse("https://www.themoviedb.org/person/1").is_err());
+    }
+}
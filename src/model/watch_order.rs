@@ -0,0 +1,111 @@
+use anyhow::{anyhow, bail, Context, Result};
+
+use super::{Raw, RemoteEpisodeId, SeasonNumber};
+
+/// A single entry in a custom watch order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum WatchOrderEntry {
+    /// An episode identified by season and episode number, like `S01E02`.
+    Number { season: SeasonNumber, number: u32 },
+    /// An episode identified by a remote, like `tmdb:1234`.
+    Remote(RemoteEpisodeId),
+}
+
+/// Parse a custom watch order, with one episode per line.
+///
+/// Empty lines and lines starting with `#` are ignored.
+pub(crate) fn parse_watch_order(input: &str) -> Result<Vec<WatchOrderEntry>> {
+    let mut output = Vec::new();
+
+    for (n, line) in input.lines().enumerate() {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let entry = parse_entry(line).with_context(|| anyhow!("Line {}", n + 1))?;
+        output.push(entry);
+    }
+
+    Ok(output)
+}
+
+fn parse_entry(input: &str) -> Result<WatchOrderEntry> {
+    if let Some(id) = input.strip_prefix("tmdb:") {
+        return Ok(WatchOrderEntry::Remote(RemoteEpisodeId::Tmdb {
+            id: parse_number(id)?,
+        }));
+    }
+
+    if let Some(id) = input.strip_prefix("tvdb:") {
+        return Ok(WatchOrderEntry::Remote(RemoteEpisodeId::Tvdb {
+            id: parse_number(id)?,
+        }));
+    }
+
+    if let Some(id) = input.strip_prefix("imdb:") {
+        let id = id.trim();
+        let id = Raw::new(id).with_context(|| anyhow!("Bad IMDb identifier `{id}`"))?;
+        return Ok(WatchOrderEntry::Remote(RemoteEpisodeId::Imdb { id }));
+    }
+
+    let lower = input.to_ascii_lowercase();
+
+    if let Some((season, number)) = lower
+        .strip_prefix('s')
+        .and_then(|rest| rest.split_once('e'))
+    {
+        let season = match parse_number(season)? {
+            0 => SeasonNumber::Specials,
+            n => SeasonNumber::Number(n),
+        };
+
+        return Ok(WatchOrderEntry::Number {
+            season,
+            number: parse_number(number)?,
+        });
+    }
+
+    bail!("Expected an episode like `S01E02` or `tmdb:1234`, but got `{input}`")
+}
+
+fn parse_number(input: &str) -> Result<u32> {
+    input
+        .trim()
+        .parse()
+        .with_context(|| anyhow!("Bad number `{input}`"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_watch_order, RemoteEpisodeId, SeasonNumber, WatchOrderEntry};
+
+    #[test]
+    fn test_parse_watch_order() {
+        let order =
+            parse_watch_order("# chronological\nS02E01\n\ns1e2\nS00E03\ntmdb:1234\n").unwrap();
+
+        assert_eq!(
+            order,
+            [
+                WatchOrderEntry::Number {
+                    season: SeasonNumber::Number(2),
+                    number: 1
+                },
+                WatchOrderEntry::Number {
+                    season: SeasonNumber::Number(1),
+                    number: 2
+                },
+                WatchOrderEntry::Number {
+                    season: SeasonNumber::Specials,
+                    number: 3
+                },
+                WatchOrderEntry::Remote(RemoteEpisodeId::Tmdb { id: 1234 }),
+            ]
+        );
+
+        assert!(parse_watch_order("S01").is_err());
+        assert!(parse_watch_order("tmdb:abc").is_err());
+    }
+}
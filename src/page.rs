@@ -22,11 +22,17 @@ pub(crate) use self::series_list::SeriesList;
 pub(crate) mod movies_list;
 pub(crate) use self::movies_list::MoviesList;
 
+pub(crate) mod collections;
+pub(crate) use self::collections::Collections;
+
 pub(crate) mod settings;
 pub(crate) use self::settings::Settings;
 
 pub(crate) mod errors;
 pub(crate) use self::errors::Errors;
 
+pub(crate) mod catch_up;
+pub(crate) use self::catch_up::CatchUp;
+
 pub(crate) mod watch_next;
 pub(crate) use self::watch_next::WatchNext;
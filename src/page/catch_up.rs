@@ -0,0 +1,148 @@
+use crate::prelude::*;
+
+/// Messages generated and handled by [CatchUp].
+#[derive(Debug, Clone)]
+pub(crate) enum Message {
+    Watch(usize, comps::watch::Message),
+    Navigate(Page),
+    DecrementDays,
+    IncrementDays,
+    IncludeSpecials(bool),
+}
+
+/// Lists recently aired episodes which haven't been watched yet.
+#[derive(Default)]
+pub(crate) struct CatchUp {
+    watch: Vec<comps::Watch>,
+}
+
+impl CatchUp {
+    pub(crate) fn prepare(&mut self, cx: &mut Ctxt<'_>) {
+        self.watch.init_from_iter(
+            cx.service
+                .catch_up()
+                .map(|e| comps::watch::Props::new(comps::watch::Kind::Episode(e.id))),
+        );
+    }
+
+    pub(crate) fn update(&mut self, cx: &mut Ctxt<'_>, message: Message) {
+        match message {
+            Message::Watch(index, message) => {
+                if let Some(w) = self.watch.get_mut(index) {
+                    w.update(cx, message);
+                }
+            }
+            Message::Navigate(page) => {
+                cx.push_history(page);
+            }
+            Message::DecrementDays => {
+                let days = cx.service.config().catch_up_days.saturating_sub(1).max(1);
+                cx.service.set_catch_up_days(days);
+            }
+            Message::IncrementDays => {
+                let days = cx.service.config().catch_up_days + 1;
+                cx.service.set_catch_up_days(days);
+            }
+            Message::IncludeSpecials(value) => {
+                cx.service.config_mut().catch_up_include_specials = value;
+            }
+        }
+    }
+
+    pub(crate) fn view(&self, cx: &CtxtRef<'_>) -> Element<'static, Message> {
+        let config = cx.service.config();
+        let days = config.catch_up_days;
+
+        let decrement = w::button(w::text("-").size(SMALL_SIZE)).style(theme::Button::Secondary);
+
+        let header = w::Row::new()
+            .push(
+                w::text(format_args!("Aired in the last {days} days"))
+                    .size(SUBTITLE_SIZE)
+                    .width(Length::Fill),
+            )
+            .push(if days > 1 {
+                decrement.on_press(Message::DecrementDays)
+            } else {
+                decrement
+            })
+            .push(
+                w::button(w::text("+").size(SMALL_SIZE))
+                    .style(theme::Button::Secondary)
+                    .on_press(Message::IncrementDays),
+            )
+            .push(
+                w::checkbox("Include specials", config.catch_up_include_specials)
+                    .on_toggle(Message::IncludeSpecials),
+            )
+            .spacing(SPACE)
+            .align_items(Alignment::Center);
+
+        let mut rows = w::Column::new();
+
+        for (index, (watch, e)) in self.watch.iter().zip(cx.service.catch_up()).enumerate() {
+            let Some(series) = cx.service.series(e.series()) else {
+                continue;
+            };
+
+            let mut title = format!("{}x{}", e.season.short(), e.number);
+
+            if let Some(name) = &e.name {
+                title.push_str(": ");
+                title.push_str(name);
+            }
+
+            let mut info = w::Column::new();
+
+            info = info.push(
+                link(w::text(&series.title).shaping(w::text::Shaping::Advanced))
+                    .on_press(Message::Navigate(page::series::page(series.id))),
+            );
+
+            info = info.push(
+                link(
+                    w::text(title)
+                        .shaping(w::text::Shaping::Advanced)
+                        .size(SMALL_SIZE),
+                )
+                .on_press(Message::Navigate(page::season::page(series.id, e.season))),
+            );
+
+            if let Some(aired) = &e.aired {
+                info = info.push(w::text(format_args!("Aired {aired}")).size(SMALL_SIZE));
+            }
+
+            rows = rows.push(
+                w::Row::new()
+                    .push(info.spacing(SPACE).width(Length::Fill))
+                    .push(
+                        watch
+                            .view(
+                                "Mark watched",
+                                theme::Button::Positive,
+                                theme::Button::Positive,
+                                Length::Shrink,
+                                Horizontal::Center,
+                                false,
+                            )
+                            .map(move |m| Message::Watch(index, m)),
+                    )
+                    .spacing(GAP)
+                    .align_items(Alignment::Center),
+            );
+        }
+
+        if self.watch.is_empty() {
+            rows = rows.push(w::text("Nothing to catch up on").size(SMALL_SIZE));
+        }
+
+        default_container(
+            w::Column::new()
+                .push(header)
+                .push(rows.spacing(GAP))
+                .spacing(GAP)
+                .padding(GAP),
+        )
+        .into()
+    }
+}
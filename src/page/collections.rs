@@ -0,0 +1,267 @@
+use std::fmt;
+
+use crate::prelude::*;
+
+/// Messages generated and handled by [Collections].
+#[derive(Debug, Clone)]
+pub(crate) enum Message {
+    ChangeName(String),
+    Create,
+    StartRename(CollectionId),
+    ChangeRename(String),
+    Rename,
+    CancelRename,
+    Remove(CollectionId),
+    AddMember(CollectionId, CollectionMember),
+    RemoveMember(CollectionId, CollectionMember),
+    Navigate(Page),
+}
+
+/// A series or movie which can be added to a collection.
+#[derive(Debug, Clone, PartialEq)]
+struct MemberOption {
+    member: CollectionMember,
+    title: String,
+}
+
+impl fmt::Display for MemberOption {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.member {
+            CollectionMember::Series { .. } => write!(f, "{} (series)", self.title),
+            CollectionMember::Movie { .. } => write!(f, "{} (movie)", self.title),
+        }
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct Collections {
+    /// Name of the collection to create.
+    name: String,
+    /// Collection being renamed and its new name.
+    renaming: Option<(CollectionId, String)>,
+}
+
+impl Collections {
+    pub(crate) fn update(&mut self, cx: &mut Ctxt<'_>, message: Message) {
+        match message {
+            Message::ChangeName(name) => {
+                self.name = name;
+            }
+            Message::Create => {
+                if !self.name.trim().is_empty() {
+                    cx.service.create_collection(&self.name);
+                    self.name.clear();
+                }
+            }
+            Message::StartRename(id) => {
+                if let Some(collection) = cx.service.collection(&id) {
+                    self.renaming = Some((id, collection.name.clone()));
+                }
+            }
+            Message::ChangeRename(name) => {
+                if let Some((_, current)) = &mut self.renaming {
+                    *current = name;
+                }
+            }
+            Message::Rename => {
+                if let Some((id, name)) = self.renaming.take() {
+                    if !name.trim().is_empty() {
+                        cx.service.rename_collection(&id, &name);
+                    }
+                }
+            }
+            Message::CancelRename => {
+                self.renaming = None;
+            }
+            Message::Remove(id) => {
+                cx.service.remove_collection(&id);
+            }
+            Message::AddMember(id, member) => {
+                cx.service.add_collection_member(&id, member);
+            }
+            Message::RemoveMember(id, member) => {
+                cx.service.remove_collection_member(&id, &member);
+            }
+            Message::Navigate(page) => {
+                cx.push_history(page);
+            }
+        }
+    }
+
+    pub(crate) fn view(&self, cx: &CtxtRef<'_>) -> Element<'static, Message> {
+        let name = w::text_input("New collection...", &self.name)
+            .on_input(Message::ChangeName)
+            .on_submit(Message::Create)
+            .width(Length::Fill);
+
+        let create = w::button(w::text("Create").size(SMALL_SIZE)).style(theme::Button::Primary);
+
+        let create = if self.name.trim().is_empty() {
+            create
+        } else {
+            create.on_press(Message::Create)
+        };
+
+        let mut page = w::Column::new().push(centered(
+            w::Row::new()
+                .push(name)
+                .push(create)
+                .spacing(SPACE)
+                .align_items(Alignment::Center)
+                .padding(GAP)
+                .width(Length::Fill),
+            None,
+        ));
+
+        let mut rows = w::Column::new();
+        let mut any = false;
+
+        for collection in cx.service.collections_by_name() {
+            any = true;
+
+            rows = rows.push(
+                centered(
+                    self.render_collection(cx, collection).spacing(SPACE),
+                    Some(style::weak),
+                )
+                .padding(GAP),
+            );
+        }
+
+        if !any {
+            rows = rows.push(centered(
+                w::text("No collections, create one above to group related series and movies")
+                    .size(SMALL_SIZE),
+                None,
+            ));
+        }
+
+        page = page.push(rows.spacing(GAP2));
+        page.width(Length::Fill).into()
+    }
+
+    fn render_collection(
+        &self,
+        cx: &CtxtRef<'_>,
+        collection: &Collection,
+    ) -> w::Column<'static, Message> {
+        let id = collection.id;
+
+        let mut header = w::Row::new().spacing(SPACE).align_items(Alignment::Center);
+
+        match &self.renaming {
+            Some((renaming, name)) if *renaming == id => {
+                header = header
+                    .push(
+                        w::text_input("Name...", name)
+                            .on_input(Message::ChangeRename)
+                            .on_submit(Message::Rename)
+                            .width(Length::Fill),
+                    )
+                    .push(
+                        w::button(w::text("Save").size(SMALL_SIZE))
+                            .style(theme::Button::Primary)
+                            .on_press(Message::Rename),
+                    )
+                    .push(
+                        w::button(w::text("Cancel").size(SMALL_SIZE))
+                            .style(theme::Button::Secondary)
+                            .on_press(Message::CancelRename),
+                    );
+            }
+            _ => {
+                header = header
+                    .push(
+                        w::text(&collection.name)
+                            .shaping(w::text::Shaping::Advanced)
+                            .size(SUBTITLE_SIZE)
+                            .width(Length::Fill),
+                    )
+                    .push(
+                        w::button(w::text("Rename").size(SMALL_SIZE))
+                            .style(theme::Button::Secondary)
+                            .on_press(Message::StartRename(id)),
+                    );
+            }
+        }
+
+        header = header.push(
+            w::button(w::text("Remove").size(SMALL_SIZE))
+                .style(theme::Button::Destructive)
+                .on_press(Message::Remove(id)),
+        );
+
+        let mut column = w::Column::new().push(header);
+
+        let (watched, total) = cx.service.collection_watched(collection);
+
+        let progress = match total {
+            0 => String::from("Nothing to watch"),
+            total => format!("Watched {watched} of {total} ({}%)", watched * 100 / total),
+        };
+
+        column = column.push(w::text(progress).size(SMALL_SIZE));
+
+        for member in &collection.members {
+            let (title, page) = match *member {
+                CollectionMember::Series { id } => {
+                    let Some(series) = cx.service.series(&id).filter(|s| s.removed.is_none())
+                    else {
+                        continue;
+                    };
+
+                    (series.title.clone(), page::series::page(id))
+                }
+                CollectionMember::Movie { id } => {
+                    let Some(movie) = cx.service.movie(&id) else {
+                        continue;
+                    };
+
+                    (movie.title.clone(), page::movie::page(id))
+                }
+            };
+
+            column = column.push(
+                w::Row::new()
+                    .push(
+                        link(w::text(title).shaping(w::text::Shaping::Advanced))
+                            .on_press(Message::Navigate(page)),
+                    )
+                    .push(w::horizontal_space())
+                    .push(
+                        w::button(w::text("Remove").size(SMALL_SIZE))
+                            .style(theme::Button::Secondary)
+                            .on_press(Message::RemoveMember(id, *member)),
+                    )
+                    .spacing(SPACE)
+                    .align_items(Alignment::Center),
+            );
+        }
+
+        let series = cx.service.series_by_name().map(|s| MemberOption {
+            member: CollectionMember::Series { id: s.id },
+            title: s.title.clone(),
+        });
+
+        let movies = cx.service.movies_by_name().map(|m| MemberOption {
+            member: CollectionMember::Movie { id: m.id },
+            title: m.title.clone(),
+        });
+
+        let options = series
+            .chain(movies)
+            .filter(|o| !collection.members.contains(&o.member))
+            .collect::<Vec<_>>();
+
+        column = column.push(
+            w::pick_list(options, None::<MemberOption>, move |o| {
+                Message::AddMember(id, o.member)
+            })
+            .placeholder("Add series or movie...")
+            .text_size(SMALL_SIZE),
+        );
+
+        column
+    }
+}
@@ -1,5 +1,9 @@
+use std::sync::Arc;
+
+use chrono::{NaiveDate, Weekday};
+
 use crate::service::PendingRef;
-use crate::utils::Hoverable;
+use crate::utils::{Hoverable, Sparkline};
 use crate::{prelude::*, Service};
 
 #[derive(Debug, Clone)]
@@ -22,29 +26,70 @@ pub(crate) enum Message {
     ShowMorePending,
     DecrementPage,
     IncrementPage,
+    /// Dismiss new content for a series.
+    DismissNewContent(SeriesId),
 }
 
+/// Maximum number of recently added episodes to show.
+const RECENTLY_ADDED_LIMIT: usize = 10;
+/// Height of the watch activity sparkline.
+const SPARKLINE_HEIGHT: f32 = 32.0;
+
 /// The state for the settings page.
 pub(crate) struct Dashboard {
     calendar: comps::Calendar,
     watch: Vec<comps::Watch>,
     schedule_focus: Option<(SeriesId, Option<ImageV2>)>,
+    /// Watch activity, and what it was computed from.
+    activity: Option<(ActivityKey, Activity)>,
+    /// Episodes recently added to tracked series.
+    recently_added: Vec<(SeriesId, EpisodeId)>,
+}
+
+/// Watch activity shown on the dashboard.
+struct Activity {
+    /// Watches per week.
+    weekly: Arc<[usize]>,
+    /// Watches made today.
+    today: usize,
+    /// Current daily watch streak.
+    streak: usize,
+}
+
+/// What weekly watch activity depends on.
+#[derive(PartialEq, Eq)]
+struct ActivityKey {
+    revision: u64,
+    today: NaiveDate,
+    week_start: Weekday,
+    weeks: usize,
 }
 
 impl Dashboard {
     pub(crate) fn new(state: &State, service: &Service) -> Self {
         let mut schedule_focus = None;
 
-        if let Some(scheduled) = service.schedule().first().and_then(|d| d.schedule.first()) {
+        let first = service
+            .schedule()
+            .iter()
+            .flat_map(|d| &d.schedule)
+            .find_map(|s| match s {
+                Scheduled::Series(s) => Some(s),
+                Scheduled::Movie(..) => None,
+            });
+
+        if let Some(scheduled) = first {
             if let Some(series) = service.series(&scheduled.series_id) {
                 schedule_focus = Some((series.id, series.graphics.poster.clone()));
             }
         }
 
         Self {
-            calendar: comps::Calendar::new(*state.today(), chrono::Weekday::Sun),
+            calendar: comps::Calendar::new(*state.today(), service.config().week_start),
             watch: Vec::new(),
             schedule_focus,
+            activity: None,
+            recently_added: Vec::new(),
         }
     }
 
@@ -56,11 +101,40 @@ impl Dashboard {
         let limit = cx.service.config().dashboard_limit();
         let today = *cx.state.today();
 
-        let iter = cx
+        let key = ActivityKey {
+            revision: cx.service.watch_revision(),
+            today,
+            week_start: cx.service.config().week_start,
+            weeks: cx.service.config().activity_weeks,
+        };
+
+        if self.activity.as_ref().map_or(true, |(k, _)| *k != key) {
+            let now = Utc::now();
+
+            let activity = Activity {
+                weekly: cx
+                    .service
+                    .weekly_watches(&key.today, key.week_start, key.weeks)
+                    .into(),
+                today: cx.service.watched_today_count(&now),
+                streak: cx.service.watch_streak(&now),
+            };
+
+            self.activity = Some((key, activity));
+        }
+
+        self.recently_added = cx
             .service
-            .pending()
+            .recently_added()
+            .take(RECENTLY_ADDED_LIMIT)
+            .map(|(s, e)| (s.id, e.id))
+            .collect();
+
+        let pending = cx.service.pending_favorites_first(&Utc::now());
+
+        let iter = pending
+            .into_iter()
             .filter(|p| p.has_aired(&today))
-            .rev()
             .take(limit);
 
         cx.assets
@@ -120,6 +194,9 @@ impl Dashboard {
                 let page = cx.service.config().dashboard_page + 1;
                 cx.service.config_mut().dashboard_page = page;
             }
+            Message::DismissNewContent(series_id) => {
+                cx.service.clear_new_content(&series_id);
+            }
         }
     }
 
@@ -187,21 +264,169 @@ impl Dashboard {
 
         let scheduled = self.render_scheduled(cx);
 
-        w::Column::new()
+        let mut page = w::Column::new()
             // .push(self.calendar.view().map(Message::Calendar))
-            .push(w::vertical_space().height(Length::Shrink))
-            .push(centered(up_next_title, None))
-            .push(centered(
+            .push(w::vertical_space().height(Length::Shrink));
+
+        if let Some(new_content) = self.render_new_content(cx) {
+            page = page
+                .push(centered(
+                    w::text("New content")
+                        .horizontal_alignment(Horizontal::Left)
+                        .width(Length::Fill)
+                        .size(SUBTITLE_SIZE),
+                    None,
+                ))
+                .push(centered(new_content.padding(GAP).spacing(SPACE), None));
+        }
+
+        if let Some(recently_added) = self.render_recently_added(cx) {
+            page = page
+                .push(centered(
+                    w::text("Recently added")
+                        .horizontal_alignment(Horizontal::Left)
+                        .width(Length::Fill)
+                        .size(SUBTITLE_SIZE),
+                    None,
+                ))
+                .push(centered(recently_added.padding(GAP).spacing(SPACE), None));
+        }
+
+        if let Some(activity) = self.render_activity() {
+            page = page.push(centered(activity, None));
+        }
+
+        // NB: Everything pending has already aired, so it's hidden in upcoming
+        // only mode.
+        if !cx.service.config().dashboard_upcoming_only {
+            page = page.push(centered(up_next_title, None)).push(centered(
                 pending.padding(GAP).spacing(GAP),
                 Some(style::weak),
-            ))
-            .push(centered(scheduled_title, None))
+            ));
+        }
+
+        page.push(centered(scheduled_title, None))
             .push(centered(scheduled.padding(GAP).spacing(GAP), None))
             .push(w::vertical_space().height(Length::Shrink))
             .spacing(GAP2)
             .into()
     }
 
+    fn render_activity(&self) -> Option<w::Column<'static, Message>> {
+        let (key, activity) = self.activity.as_ref()?;
+
+        if key.weeks == 0 {
+            return None;
+        }
+
+        let total = activity.weekly.iter().sum::<usize>();
+
+        let summary = match total {
+            0 => format!("No watches in the last {} weeks", key.weeks),
+            1 => format!("1 watch in the last {} weeks", key.weeks),
+            n => format!("{n} watches in the last {} weeks", key.weeks),
+        };
+
+        Some(
+            w::Column::new()
+                .push(
+                    w::Row::new()
+                        .push(w::text("Activity").size(SUBTITLE_SIZE))
+                        .push(w::horizontal_space())
+                        .push(w::text(summary).size(SMALL_SIZE))
+                        .align_items(Alignment::Center),
+                )
+                .push(w::text(streak_summary(activity)).size(SMALL_SIZE))
+                .push(
+                    w::canvas(Sparkline::new(activity.weekly.clone()))
+                        .width(Length::Fill)
+                        .height(SPARKLINE_HEIGHT),
+                )
+                .spacing(SPACE),
+        )
+    }
+
+    fn render_new_content(&self, cx: &CtxtRef<'_>) -> Option<w::Column<'static, Message>> {
+        let mut column = w::Column::new();
+        let mut any = false;
+
+        for (series, new_content) in cx.service.series_with_new_content() {
+            let badge = match new_content {
+                NewContent::Season {
+                    season: SeasonNumber::Number(number),
+                } => format!("New season {number}!"),
+                NewContent::Season { .. } => String::from("New season!"),
+                NewContent::Episodes => String::from("New episodes!"),
+            };
+
+            let title = link(w::text(&series.title).shaping(w::text::Shaping::Advanced))
+                .on_press(Message::Navigate(page::series::page(series.id)));
+
+            column = column.push(
+                w::Row::new()
+                    .push(title)
+                    .push(w::text(badge).size(SMALL_SIZE).style(cx.warning_text()))
+                    .push(w::horizontal_space())
+                    .push(
+                        w::button(w::text("Dismiss").size(SMALL_SIZE))
+                            .style(theme::Button::Secondary)
+                            .on_press(Message::DismissNewContent(series.id)),
+                    )
+                    .spacing(SPACE)
+                    .align_items(Alignment::Center),
+            );
+
+            any = true;
+        }
+
+        any.then_some(column)
+    }
+
+    fn render_recently_added(&self, cx: &CtxtRef<'_>) -> Option<w::Column<'static, Message>> {
+        let mut column = w::Column::new();
+        let mut any = false;
+
+        for (series_id, episode_id) in &self.recently_added {
+            let (Some(series), Some(episode)) =
+                (cx.service.series(series_id), cx.service.episode(episode_id))
+            else {
+                continue;
+            };
+
+            let title = link(w::text(&series.title).shaping(w::text::Shaping::Advanced))
+                .on_press(Message::Navigate(page::series::page(series.id)));
+
+            let mut name = format!("S{}E{}", episode.season.short(), episode.number);
+
+            if let Some(n) = &episode.name {
+                name.push_str(": ");
+                name.push_str(n);
+            }
+
+            let episode = link(
+                w::text(name)
+                    .size(SMALL_SIZE)
+                    .shaping(w::text::Shaping::Advanced),
+            )
+            .on_press(Message::Navigate(page::season::page(
+                series.id,
+                episode.season,
+            )));
+
+            column = column.push(
+                w::Row::new()
+                    .push(title)
+                    .push(episode)
+                    .spacing(SPACE)
+                    .align_items(Alignment::Center),
+            );
+
+            any = true;
+        }
+
+        any.then_some(column)
+    }
+
     fn render_pending(&self, cx: &CtxtRef<'_>) -> w::Column<'static, Message> {
         let mut cols = w::Column::new();
 
@@ -213,8 +438,8 @@ impl Dashboard {
 
         let iter = cx
             .service
-            .pending()
-            .rev()
+            .pending_favorites_first(&Utc::now())
+            .into_iter()
             .filter(|p| p.has_aired(cx.state.today()))
             .take(limit);
 
@@ -232,7 +457,7 @@ impl Dashboard {
                 .and_then(|i| cx.assets.image_with_hint(i, POSTER_HINT))
             {
                 Some(handle) => handle,
-                None => cx.missing_poster(),
+                None => cx.poster_placeholder(pending_ref.poster(), pending_ref.title()),
             };
 
             let mut panel = w::Column::new();
@@ -346,111 +571,170 @@ impl Dashboard {
 
     fn render_scheduled(&self, cx: &CtxtRef<'_>) -> w::Column<'static, Message> {
         let mut scheduled_rows = w::Column::new();
-        let mut cols = w::Row::new();
-        let mut count = 0;
         let mut first = true;
 
-        let page = cx.service.config().schedule_page();
+        let config = cx.service.config();
+        let page = config.schedule_page();
 
-        for (n, day) in cx.service.schedule().iter().enumerate() {
-            if n % page == 0 && n > 0 {
-                scheduled_rows = scheduled_rows.push(cols.spacing(GAP));
-                cols = w::Row::new();
-                count = 0;
-            } else {
-                count += 1;
-            }
+        for week in cx.service.schedule_by_week() {
+            let mut days = week
+                .days
+                .iter()
+                .filter(|(date, _)| config.is_dashboard_scheduled(date, cx.state.today()))
+                .peekable();
 
-            let mut column = w::Column::new();
+            if days.peek().is_none() {
+                continue;
+            }
 
-            column = column.push(
-                match day.date.signed_duration_since(*cx.service.now()).num_days() {
-                    0 => w::text("Today"),
-                    1 => w::text("Tomorrow"),
-                    _ => w::text(day.date),
-                },
+            scheduled_rows = scheduled_rows.push(
+                w::Column::new()
+                    .push(w::text(format_args!("Week of {}", week.start)))
+                    .push(w::horizontal_rule(1))
+                    .spacing(SPACE),
             );
 
-            let mut it = day
-                .schedule
-                .iter()
-                .flat_map(|sched| {
-                    cx.service
-                        .series(&sched.series_id)
-                        .into_iter()
-                        .map(move |series| (series, sched))
-                })
-                .peekable();
+            let mut cols = w::Row::new();
+            let mut count = 0;
 
-            if let Some((series_id, id)) = self.schedule_focus.as_ref().filter(|_| first) {
-                let poster = match id
-                    .as_ref()
-                    .and_then(|id| cx.assets.image_with_hint(id, POSTER_HINT))
-                {
-                    Some(image) => image,
-                    None => cx.missing_poster(),
-                };
+            for (n, (date, schedule)) in days.enumerate() {
+                if n % page == 0 && n > 0 {
+                    scheduled_rows = scheduled_rows.push(cols.spacing(GAP));
+                    cols = w::Row::new();
+                    count = 0;
+                } else {
+                    count += 1;
+                }
 
-                cols = cols.push(
-                    link(w::image(poster))
-                        .on_press(Message::Navigate(page::series::page(*series_id)))
-                        .width(Length::FillPortion(1)),
-                );
+                let mut column = w::Column::new();
 
-                count += 1;
-                first = false;
-            }
+                column = column.push(
+                    match date.signed_duration_since(*cx.service.now()).num_days() {
+                        0 => w::text("Today"),
+                        1 => w::text("Tomorrow"),
+                        _ => w::text(*date),
+                    },
+                );
 
-            while let Some((series, schedule)) = it.next() {
-                let mut series_column = w::Column::new();
-                let mut episodes = w::Column::new();
+                if schedule.is_empty() {
+                    column = column.push(w::text("Nothing scheduled").size(SMALL_SIZE));
+                }
 
-                for episode_id in &schedule.episodes {
-                    let Some(episode) = cx.service.episode(episode_id) else {
-                        continue;
+                let mut it = schedule
+                    .iter()
+                    .filter(|sched| match sched {
+                        Scheduled::Series(s) => cx.service.series(&s.series_id).is_some(),
+                        Scheduled::Movie(id) => cx.service.movie(id).is_some(),
+                    })
+                    .peekable();
+
+                if let Some((series_id, id)) = self.schedule_focus.as_ref().filter(|_| first) {
+                    let poster = match id
+                        .as_ref()
+                        .and_then(|id| cx.assets.image_with_hint(id, POSTER_HINT))
+                    {
+                        Some(image) => image,
+                        None => match cx.service.series(series_id) {
+                            Some(series) => cx.poster_placeholder(id.as_ref(), &series.title),
+                            None => cx.missing_poster(),
+                        },
                     };
 
-                    let name = match &episode.name {
-                        Some(name) => {
-                            format!("{}x{} {name}", episode.season.short(), episode.number)
+                    cols = cols.push(
+                        link(w::image(poster))
+                            .on_press(Message::Navigate(page::series::page(*series_id)))
+                            .width(Length::FillPortion(1)),
+                    );
+
+                    count += 1;
+                    first = false;
+                }
+
+                while let Some(sched) = it.next() {
+                    let schedule = match sched {
+                        Scheduled::Series(schedule) => schedule,
+                        Scheduled::Movie(movie_id) => {
+                            if let Some(movie) = cx.service.movie(movie_id) {
+                                let title =
+                                    link(w::text(&movie.title).shaping(w::text::Shaping::Advanced))
+                                        .on_press(Message::Navigate(page::movie::page(movie.id)));
+
+                                column = column.push(
+                                    w::Column::new()
+                                        .push(title)
+                                        .push(w::text("Movie release").size(SMALL_SIZE))
+                                        .spacing(SPACE),
+                                );
+                            }
+
+                            if it.peek().is_some() {
+                                column = column.push(w::horizontal_rule(1));
+                            }
+
+                            continue;
                         }
-                        None => format!("{}x{}", episode.season.short(), episode.number),
                     };
 
-                    let episode = link(
-                        w::text(name)
-                            .shaping(w::text::Shaping::Advanced)
-                            .size(SMALL_SIZE),
-                    )
-                    .on_press(Message::Navigate(page::season::page(
-                        series.id,
-                        episode.season,
-                    )));
+                    let Some(series) = cx.service.series(&schedule.series_id) else {
+                        continue;
+                    };
 
-                    episodes = episodes
-                        .push(Hoverable::new(episode).on_hover(Message::HoverScheduled(series.id)));
-                }
+                    let mut series_column = w::Column::new();
+                    let mut episodes = w::Column::new();
+
+                    for episode_id in &schedule.episodes {
+                        let Some(episode) = cx.service.episode(episode_id) else {
+                            continue;
+                        };
+
+                        let name = match &episode.name {
+                            Some(name) => {
+                                format!("{}x{} {name}", episode.season.short(), episode.number)
+                            }
+                            None => format!("{}x{}", episode.season.short(), episode.number),
+                        };
+
+                        let name = match episode.air_time {
+                            Some(time) => format!("{} {name}", time.format("%H:%M")),
+                            None => name,
+                        };
+
+                        let episode = link(
+                            w::text(name)
+                                .shaping(w::text::Shaping::Advanced)
+                                .size(SMALL_SIZE),
+                        )
+                        .on_press(Message::Navigate(page::season::page(
+                            series.id,
+                            episode.season,
+                        )));
+
+                        episodes = episodes.push(
+                            Hoverable::new(episode).on_hover(Message::HoverScheduled(series.id)),
+                        );
+                    }
 
-                let title = link(w::text(&series.title).shaping(w::text::Shaping::Advanced))
-                    .on_press(Message::Navigate(page::series::page(series.id)));
+                    let title = link(w::text(&series.title).shaping(w::text::Shaping::Advanced))
+                        .on_press(Message::Navigate(page::series::page(series.id)));
 
-                series_column = series_column
-                    .push(Hoverable::new(title).on_hover(Message::HoverScheduled(series.id)));
+                    series_column = series_column
+                        .push(Hoverable::new(title).on_hover(Message::HoverScheduled(series.id)));
 
-                series_column = series_column.push(episodes.spacing(SPACE));
+                    series_column = series_column.push(episodes.spacing(SPACE));
 
-                column = column.push(series_column.spacing(SPACE));
+                    column = column.push(series_column.spacing(SPACE));
 
-                if it.peek().is_some() {
-                    column = column.push(w::horizontal_rule(1));
+                    if it.peek().is_some() {
+                        column = column.push(w::horizontal_rule(1));
+                    }
                 }
-            }
 
-            cols = cols.push(column.width(Length::FillPortion(1)).spacing(GAP));
-        }
+                cols = cols.push(column.width(Length::FillPortion(1)).spacing(GAP));
+            }
 
-        if count > 0 {
-            scheduled_rows = scheduled_rows.push(cols.spacing(GAP));
+            if count > 0 {
+                scheduled_rows = scheduled_rows.push(cols.spacing(GAP));
+            }
         }
 
         scheduled_rows
@@ -474,3 +758,18 @@ fn episode_title(episode: &Episode) -> w::Text<'static> {
         w::text(episode_number)
     }
 }
+
+/// Summarize what has been watched today and the current streak.
+fn streak_summary(activity: &Activity) -> String {
+    let today = match activity.today {
+        0 => String::from("Nothing watched today"),
+        1 => String::from("Watched 1 episode or movie today"),
+        n => format!("Watched {n} episodes or movies today"),
+    };
+
+    match activity.streak {
+        0 => today,
+        1 => format!("{today}, 1 day streak"),
+        n => format!("{today}, {n} day streak"),
+    }
+}
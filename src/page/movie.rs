@@ -16,16 +16,21 @@ pub(crate) fn page(id: MovieId) -> Page {
 #[derive(Debug, Clone)]
 pub(crate) enum Message {
     OpenRemote(RemoteId),
+    CopyLink(String),
     MovieActions(comps::movie_actions::Message),
     MovieItem(comps::movie_item::Message),
     MovieBanner(comps::movie_banner::Message),
     SwitchMovie(MovieId, RemoteId),
+    WatchCollection(comps::confirm::Message),
+    Notes(comps::notes::Message),
 }
 
 pub(crate) struct Movie {
     movie_actions: comps::MovieActions,
+    watch_collection: comps::Confirm,
     banner: comps::MovieBanner,
     movie_item: comps::MovieItem,
+    notes: comps::Notes,
 }
 
 impl Movie {
@@ -33,21 +38,30 @@ impl Movie {
     pub(crate) fn new(cx: &CtxtRef<'_>, state: &State) -> Self {
         Self {
             movie_actions: comps::MovieActions::new(state.id),
+            watch_collection: comps::Confirm::new(comps::confirm::Props::new(
+                comps::confirm::Kind::WatchCollection { movie_id: state.id },
+            )),
             banner: comps::MovieBanner,
             movie_item: comps::MovieItem::new(comps::movie_item::Props {
                 movie_id: state.id,
                 watched: cx.service.watched_by_movie(&state.id),
             }),
+            notes: comps::Notes::new(comps::notes::Kind::Movie(state.id)),
         }
     }
 
     pub(crate) fn prepare(&mut self, cx: &mut Ctxt<'_>, state: &State) {
         self.banner.prepare(cx, &state.id);
+        self.watch_collection.changed(comps::confirm::Props::new(
+            comps::confirm::Kind::WatchCollection { movie_id: state.id },
+        ));
         self.movie_item.changed(comps::movie_item::Props {
             movie_id: state.id,
             watched: cx.service.watched_by_movie(&state.id),
         });
         self.movie_item.prepare(cx);
+        self.notes.changed(comps::notes::Kind::Movie(state.id));
+        self.notes.prepare(cx);
     }
 
     pub(crate) fn update(&mut self, cx: &mut Ctxt<'_>, message: Message) {
@@ -56,6 +70,9 @@ impl Movie {
                 let url = remote_id.url();
                 let _ = webbrowser::open_browser(webbrowser::Browser::Default, &url);
             }
+            Message::CopyLink(url) => {
+                cx.state.copy_to_clipboard(url);
+            }
             Message::MovieActions(message) => {
                 self.movie_actions.update(cx, message);
             }
@@ -65,6 +82,12 @@ impl Movie {
             Message::MovieBanner(message) => {
                 self.banner.update(cx, message);
             }
+            Message::WatchCollection(message) => {
+                self.watch_collection.update(cx, message);
+            }
+            Message::Notes(message) => {
+                self.notes.update(cx, message);
+            }
             Message::SwitchMovie(movie_id, remote_id) => {
                 cx.service.push_task_without_delay(TaskKind::DownloadMovie {
                     movie_id,
@@ -76,11 +99,11 @@ impl Movie {
         }
     }
 
-    pub(crate) fn view(
-        &self,
+    pub(crate) fn view<'a>(
+        &'a self,
         cx: &CtxtRef<'_>,
         state: &State,
-    ) -> Result<Element<'static, Message>> {
+    ) -> Result<Element<'a, Message>> {
         let Some(movie) = cx.service.movie(&state.id) else {
             bail!("Missing movie {}", state.id);
         };
@@ -122,17 +145,53 @@ impl Movie {
                 remotes = remotes.push(row);
             }
 
+            if let Some(url) = cx.service.movie_share_url(&movie.id) {
+                remotes = remotes.push(
+                    w::button(w::text("Copy link").size(SMALL_SIZE))
+                        .style(theme::Button::Secondary)
+                        .on_press(Message::CopyLink(url)),
+                );
+            }
+
             top = top.push(remotes.spacing(GAP));
         }
 
-        let info = w::Column::new()
+        let mut info = w::Column::new()
             .push(top.align_items(Alignment::Center).spacing(GAP))
             .push(
                 self.movie_actions
                     .view(cx, movie)
                     .map(Message::MovieActions),
-            )
-            .push(self.movie_item.view(cx, false)?.map(Message::MovieItem));
+            );
+
+        if let Some(collection) = &movie.collection {
+            let mut row = w::Row::new()
+                .push(cx.style.text(format_args!("Collection: {collection}")).sm())
+                .align_items(Alignment::Center)
+                .spacing(SPACE);
+
+            let today = cx.state.today();
+
+            let any_unwatched = cx
+                .service
+                .movies_by_collection(collection)
+                .filter(|m| m.has_released(today))
+                .any(|m| cx.service.watched_by_movie(&m.id).len() == 0);
+
+            if any_unwatched || self.watch_collection.is_confirm() {
+                row = row.push(
+                    self.watch_collection
+                        .view("Watch collection", theme::Button::Positive)
+                        .map(Message::WatchCollection),
+                );
+            }
+
+            info = info.push(row);
+        }
+
+        let info = info
+            .push(self.movie_item.view(cx, false)?.map(Message::MovieItem))
+            .push(self.notes.view().map(Message::Notes));
 
         let info = centered(info.spacing(GAP), None).padding(GAP);
         Ok(info.into())
@@ -6,6 +6,8 @@ pub(crate) enum Message {
     ChangeFilter(String),
     MovieActions(usize, comps::movie_actions::Message),
     Navigate(Page),
+    /// Merge the second movie into the first.
+    MergeMovies(MovieId, MovieId),
 }
 
 #[derive(Default)]
@@ -13,11 +15,15 @@ pub(crate) struct MoviesList {
     filter: String,
     filtered: Option<Box<[MovieId]>>,
     actions: Vec<comps::MovieActions>,
+    /// Movies which are likely duplicates of each other.
+    duplicates: Vec<(MovieId, MovieId)>,
 }
 
 impl MoviesList {
     /// Prepare the view.
     pub(crate) fn prepare(&mut self, cx: &mut Ctxt<'_>) {
+        self.duplicates = cx.service.find_duplicate_movies();
+
         if let Some(filtered) = &self.filtered {
             let movies = filtered.iter().flat_map(|id| cx.service.movie(id));
             self.actions.init_from_iter(movies.clone().map(|s| s.id));
@@ -62,6 +68,11 @@ impl MoviesList {
             Message::Navigate(page) => {
                 cx.push_history(page);
             }
+            Message::MergeMovies(keep, drop) => {
+                let now = Utc::now();
+                cx.service.merge_movies(&now, &keep, &drop);
+                self.duplicates.retain(|&(a, b)| a != drop && b != drop);
+            }
         }
     }
 
@@ -85,7 +96,7 @@ impl MoviesList {
                 .and_then(|i| cx.assets.image_with_hint(i, POSTER_HINT))
             {
                 Some(handle) => handle,
-                None => cx.missing_poster(),
+                None => cx.poster_placeholder(movies.poster(), &movies.title),
             };
 
             let graphic = link(w::image(poster).height(IMAGE_HEIGHT))
@@ -128,13 +139,56 @@ impl MoviesList {
             .on_input(Message::ChangeFilter)
             .width(Length::Fill);
 
-        w::Column::new()
-            .push(centered(
-                w::Row::new().push(filter).padding(GAP).width(Length::Fill),
-                None,
-            ))
-            .push(rows.spacing(GAP2))
-            .width(Length::Fill)
-            .into()
+        let mut page = w::Column::new().push(centered(
+            w::Row::new().push(filter).padding(GAP).width(Length::Fill),
+            None,
+        ));
+
+        if let Some(duplicates) = self.render_duplicates(cx) {
+            page = page.push(centered(duplicates.padding(GAP).spacing(SPACE), None));
+        }
+
+        page.push(rows.spacing(GAP2)).width(Length::Fill).into()
+    }
+
+    fn render_duplicates(&self, cx: &CtxtRef<'_>) -> Option<w::Column<'static, Message>> {
+        if self.duplicates.is_empty() {
+            return None;
+        }
+
+        let mut column = w::Column::new().push(
+            w::text("Possible duplicates")
+                .size(SUBTITLE_SIZE)
+                .width(Length::Fill),
+        );
+
+        for &(keep, drop) in &self.duplicates {
+            let (Some(a), Some(b)) = (cx.service.movie(&keep), cx.service.movie(&drop)) else {
+                continue;
+            };
+
+            column = column.push(
+                w::Row::new()
+                    .push(
+                        link(w::text(&a.title).shaping(w::text::Shaping::Advanced))
+                            .on_press(Message::Navigate(page::movie::page(keep))),
+                    )
+                    .push(w::text("and").size(SMALL_SIZE))
+                    .push(
+                        link(w::text(&b.title).shaping(w::text::Shaping::Advanced))
+                            .on_press(Message::Navigate(page::movie::page(drop))),
+                    )
+                    .push(w::horizontal_space())
+                    .push(
+                        w::button(w::text("Merge").size(SMALL_SIZE))
+                            .style(theme::Button::Primary)
+                            .on_press(Message::MergeMovies(keep, drop)),
+                    )
+                    .spacing(SPACE)
+                    .align_items(Alignment::Center),
+            );
+        }
+
+        Some(column)
     }
 }
@@ -3,11 +3,14 @@ use std::time::Duration;
 use serde::{Deserialize, Serialize};
 
 use crate::prelude::*;
-use crate::queue::TaskKind;
+use crate::queue::{Task, TaskKind};
 use crate::utils::{TimedOut, Timeout};
 
 const LIMIT: usize = 8;
 const UPDATE_TIMER: u64 = 10;
+/// Number of seconds after which a running task is considered to be possibly
+/// stuck.
+const STUCK_SECONDS: i64 = 120;
 
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
@@ -35,6 +38,10 @@ pub(crate) enum Message {
     OpenRemoteSeries(RemoteId),
     /// Open the given remote movie.
     OpenRemoteMovie(RemoteId),
+    /// Run a completed task again.
+    Rerun(TaskKind),
+    /// Move a pending task up or down in the queue.
+    MoveTask(TaskId, isize),
 }
 
 /// The state for the settings page.
@@ -77,6 +84,12 @@ impl Queue {
                 let url = remote_id.url();
                 let _ = webbrowser::open(&url);
             }
+            Message::Rerun(kind) => {
+                cx.service.rerun_task(&kind);
+            }
+            Message::MoveTask(id, delta) => {
+                cx.service.move_task(id, delta);
+            }
         }
     }
 
@@ -136,11 +149,33 @@ impl Queue {
                     .then_some(usize::MAX)
                     .unwrap_or(LIMIT)
                 {
-                    let Some(task) = running.next() else {
+                    let Some(r) = running.next() else {
                         break;
                     };
 
-                    let row = build_task_row(cx, &task.kind, Temporal::Now);
+                    let elapsed = now.signed_duration_since(r.started);
+
+                    let mut row = build_task_row(cx, &r.task.kind, Temporal::Now);
+
+                    let mut text =
+                        w::text(format_args!("running for {}", elapsed_display(elapsed)))
+                            .size(SMALL_SIZE);
+
+                    if elapsed.num_seconds() >= STUCK_SECONDS {
+                        row = row.push(
+                            w::text("Possibly stuck")
+                                .size(SMALL_SIZE)
+                                .style(cx.warning_text()),
+                        );
+                        text = text.style(cx.warning_text());
+                    }
+
+                    row = row.push(text);
+
+                    if let Some(attempts) = attempts_text(&r.task) {
+                        row = row.push(attempts);
+                    }
+
                     list = list.push(row.width(Length::Fill).spacing(GAP));
 
                     if peek!() {
@@ -154,7 +189,9 @@ impl Queue {
             if matches!(state, State::Default | State::Pending) {
                 title!(pending, "Pending", "No pending tasks");
 
-                for _ in 0..matches!(state, State::Pending)
+                let count = pending.len();
+
+                for index in 0..matches!(state, State::Pending)
                     .then_some(usize::MAX)
                     .unwrap_or(LIMIT)
                 {
@@ -170,6 +207,27 @@ impl Queue {
 
                     let mut row = build_task_row(cx, &task.kind, Temporal::Future);
                     row = row.push(duration_display(duration).size(SMALL_SIZE));
+
+                    if let Some(attempts) = attempts_text(task) {
+                        row = row.push(attempts);
+                    }
+
+                    let up =
+                        w::button(w::text("Up").size(SMALL_SIZE)).style(theme::Button::Secondary);
+                    let down =
+                        w::button(w::text("Down").size(SMALL_SIZE)).style(theme::Button::Secondary);
+
+                    row = row
+                        .push(if index > 0 {
+                            up.on_press(Message::MoveTask(task.id, -1))
+                        } else {
+                            up
+                        })
+                        .push(if index + 1 < count {
+                            down.on_press(Message::MoveTask(task.id, 1))
+                        } else {
+                            down
+                        });
                     list = list.push(row.width(Length::Fill).spacing(GAP));
 
                     if peek!() {
@@ -192,8 +250,29 @@ impl Queue {
                     };
 
                     let mut row = build_task_row(cx, &c.task.kind, Temporal::Past);
+
+                    if let Some(duration) = c.duration() {
+                        row = row.push(
+                            w::text(format_args!("took {}", elapsed_display(duration)))
+                                .size(SMALL_SIZE),
+                        );
+                    }
+
                     row = row
                         .push(duration_display(now.signed_duration_since(c.at)).size(SMALL_SIZE));
+
+                    if let Some(attempts) = attempts_text(&c.task) {
+                        row = row.push(attempts);
+                    }
+
+                    let rerun = w::button(w::text("Re-run").size(SMALL_SIZE))
+                        .style(theme::Button::Secondary);
+
+                    row = row.push(if cx.service.can_rerun_task(&c.task.kind) {
+                        rerun.on_press(Message::Rerun(c.task.kind.clone()))
+                    } else {
+                        rerun
+                    });
                     list = list.push(row.width(Length::Fill).spacing(GAP));
 
                     if peek!() {
@@ -218,6 +297,16 @@ impl Queue {
     }
 }
 
+/// Describe which attempt a task is on, if it has been retried.
+fn attempts_text(task: &Task) -> Option<w::Text<'static>> {
+    if task.attempts == 0 {
+        return None;
+    }
+
+    let attempt = u32::from(task.attempts) + 1;
+    Some(w::text(format_args!("attempt {attempt}")).size(SMALL_SIZE))
+}
+
 fn build_task_row<'a>(cx: &CtxtRef<'_>, kind: &TaskKind, t: Temporal) -> w::Row<'a, Message> {
     let mut update = w::Row::new();
 
@@ -250,6 +339,19 @@ fn build_task_row<'a>(cx: &CtxtRef<'_>, kind: &TaskKind, t: Temporal) -> w::Row<
             update = update.push(w::text(text).size(SMALL_SIZE));
             update = decorate_series(cx, *series_id, *remote_id, update);
         }
+        TaskKind::ResolveEpisodeRemotes {
+            series_id,
+            remote_id,
+        } => {
+            let text = match t {
+                Temporal::Past => "Re-resolved episode ids",
+                Temporal::Now => "Re-resolving episode ids",
+                Temporal::Future => "Re-resolve episode ids",
+            };
+
+            update = update.push(w::text(text).size(SMALL_SIZE));
+            update = decorate_series(cx, *series_id, *remote_id, update);
+        }
         TaskKind::DownloadMovie {
             movie_id,
             remote_id,
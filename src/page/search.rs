@@ -4,9 +4,14 @@ use serde::{Deserialize, Serialize};
 
 use crate::prelude::*;
 use crate::queue::{TaskKind, TaskRef, TaskStatus};
+use crate::service::LocalSearchHit;
 
 /// Number of results per page.
 const PER_PAGE: usize = 5;
+/// Number of results shown when only searching the library.
+const LIBRARY_LIMIT: usize = 25;
+/// Identifier of the query input, so that it can be focused.
+pub(crate) const QUERY_ID: &str = "search-query";
 
 /// Message generated by dashboard page.
 #[derive(Debug, Clone)]
@@ -17,7 +22,9 @@ pub(crate) enum Message {
     Change(String),
     SeriesPage(usize),
     MoviesPage(usize),
-    Result(Vec<SearchSeries>, Vec<SearchMovie>),
+    TvdbPage(usize),
+    TvdbResult(Uuid, Vec<SearchSeries>),
+    TmdbResult(Uuid, Vec<SearchSeries>, Vec<SearchMovie>),
     SearchKindChanged(SearchKind),
     AddSeriesByRemote(RemoteId),
     SwitchSeries(SeriesId, RemoteId),
@@ -25,6 +32,7 @@ pub(crate) enum Message {
     AddMovieByRemote(RemoteId),
     SwitchMovie(#[allow(unused)] MovieId, #[allow(unused)] RemoteId),
     RemoveMovie(#[allow(unused)] MovieId),
+    QuickAdd(RemoteLink),
 }
 
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -33,6 +41,10 @@ pub(crate) enum SearchKind {
     Tvdb,
     #[default]
     Tmdb,
+    /// Search the local library and every remote source at once.
+    All,
+    /// Only search the local library.
+    Library,
 }
 
 impl fmt::Display for SearchKind {
@@ -41,6 +53,8 @@ impl fmt::Display for SearchKind {
         match self {
             SearchKind::Tvdb => write!(f, "thetvdb.com"),
             SearchKind::Tmdb => write!(f, "themoviedb.com"),
+            SearchKind::All => write!(f, "Library and all sources"),
+            SearchKind::Library => write!(f, "Library"),
         }
     }
 }
@@ -51,6 +65,8 @@ pub(crate) struct State {
     text: String,
     series_page: usize,
     movies_page: usize,
+    #[serde(default)]
+    tvdb_page: usize,
     // Unique identifier of last search so that we can look up any recorded errors.
     search_id: Uuid,
     // Current search kind.
@@ -60,7 +76,13 @@ pub(crate) struct State {
 /// The state for the settings page.
 #[derive(Default)]
 pub(crate) struct Search {
+    /// Matches in the local library.
+    local: Vec<LocalSearchHit>,
+    /// Series found on thetvdb.com.
+    tvdb: Vec<SearchSeries>,
+    /// Series found on themoviedb.com.
     series: Vec<SearchSeries>,
+    /// Movies found on themoviedb.com.
     movies: Vec<SearchMovie>,
     initialized: bool,
 }
@@ -91,6 +113,27 @@ impl Search {
             POSTER_HINT,
         );
 
+        cx.assets.mark_with_hint(
+            self.tvdb
+                .iter()
+                .skip(state.tvdb_page * PER_PAGE)
+                .take(PER_PAGE)
+                .flat_map(|s| s.poster()),
+            POSTER_HINT,
+        );
+
+        cx.assets.mark_with_hint(
+            self.local
+                .iter()
+                .take(local_limit(state.kind))
+                .flat_map(|hit| match hit {
+                    LocalSearchHit::Series(id) => cx.service.series(id)?.poster(),
+                    LocalSearchHit::Movie(id) => cx.service.movie(id)?.poster(),
+                    LocalSearchHit::Episode(..) => None,
+                }),
+            POSTER_HINT,
+        );
+
         if !self.initialized {
             self.initialized = true;
             self.search(cx, state, commands);
@@ -117,6 +160,12 @@ impl Search {
             }
             Message::Change(text) => {
                 state.text = text;
+
+                // NB: The library is searched as you type, since it doesn't
+                // involve any network calls.
+                if state.kind == SearchKind::Library {
+                    self.local = cx.service.search_local(&state.text);
+                }
             }
             Message::SeriesPage(page) => {
                 state.series_page = page;
@@ -126,9 +175,25 @@ impl Search {
                 state.movies_page = page;
                 cx.assets.clear();
             }
-            Message::Result(series, movies) => {
-                self.series = series;
-                self.movies = movies;
+            Message::TvdbPage(page) => {
+                state.tvdb_page = page;
+                cx.assets.clear();
+            }
+            Message::TvdbResult(search_id, series) => {
+                if search_id != state.search_id {
+                    return;
+                }
+
+                self.tvdb = self.dedup_series(cx, series);
+                cx.assets.clear();
+            }
+            Message::TmdbResult(search_id, series, movies) => {
+                if search_id != state.search_id {
+                    return;
+                }
+
+                self.series = self.dedup_series(cx, series);
+                self.movies = self.dedup_movies(cx, movies);
                 cx.assets.clear();
             }
             Message::SearchKindChanged(kind) => {
@@ -140,7 +205,7 @@ impl Search {
                     .push_task_without_delay(TaskKind::DownloadSeriesByRemoteId { remote_id });
             }
             Message::SwitchSeries(series_id, remote_id) => {
-                cx.remove_series(&series_id);
+                cx.delete_series(&series_id);
                 cx.service
                     .push_task_without_delay(TaskKind::DownloadSeriesByRemoteId { remote_id });
             }
@@ -153,6 +218,49 @@ impl Search {
             }
             Message::SwitchMovie(_, _) => {}
             Message::RemoveMovie(_) => {}
+            Message::QuickAdd(link) => {
+                self.quick_add(cx, state, link, commands);
+            }
+        }
+    }
+
+    /// Track whatever the given link refers to.
+    fn quick_add(
+        &mut self,
+        cx: &mut Ctxt<'_>,
+        state: &mut State,
+        link: RemoteLink,
+        mut commands: impl Commands<Message>,
+    ) {
+        match link {
+            RemoteLink::Series(remote_id) => {
+                cx.service
+                    .push_task_without_delay(TaskKind::DownloadSeriesByRemoteId { remote_id });
+            }
+            RemoteLink::Movie(remote_id) => {
+                cx.service
+                    .push_task_without_delay(TaskKind::DownloadMovieByRemoteId { remote_id });
+            }
+            RemoteLink::Imdb(id) => {
+                let search_id = state.search_id;
+                let op = cx.service.find_imdb(id);
+
+                let translate = move |out: Result<_>| match out {
+                    Ok(Some(link @ (RemoteLink::Series(..) | RemoteLink::Movie(..)))) => {
+                        Message::QuickAdd(link)
+                    }
+                    Ok(_) => Message::Error(ErrorInfo::new(
+                        ErrorId::Search(search_id),
+                        anyhow!("No series or movie found for imdb:{id}"),
+                    )),
+                    Err(error) => Message::Error(ErrorInfo::new(
+                        ErrorId::Search(search_id),
+                        error.context(anyhow!("Looking up imdb:{id}")),
+                    )),
+                };
+
+                commands.perform(op, translate);
+            }
         }
     }
 
@@ -162,309 +270,532 @@ impl Search {
         state: &mut State,
         mut commands: impl Commands<Message>,
     ) {
+        if state.kind == SearchKind::Library {
+            self.local = cx.service.search_local(&state.text);
+            return;
+        }
+
         if state.text.is_empty() {
             return;
         }
 
         state.series_page = 0;
         state.movies_page = 0;
+        state.tvdb_page = 0;
 
         let search_id = Uuid::new_v4();
         let query = state.text.clone();
         state.search_id = search_id;
-        let kind = state.kind;
 
-        match kind {
-            SearchKind::Tvdb => {
-                let op = cx.service.search_tvdb(&state.text);
+        match RemoteLink::parse(&query) {
+            Ok(Some(link)) => {
+                self.quick_add(cx, state, link, commands);
+                return;
+            }
+            Ok(None) => {}
+            Err(error) => {
+                cx.state
+                    .handle_error(ErrorInfo::new(ErrorId::Search(search_id), error));
+                return;
+            }
+        }
+
+        self.local.clear();
+        self.tvdb.clear();
+        self.series.clear();
+        self.movies.clear();
+
+        // NB: Local results are available immediately, so they're shown while
+        // waiting for remote sources.
+        if state.kind == SearchKind::All {
+            self.local = cx.service.search_local(&query);
+        }
+
+        // NB: Sources are searched independently, so that an error in one
+        // doesn't hide results from the others.
+        if matches!(state.kind, SearchKind::Tvdb | SearchKind::All) {
+            let kind = SearchKind::Tvdb;
+            let query = query.clone();
+            let op = cx.service.search_tvdb(&query);
+
+            let translate = move |out: Result<_>| match out
+                .with_context(|| anyhow!("Searching {kind} for `{query}`"))
+            {
+                Ok(series) => Message::TvdbResult(search_id, series),
+                Err(error) => Message::Error(ErrorInfo::new(ErrorId::Search(search_id), error)),
+            };
+
+            commands.perform(op, translate);
+        }
+
+        if matches!(state.kind, SearchKind::Tmdb | SearchKind::All) {
+            let kind = SearchKind::Tmdb;
+            let series = cx.service.search_series_tmdb(&query);
+            let movies = cx.service.search_movies_tmdb(&query);
 
-                let translate = move |out: Result<_>| match out
+            let op = async move {
+                match tokio::try_join!(series, movies)
                     .with_context(|| anyhow!("Searching {kind} for `{query}`"))
                 {
-                    Ok(series) => Message::Result(series, Vec::new()),
+                    Ok((series, movies)) => Message::TmdbResult(search_id, series, movies),
                     Err(error) => Message::Error(ErrorInfo::new(ErrorId::Search(search_id), error)),
-                };
-
-                commands.perform(op, translate);
-            }
-            SearchKind::Tmdb => {
-                let series = cx.service.search_series_tmdb(&state.text);
-                let movies = cx.service.search_movies_tmdb(&state.text);
-
-                let op = async move {
-                    match tokio::try_join!(series, movies) {
-                        Ok((series, movies)) => Message::Result(series, movies),
-                        Err(error) => {
-                            Message::Error(ErrorInfo::new(ErrorId::Search(search_id), error))
-                        }
-                    }
-                };
+                }
+            };
 
-                commands.perform(op, |out| out);
-            }
+            commands.perform(op, |out| out);
         }
     }
 
-    /// Generate the view for the settings page.
-    pub(crate) fn view(&self, cx: &CtxtRef<'_>, state: &State) -> Element<'static, Message> {
-        let mut series = w::Column::new();
+    /// Remove remote series which are already listed among local results.
+    fn dedup_series(&self, cx: &Ctxt<'_>, series: Vec<SearchSeries>) -> Vec<SearchSeries> {
+        series
+            .into_iter()
+            .filter(|s| {
+                !cx.service
+                    .get_series_by_remote(&s.id)
+                    .is_some_and(|local| self.local.contains(&LocalSearchHit::Series(local.id)))
+            })
+            .collect()
+    }
 
-        for s in self
-            .series
-            .iter()
-            .skip(state.series_page * PER_PAGE)
-            .take(PER_PAGE)
-        {
-            let local_series = cx.service.get_series_by_remote(&s.id);
+    /// Remove remote movies which are already listed among local results.
+    fn dedup_movies(&self, cx: &Ctxt<'_>, movies: Vec<SearchMovie>) -> Vec<SearchMovie> {
+        movies
+            .into_iter()
+            .filter(|m| {
+                !cx.service
+                    .get_movie_by_remote(&m.id)
+                    .is_some_and(|local| self.local.contains(&LocalSearchHit::Movie(local.id)))
+            })
+            .collect()
+    }
 
-            let handle = match s
-                .poster()
-                .and_then(|p| cx.assets.image_with_hint(p, POSTER_HINT))
-            {
-                Some(handle) => handle,
-                None => cx.missing_poster(),
-            };
+    /// Generate the view for the settings page.
+    pub(crate) fn view(&self, cx: &CtxtRef<'_>, state: &State) -> Element<'static, Message> {
+        let query = w::text_input("Query, or paste a link to add it...", &state.text)
+            .id(w::text_input::Id::new(QUERY_ID))
+            .on_input(Message::Change)
+            .on_submit(Message::Search);
 
-            let mut actions = w::Row::new();
+        let submit = w::button(cx.style.text("Search"));
 
-            let status = cx
-                .service
-                .task_status(TaskRef::RemoteSeries { remote_id: s.id });
+        let submit = if !state.text.is_empty() {
+            submit.on_press(Message::Search)
+        } else {
+            submit
+        };
 
-            match status {
-                Some(TaskStatus::Pending) => {
-                    actions = actions.push(
-                        w::button(cx.style.text("Queued...").sm()).style(theme::Button::Primary),
-                    );
-                }
-                Some(TaskStatus::Running) => {
-                    actions = actions.push(
-                        w::button(cx.style.text("Downloading...").sm())
-                            .style(theme::Button::Primary),
-                    );
-                }
-                None => {
-                    if let Some(local) = local_series {
-                        if local.remote_id != Some(s.id) {
-                            actions = actions.push(
-                                w::button(cx.style.text("Switch").sm())
-                                    .style(theme::Button::Primary)
-                                    .on_press(Message::SwitchSeries(local.id, s.id)),
-                            );
-                        }
+        let mut search_kind = w::Column::new().push(cx.style.text("Source:").sm());
 
-                        actions = actions.push(
-                            w::button(cx.style.text("Remove").sm())
-                                .style(theme::Button::Destructive)
-                                .on_press(Message::RemoveSeries(local.id)),
-                        );
-                    } else {
-                        actions = actions.push(
-                            w::button(cx.style.text("Add").sm())
-                                .style(theme::Button::Positive)
-                                .on_press(Message::AddSeriesByRemote(s.id)),
-                        );
-                    }
-                }
-            }
+        search_kind = [
+            SearchKind::Tvdb,
+            SearchKind::Tmdb,
+            SearchKind::All,
+            SearchKind::Library,
+        ]
+        .iter()
+        .fold(search_kind, |column, kind| {
+            column.push(
+                w::radio(
+                    kind.to_string(),
+                    *kind,
+                    Some(state.kind),
+                    Message::SearchKindChanged,
+                )
+                .size(SMALL_SIZE),
+            )
+        });
 
-            let mut first_aired = w::Column::new();
+        let mut page = w::Column::new();
 
-            if let Some(date) = s.first_aired {
-                first_aired = first_aired.push(cx.style.text(format!("First aired: {date}")).sm());
-            }
+        page = page.push(cx.style.text("Search").title());
+        page = page.push(w::Row::new().push(query).push(submit));
 
-            let mut result = w::Column::new();
+        if let Some(e) = cx.state.get_error(ErrorId::Search(state.search_id)) {
+            page = page.push(
+                w::button(cx.style.text(format_args!("Error: {}", e.message)))
+                    .width(Length::Fill)
+                    .style(theme::Button::Destructive)
+                    .on_press(Message::Navigate(Page::Errors)),
+            );
+        }
 
-            let series_name = cx.style.text(&s.name).sub();
+        page = page.push(search_kind.spacing(SPACE));
 
-            if let Some(local_series) = local_series {
-                result = result.push(
-                    link(series_name)
-                        .on_press(Message::Navigate(page::series::page(local_series.id))),
-                );
-            } else {
-                result = result.push(series_name);
+        if matches!(state.kind, SearchKind::All | SearchKind::Library) {
+            if let Some(local) = self.local_results(cx, local_limit(state.kind)) {
+                page = page.push(cx.style.text("Library").sub());
+                page = page.push(local);
+            } else if state.kind == SearchKind::Library && !state.text.is_empty() {
+                page = page.push(cx.style.text("Nothing in the library matches").sm());
             }
+        }
 
-            result = result.push(first_aired);
-            result = result.push(actions.spacing(SPACE));
+        if state.kind == SearchKind::All {
+            page = page.push(cx.style.text(SearchKind::Tmdb).sub());
+        }
 
-            series = series.push(
-                w::Row::new()
-                    .push(w::image(handle).height(IMAGE_HEIGHT))
-                    .push(
-                        w::Column::new()
-                            .push(result.spacing(SPACE))
-                            .push(cx.style.text(&s.overview))
-                            .spacing(GAP),
-                    )
-                    .spacing(GAP),
+        if matches!(state.kind, SearchKind::Tmdb | SearchKind::All) {
+            let mut row = w::Row::new();
+            row = row.push(
+                series_results(cx, &self.series, state.series_page, Message::SeriesPage)
+                    .spacing(GAP2)
+                    .width(Length::FillPortion(1)),
             );
+            row = row.push(
+                movies_results(cx, &self.movies, state.movies_page, Message::MoviesPage)
+                    .spacing(GAP2)
+                    .width(Length::FillPortion(1)),
+            );
+            page = page.push(row.spacing(GAP2));
         }
 
-        series = series.push(paginate(
-            cx,
-            state.series_page,
-            self.series.len(),
-            Message::SeriesPage,
-        ));
+        if state.kind == SearchKind::All {
+            page = page.push(cx.style.text(SearchKind::Tvdb).sub());
+        }
 
-        let mut movies = w::Column::new();
+        if matches!(state.kind, SearchKind::Tvdb | SearchKind::All) {
+            page = page.push(
+                series_results(cx, &self.tvdb, state.tvdb_page, Message::TvdbPage).spacing(GAP2),
+            );
+        }
 
-        for m in self
-            .movies
-            .iter()
-            .skip(state.movies_page * PER_PAGE)
-            .take(PER_PAGE)
-        {
-            let local_movie = cx.service.get_movie_by_remote(&m.id);
+        default_container(page.spacing(GAP).padding(GAP)).into()
+    }
 
-            let handle = match m
-                .poster()
-                .and_then(|p| cx.assets.image_with_hint(p, POSTER_HINT))
-            {
-                Some(handle) => handle,
-                None => cx.missing_poster(),
-            };
+    /// Render matches from the local library.
+    fn local_results(&self, cx: &CtxtRef<'_>, limit: usize) -> Option<w::Column<'static, Message>> {
+        if self.local.is_empty() {
+            return None;
+        }
 
-            let mut actions = w::Row::new();
+        let mut column = w::Column::new();
+
+        for hit in self.local.iter().take(limit) {
+            match hit {
+                LocalSearchHit::Series(id) => {
+                    let Some(series) = cx.service.series(id) else {
+                        continue;
+                    };
+
+                    let handle = match series
+                        .poster()
+                        .and_then(|p| cx.assets.image_with_hint(p, POSTER_HINT))
+                    {
+                        Some(handle) => handle,
+                        None => cx.poster_placeholder(series.poster(), &series.title),
+                    };
+
+                    let mut info = w::Column::new().push(
+                        link(cx.style.text(&series.title).sub())
+                            .on_press(Message::Navigate(page::series::page(series.id))),
+                    );
 
-            let status = cx
-                .service
-                .task_status(TaskRef::RemoteMovie { remote_id: m.id });
+                    if let Some(date) = series.first_air_date {
+                        info = info.push(cx.style.text(format_args!("First aired: {date}")).sm());
+                    }
 
-            match status {
-                Some(TaskStatus::Pending) => {
-                    actions = actions.push(
-                        w::button(cx.style.text("Queued...").sm()).style(theme::Button::Primary),
+                    column = column.push(
+                        w::Row::new()
+                            .push(w::image(handle).height(IMAGE_HEIGHT))
+                            .push(info.spacing(SPACE))
+                            .spacing(GAP),
                     );
                 }
-                Some(TaskStatus::Running) => {
-                    actions = actions.push(
-                        w::button(cx.style.text("Downloading...").sm())
-                            .style(theme::Button::Primary),
+                LocalSearchHit::Movie(id) => {
+                    let Some(movie) = cx.service.movie(id) else {
+                        continue;
+                    };
+
+                    let handle = match movie
+                        .poster()
+                        .and_then(|p| cx.assets.image_with_hint(p, POSTER_HINT))
+                    {
+                        Some(handle) => handle,
+                        None => cx.poster_placeholder(movie.poster(), &movie.title),
+                    };
+
+                    let mut info = w::Column::new().push(
+                        link(cx.style.text(&movie.title).sub())
+                            .on_press(Message::Navigate(page::movie::page(movie.id))),
                     );
-                }
-                None => {
-                    if let Some(local) = local_movie {
-                        if local.remote_id != Some(m.id) {
-                            actions = actions.push(
-                                w::button(cx.style.text("Switch").sm())
-                                    .style(theme::Button::Primary)
-                                    .on_press(Message::SwitchMovie(local.id, m.id)),
-                            );
-                        }
 
-                        actions = actions.push(
-                            w::button(cx.style.text("Remove").sm())
-                                .style(theme::Button::Destructive)
-                                .on_press(Message::RemoveMovie(local.id)),
-                        );
-                    } else {
-                        actions = actions.push(
-                            w::button(cx.style.text("Add").sm())
-                                .style(theme::Button::Positive)
-                                .on_press(Message::AddMovieByRemote(m.id)),
-                        );
+                    if let Some(date) = movie.release_date {
+                        info = info.push(cx.style.text(format_args!("Released: {date}")).sm());
                     }
+
+                    column = column.push(
+                        w::Row::new()
+                            .push(w::image(handle).height(IMAGE_HEIGHT))
+                            .push(info.spacing(SPACE))
+                            .spacing(GAP),
+                    );
+                }
+                LocalSearchHit::Episode(series_id, episode_id) => {
+                    let (Some(series), Some(episode)) =
+                        (cx.service.series(series_id), cx.service.episode(episode_id))
+                    else {
+                        continue;
+                    };
+
+                    let name = episode.name.as_deref().unwrap_or_default();
+
+                    column = column.push(
+                        link(
+                            cx.style
+                                .text(format_args!(
+                                    "{}: S{}E{} {name}",
+                                    series.title,
+                                    episode.season.short(),
+                                    episode.number
+                                ))
+                                .sm(),
+                        )
+                        .on_press(Message::Navigate(page::season::page(
+                            series.id,
+                            episode.season,
+                        ))),
+                    );
                 }
             }
+        }
 
-            let mut release_date = w::Column::new();
+        let more = self.local.len().saturating_sub(limit);
 
-            if let Some(date) = m.release_date {
-                release_date =
-                    release_date.push(cx.style.text(format_args!("First aired: {date}")).sm());
-            }
+        if more > 0 {
+            column = column.push(
+                cx.style
+                    .text(format_args!("And {more} more in the library"))
+                    .sm(),
+            );
+        }
 
-            let mut result = w::Column::new();
+        Some(column.spacing(GAP))
+    }
+}
+
+/// Number of library matches to show for the given kind of search.
+fn local_limit(kind: SearchKind) -> usize {
+    match kind {
+        SearchKind::Library => LIBRARY_LIMIT,
+        _ => PER_PAGE,
+    }
+}
+
+/// Render a page of series search results.
+fn series_results(
+    cx: &CtxtRef<'_>,
+    results: &[SearchSeries],
+    current: usize,
+    to_page: fn(usize) -> Message,
+) -> w::Column<'static, Message> {
+    let mut series = w::Column::new();
+
+    for s in results.iter().skip(current * PER_PAGE).take(PER_PAGE) {
+        // NB: Removed series are treated as not being present, adding
+        // them back simply restores them.
+        let local_series = cx
+            .service
+            .get_series_by_remote(&s.id)
+            .filter(|s| s.removed.is_none());
+
+        let handle = match s
+            .poster()
+            .and_then(|p| cx.assets.image_with_hint(p, POSTER_HINT))
+        {
+            Some(handle) => handle,
+            None => cx.poster_placeholder(s.poster(), &s.name),
+        };
 
-            let movie_title = cx.style.text(&m.title).sub();
+        let mut actions = w::Row::new();
 
-            if let Some(local_movie) = local_movie {
-                result = result.push(
-                    link(movie_title)
-                        .on_press(Message::Navigate(page::movie::page(local_movie.id))),
+        let status = cx
+            .service
+            .task_status(TaskRef::RemoteSeries { remote_id: s.id });
+
+        match status {
+            Some(TaskStatus::Pending) => {
+                actions = actions
+                    .push(w::button(cx.style.text("Queued...").sm()).style(theme::Button::Primary));
+            }
+            Some(TaskStatus::Running) => {
+                actions = actions.push(
+                    w::button(cx.style.text("Downloading...").sm()).style(theme::Button::Primary),
                 );
-            } else {
-                result = result.push(movie_title);
             }
+            None => {
+                if let Some(local) = local_series {
+                    if local.remote_id != Some(s.id) {
+                        actions = actions.push(
+                            w::button(cx.style.text("Switch").sm())
+                                .style(theme::Button::Primary)
+                                .on_press(Message::SwitchSeries(local.id, s.id)),
+                        );
+                    }
+
+                    actions = actions.push(
+                        w::button(cx.style.text("Remove").sm())
+                            .style(theme::Button::Destructive)
+                            .on_press(Message::RemoveSeries(local.id)),
+                    );
+                } else {
+                    actions = actions.push(
+                        w::button(cx.style.text("Add").sm())
+                            .style(theme::Button::Positive)
+                            .on_press(Message::AddSeriesByRemote(s.id)),
+                    );
+                }
+            }
+        }
+
+        let mut first_aired = w::Column::new();
 
-            result = result.push(release_date);
-            result = result.push(actions.spacing(SPACE));
+        if let Some(date) = s.first_aired {
+            first_aired = first_aired.push(cx.style.text(format!("First aired: {date}")).sm());
+        }
+
+        let mut result = w::Column::new();
 
-            movies = movies.push(
+        let series_name = cx.style.text(&s.name).sub();
+
+        if let Some(local_series) = local_series {
+            result = result.push(
                 w::Row::new()
-                    .push(w::image(handle).height(IMAGE_HEIGHT))
                     .push(
-                        w::Column::new()
-                            .push(result.spacing(SPACE))
-                            .push(cx.style.text(&m.overview))
-                            .spacing(GAP),
+                        link(series_name)
+                            .on_press(Message::Navigate(page::series::page(local_series.id))),
                     )
-                    .spacing(GAP),
+                    .push(cx.style.text("In library").sm())
+                    .spacing(SPACE)
+                    .align_items(Alignment::Center),
             );
+        } else {
+            result = result.push(series_name);
         }
 
-        movies = movies.push(paginate(
-            cx,
-            state.movies_page,
-            self.movies.len(),
-            Message::MoviesPage,
-        ));
-
-        let query = w::text_input("Query...", &state.text)
-            .on_input(Message::Change)
-            .on_submit(Message::Search);
+        result = result.push(first_aired);
+        result = result.push(actions.spacing(SPACE));
+
+        series = series.push(
+            w::Row::new()
+                .push(w::image(handle).height(IMAGE_HEIGHT))
+                .push(
+                    w::Column::new()
+                        .push(result.spacing(SPACE))
+                        .push(cx.style.text(&s.overview))
+                        .spacing(GAP),
+                )
+                .spacing(GAP),
+        );
+    }
 
-        let submit = w::button(cx.style.text("Search"));
+    series.push(paginate(cx, current, results.len(), to_page))
+}
 
-        let submit = if !state.text.is_empty() {
-            submit.on_press(Message::Search)
-        } else {
-            submit
+/// Render a page of movie search results.
+fn movies_results(
+    cx: &CtxtRef<'_>,
+    results: &[SearchMovie],
+    current: usize,
+    to_page: fn(usize) -> Message,
+) -> w::Column<'static, Message> {
+    let mut movies = w::Column::new();
+
+    for m in results.iter().skip(current * PER_PAGE).take(PER_PAGE) {
+        let local_movie = cx.service.get_movie_by_remote(&m.id);
+
+        let handle = match m
+            .poster()
+            .and_then(|p| cx.assets.image_with_hint(p, POSTER_HINT))
+        {
+            Some(handle) => handle,
+            None => cx.poster_placeholder(m.poster(), &m.title),
         };
 
-        let mut search_kind = w::Column::new().push(cx.style.text("Source:").sm());
+        let mut actions = w::Row::new();
 
-        search_kind =
-            [SearchKind::Tvdb, SearchKind::Tmdb]
-                .iter()
-                .fold(search_kind, |column, kind| {
-                    column.push(
-                        w::radio(
-                            kind.to_string(),
-                            *kind,
-                            Some(state.kind),
-                            Message::SearchKindChanged,
-                        )
-                        .size(SMALL_SIZE),
-                    )
-                });
+        let status = cx
+            .service
+            .task_status(TaskRef::RemoteMovie { remote_id: m.id });
 
-        let mut page = w::Column::new();
+        match status {
+            Some(TaskStatus::Pending) => {
+                actions = actions
+                    .push(w::button(cx.style.text("Queued...").sm()).style(theme::Button::Primary));
+            }
+            Some(TaskStatus::Running) => {
+                actions = actions.push(
+                    w::button(cx.style.text("Downloading...").sm()).style(theme::Button::Primary),
+                );
+            }
+            None => {
+                if let Some(local) = local_movie {
+                    if local.remote_id != Some(m.id) {
+                        actions = actions.push(
+                            w::button(cx.style.text("Switch").sm())
+                                .style(theme::Button::Primary)
+                                .on_press(Message::SwitchMovie(local.id, m.id)),
+                        );
+                    }
 
-        page = page.push(cx.style.text("Search").title());
-        page = page.push(w::Row::new().push(query).push(submit));
+                    actions = actions.push(
+                        w::button(cx.style.text("Remove").sm())
+                            .style(theme::Button::Destructive)
+                            .on_press(Message::RemoveMovie(local.id)),
+                    );
+                } else {
+                    actions = actions.push(
+                        w::button(cx.style.text("Add").sm())
+                            .style(theme::Button::Positive)
+                            .on_press(Message::AddMovieByRemote(m.id)),
+                    );
+                }
+            }
+        }
 
-        if let Some(e) = cx.state.get_error(ErrorId::Search(state.search_id)) {
-            page = page.push(
-                w::button(cx.style.text(format_args!("Error: {}", e.message)))
-                    .width(Length::Fill)
-                    .style(theme::Button::Destructive)
-                    .on_press(Message::Navigate(Page::Errors)),
-            );
+        let mut release_date = w::Column::new();
+
+        if let Some(date) = m.release_date {
+            release_date =
+                release_date.push(cx.style.text(format_args!("First aired: {date}")).sm());
         }
 
-        page = page.push(search_kind.spacing(SPACE));
+        let mut result = w::Column::new();
 
-        let mut row = w::Row::new();
-        row = row.push(series.spacing(GAP2).width(Length::FillPortion(1)));
-        row = row.push(movies.spacing(GAP2).width(Length::FillPortion(1)));
-        page = page.push(row.spacing(GAP2));
-        default_container(page.spacing(GAP).padding(GAP)).into()
+        let movie_title = cx.style.text(&m.title).sub();
+
+        if let Some(local_movie) = local_movie {
+            result = result.push(
+                w::Row::new()
+                    .push(
+                        link(movie_title)
+                            .on_press(Message::Navigate(page::movie::page(local_movie.id))),
+                    )
+                    .push(cx.style.text("In library").sm())
+                    .spacing(SPACE)
+                    .align_items(Alignment::Center),
+            );
+        } else {
+            result = result.push(movie_title);
+        }
+
+        result = result.push(release_date);
+        result = result.push(actions.spacing(SPACE));
+
+        movies = movies.push(
+            w::Row::new()
+                .push(w::image(handle).height(IMAGE_HEIGHT))
+                .push(
+                    w::Column::new()
+                        .push(result.spacing(SPACE))
+                        .push(cx.style.text(&m.overview))
+                        .spacing(GAP),
+                )
+                .spacing(GAP),
+        );
     }
+
+    movies.push(paginate(cx, current, results.len(), to_page))
 }
 
 fn paginate<M>(cx: &CtxtRef<'_>, page: usize, len: usize, m: M) -> w::Row<'static, Message>
@@ -7,10 +7,23 @@ use crate::prelude::*;
 pub(crate) struct State {
     pub(crate) series_id: SeriesId,
     pub(crate) season: SeasonNumber,
+    /// Hide watched episodes, overriding the configured default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) hide_watched: Option<bool>,
+}
+
+impl State {
+    fn hide_watched(&self, config: &Config) -> bool {
+        self.hide_watched.unwrap_or(config.season_hide_watched)
+    }
 }
 
 pub(crate) fn page(series_id: SeriesId, season: SeasonNumber) -> Page {
-    Page::Season(State { series_id, season })
+    Page::Season(State {
+        series_id,
+        season,
+        hide_watched: None,
+    })
 }
 
 #[derive(Debug, Clone)]
@@ -19,10 +32,13 @@ pub(crate) enum Message {
     Episode(usize, comps::episode::Message),
     SeasonInfo(comps::season_info::Message),
     SeriesBanner(comps::series_banner::Message),
+    HideWatched(bool),
 }
 
 pub(crate) struct Season {
     episodes: Vec<comps::Episode>,
+    /// Number of episodes in the season, including hidden ones.
+    total: usize,
     season_info: comps::SeasonInfo,
     banner: comps::SeriesBanner,
 }
@@ -32,16 +48,26 @@ impl Season {
     pub(crate) fn new(state: &State) -> Self {
         Self {
             episodes: Vec::new(),
+            total: 0,
             season_info: comps::SeasonInfo::new((state.series_id, state.season)),
             banner: comps::SeriesBanner,
         }
     }
 
     pub(crate) fn prepare(&mut self, cx: &mut Ctxt<'_>, state: &State) {
+        let hide_watched = state.hide_watched(cx.service.config());
+
+        self.total = cx
+            .service
+            .episodes(&state.series_id)
+            .filter(|e| e.season == state.season)
+            .count();
+
         self.episodes.init_from_iter(
             cx.service
                 .episodes(&state.series_id)
                 .filter(|e| e.season == state.season)
+                .filter(|e| !hide_watched || cx.service.watched_by_episode(&e.id).len() == 0)
                 .map(|e| comps::episode::Props {
                     include_series: false,
                     episode_id: e.id,
@@ -56,8 +82,11 @@ impl Season {
         self.banner.prepare(cx, &state.series_id);
     }
 
-    pub(crate) fn update(&mut self, cx: &mut Ctxt<'_>, message: Message) {
+    pub(crate) fn update(&mut self, cx: &mut Ctxt<'_>, state: &mut State, message: Message) {
         match message {
+            Message::HideWatched(value) => {
+                state.hide_watched = Some(value);
+            }
             Message::OpenRemote(remote) => {
                 let url = remote.url();
                 let _ = webbrowser::open_browser(webbrowser::Browser::Default, &url);
@@ -92,6 +121,25 @@ impl Season {
 
         let mut episodes = w::Column::new();
 
+        let hide_watched = state.hide_watched(cx.service.config());
+
+        episodes = episodes.push(centered(
+            w::checkbox("Hide watched episodes", hide_watched)
+                .on_toggle(Message::HideWatched)
+                .width(Length::Fill),
+            None,
+        ));
+
+        if self.episodes.is_empty() && self.total > 0 {
+            episodes = episodes.push(
+                centered(
+                    w::text("Fully watched, all episodes are hidden").width(Length::Fill),
+                    Some(style::weak),
+                )
+                .padding(GAP),
+            );
+        }
+
         for (index, episode) in self.episodes.iter().enumerate() {
             episodes = episodes.push(
                 centered(
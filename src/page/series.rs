@@ -16,17 +16,30 @@ pub(crate) fn page(id: SeriesId) -> Page {
 #[derive(Debug, Clone)]
 pub(crate) enum Message {
     OpenRemote(RemoteId),
+    CopyLink(String),
     SeriesActions(comps::series_actions::Message),
     Navigate(Page),
     SeasonInfo(usize, comps::season_info::Message),
     SeriesBanner(comps::series_banner::Message),
     SwitchSeries(SeriesId, RemoteId),
+    RefreshInterval(SeriesId, Option<u32>),
+    IgnoreSpecials(SeriesId, bool),
+    ToggleFavorite(SeriesId),
+    Unsnooze(SeriesId),
+    StartSeason(SeriesId),
+    WatchRemaining(comps::watch_remaining::Message),
+    Notes(comps::notes::Message),
 }
 
+/// Selectable refresh intervals in hours.
+const REFRESH_INTERVALS: &[u32] = &[1, 2, 4, 6, 12, 24, 48, 72, 168, 336, 720];
+
 pub(crate) struct Series {
     series: comps::SeriesActions,
     seasons: Vec<comps::SeasonInfo>,
     banner: comps::SeriesBanner,
+    watch_remaining: comps::WatchRemaining,
+    notes: comps::Notes,
 }
 
 impl Series {
@@ -36,10 +49,16 @@ impl Series {
             series: comps::SeriesActions::new(state.id),
             seasons: Vec::new(),
             banner: comps::SeriesBanner,
+            watch_remaining: comps::WatchRemaining::new(comps::watch_remaining::Props::series(
+                state.id,
+            )),
+            notes: comps::Notes::new(comps::notes::Kind::Series(state.id)),
         }
     }
 
     pub(crate) fn prepare(&mut self, cx: &mut Ctxt<'_>, state: &State) {
+        cx.service.clear_new_content(&state.id);
+
         self.seasons.init_from_iter(
             cx.service
                 .seasons(&state.id)
@@ -47,6 +66,8 @@ impl Series {
         );
 
         self.banner.prepare(cx, &state.id);
+        self.notes.changed(comps::notes::Kind::Series(state.id));
+        self.notes.prepare(cx);
 
         if let Some(series) = cx.service.series(&state.id) {
             cx.assets.mark_with_hint(
@@ -64,6 +85,9 @@ impl Series {
                 let url = remote_id.url();
                 let _ = webbrowser::open_browser(webbrowser::Browser::Default, &url);
             }
+            Message::CopyLink(url) => {
+                cx.state.copy_to_clipboard(url);
+            }
             Message::SeriesActions(message) => {
                 self.series.update(cx, message);
             }
@@ -78,6 +102,29 @@ impl Series {
             Message::SeriesBanner(message) => {
                 self.banner.update(cx, message);
             }
+            Message::RefreshInterval(series_id, hours) => {
+                cx.service.set_refresh_interval(&series_id, hours);
+            }
+            Message::IgnoreSpecials(series_id, value) => {
+                let now = Utc::now();
+                cx.service.set_ignore_specials(&now, &series_id, value);
+            }
+            Message::ToggleFavorite(series_id) => {
+                cx.service.toggle_favorite(&series_id);
+            }
+            Message::Unsnooze(series_id) => {
+                cx.service.snooze_pending(&series_id, None);
+            }
+            Message::StartSeason(series_id) => {
+                let now = Utc::now();
+                cx.service.start_next_season(&now, &series_id);
+            }
+            Message::WatchRemaining(message) => {
+                self.watch_remaining.update(cx, message);
+            }
+            Message::Notes(message) => {
+                self.notes.update(cx, message);
+            }
             Message::SwitchSeries(series_id, remote_id) => {
                 cx.service
                     .push_task_without_delay(TaskKind::DownloadSeries {
@@ -90,11 +137,11 @@ impl Series {
         }
     }
 
-    pub(crate) fn view(
-        &self,
+    pub(crate) fn view<'a>(
+        &'a self,
         cx: &CtxtRef<'_>,
         state: &State,
-    ) -> Result<Element<'static, Message>> {
+    ) -> Result<Element<'a, Message>> {
         let Some(series) = cx.service.series(&state.id) else {
             bail!("Missing series {}", state.id);
         };
@@ -139,6 +186,14 @@ impl Series {
                 remotes = remotes.push(row);
             }
 
+            if let Some(url) = cx.service.series_share_url(&series.id) {
+                remotes = remotes.push(
+                    w::button(w::text("Copy link").size(SMALL_SIZE))
+                        .style(theme::Button::Secondary)
+                        .on_press(Message::CopyLink(url)),
+                );
+            }
+
             top = top.push(remotes.spacing(GAP));
         }
 
@@ -150,15 +205,11 @@ impl Series {
             .zip(&self.seasons)
             .enumerate()
         {
-            let poster = match season
-                .graphics
-                .poster
-                .as_ref()
-                .or(series.poster())
-                .and_then(|i| cx.assets.image_with_hint(i, POSTER_HINT))
-            {
+            let image = season.graphics.poster.as_ref().or(series.poster());
+
+            let poster = match image.and_then(|i| cx.assets.image_with_hint(i, POSTER_HINT)) {
                 Some(poster) => poster,
-                None => cx.missing_poster(),
+                None => cx.poster_placeholder(image, &series.title),
             };
 
             let graphic = link(w::image(poster).height(IMAGE_HEIGHT)).on_press(Message::Navigate(
@@ -208,10 +259,96 @@ impl Series {
             .push(self.series.view(cx, series).map(Message::SeriesActions))
             .push(info);
 
+        let stats = cx.service.series_rewatch_stats(&series.id);
+
+        if let (Some(average), true) = (stats.average(), stats.distinct > 0) {
+            let max = match stats.max {
+                1 => String::from("once"),
+                n => format!("{n} times"),
+            };
+
+            header = header.push(
+                w::text(format_args!(
+                    "Watched {average:.1}x on average, most watched episode seen {max}"
+                ))
+                .size(SMALL_SIZE),
+            );
+        }
+
+        let unwatched = cx
+            .service
+            .episodes(&series.id)
+            .filter(|e| !cx.service.is_ignored_season(&series.id, &e.season))
+            .any(|e| {
+                e.has_aired(cx.state.today()) && cx.service.watched_by_episode(&e.id).len() == 0
+            });
+
+        if unwatched || self.watch_remaining.is_confirm() {
+            header = header.push(
+                self.watch_remaining
+                    .view(
+                        "Watch all remaining",
+                        theme::Button::Positive,
+                        theme::Button::Positive,
+                    )
+                    .map(Message::WatchRemaining),
+            );
+        }
+
+        header = header
+            .push(favorite(series))
+            .push(refresh_interval(series))
+            .push(ignore_specials(series));
+
+        if let Some(until) = cx.service.snoozed_until(&series.id, &Utc::now()) {
+            header = header.push(
+                w::Row::new()
+                    .push(
+                        w::text(format_args!(
+                            "Snoozed until {}",
+                            until.with_timezone(&chrono::Local).date_naive()
+                        ))
+                        .size(SMALL_SIZE),
+                    )
+                    .push(
+                        w::button(w::text("Wake up").size(SMALL_SIZE))
+                            .style(theme::Button::Secondary)
+                            .on_press(Message::Unsnooze(series.id)),
+                    )
+                    .spacing(SPACE)
+                    .align_items(Alignment::Center),
+            );
+        }
+
+        if let Some(next) = &series.next_season {
+            if let Some(episode) = cx.service.episode(&next.episode) {
+                header = header.push(
+                    w::button(w::text(format_args!("Start {}", episode.season)).size(SMALL_SIZE))
+                        .style(theme::Button::Positive)
+                        .on_press(Message::StartSeason(series.id)),
+                );
+            }
+        }
+
+        if let Some(removed) = series.removed {
+            let text = match series.deleted_at(cx.service.config()) {
+                Some(deleted_at) => format!(
+                    "Removed on {}, will be permanently deleted on {}",
+                    removed.date_naive(),
+                    deleted_at.date_naive()
+                ),
+                None => format!("Removed on {}", removed.date_naive()),
+            };
+
+            header = header.push(w::text(text).style(cx.warning_text()));
+        }
+
         if !series.overview.is_empty() {
             header = header.push(w::text(&series.overview).shaping(w::text::Shaping::Advanced));
         }
 
+        header = header.push(self.notes.view().map(Message::Notes));
+
         let header = centered(header.spacing(GAP), None).padding(GAP);
 
         Ok(w::Column::new()
@@ -222,3 +359,85 @@ impl Series {
             .into())
     }
 }
+
+/// Button to toggle whether a series is a favorite.
+fn favorite(series: &crate::model::Series) -> w::Button<'static, Message> {
+    let (text, style) = if series.favorite {
+        ("★ Favorite", theme::Button::Positive)
+    } else {
+        ("☆ Favorite", theme::Button::Secondary)
+    };
+
+    w::button(
+        w::text(text)
+            .shaping(w::text::Shaping::Advanced)
+            .size(SMALL_SIZE),
+    )
+    .style(style)
+    .on_press(Message::ToggleFavorite(series.id))
+}
+
+/// Toggle for ignoring the specials of a series.
+fn ignore_specials(series: &crate::model::Series) -> w::Checkbox<'static, Message> {
+    let series_id = series.id;
+
+    w::checkbox("Ignore specials", series.ignore_specials)
+        .on_toggle(move |value| Message::IgnoreSpecials(series_id, value))
+        .size(SMALL_SIZE)
+        .text_size(SMALL_SIZE)
+}
+
+/// Controls for the series refresh interval.
+fn refresh_interval(series: &crate::model::Series) -> w::Row<'static, Message> {
+    let mut row = w::Row::new().align_items(Alignment::Center).spacing(SPACE);
+
+    let current = series.refresh_interval_hours;
+
+    let text = match current {
+        Some(1) => String::from("Check for updates every hour"),
+        Some(hours) => format!("Check for updates every {hours} hours"),
+        None => String::from("Check for updates at the default interval"),
+    };
+
+    row = row.push(w::text(text).size(SMALL_SIZE));
+
+    let index = current.map(|hours| REFRESH_INTERVALS.partition_point(|&h| h < hours));
+
+    let shorter = match index {
+        Some(index) => index.checked_sub(1).map(|i| REFRESH_INTERVALS[i]),
+        None => REFRESH_INTERVALS.iter().rev().find(|&&h| h < 12).copied(),
+    };
+
+    let longer = match index {
+        Some(index) => REFRESH_INTERVALS
+            .get(index)
+            .filter(|&&h| Some(h) != current)
+            .or(REFRESH_INTERVALS.get(index + 1))
+            .copied(),
+        None => REFRESH_INTERVALS.iter().find(|&&h| h > 12).copied(),
+    };
+
+    let mut less = w::button(w::text("-").size(SMALL_SIZE)).style(theme::Button::Secondary);
+
+    if let Some(hours) = shorter {
+        less = less.on_press(Message::RefreshInterval(series.id, Some(hours)));
+    }
+
+    let mut more = w::button(w::text("+").size(SMALL_SIZE)).style(theme::Button::Secondary);
+
+    if let Some(hours) = longer {
+        more = more.on_press(Message::RefreshInterval(series.id, Some(hours)));
+    }
+
+    row = row.push(less).push(more);
+
+    if current.is_some() {
+        row = row.push(
+            w::button(w::text("reset").size(SMALL_SIZE))
+                .style(theme::Button::Secondary)
+                .on_press(Message::RefreshInterval(series.id, None)),
+        );
+    }
+
+    row
+}
@@ -1,23 +1,58 @@
+use std::collections::HashSet;
+
 use crate::prelude::*;
 
 /// Messages generated and handled by [SeriesList].
 #[derive(Debug, Clone)]
 pub(crate) enum Message {
     ChangeFilter(String),
+    ChangeGenre(Option<String>),
+    CompletedLast(bool),
     SeriesActions(usize, comps::series_actions::Message),
     Navigate(Page),
+    RestoreSeries(SeriesId),
+    /// Select or deselect a series for bulk removal.
+    Select(SeriesId, bool),
+    ClearSelection,
+    RemoveSelected,
 }
 
 #[derive(Default)]
 pub(crate) struct SeriesList {
     filter: String,
+    genre: Option<String>,
+    completed_last: bool,
+    /// Known genres to pick from.
+    genres: Vec<String>,
+    /// Series which have been completely watched.
+    complete: HashSet<SeriesId>,
     filtered: Option<Box<[SeriesId]>>,
     actions: Vec<comps::SeriesActions>,
+    selected: HashSet<SeriesId>,
 }
 
 impl SeriesList {
     /// Prepare the view.
     pub(crate) fn prepare(&mut self, cx: &mut Ctxt<'_>) {
+        let today = cx.state.today();
+
+        self.genres = cx.service.genres();
+
+        self.complete = cx
+            .service
+            .series_by_name()
+            .filter(|s| cx.service.is_series_complete(&s.id, today))
+            .map(|s| s.id)
+            .collect();
+
+        // NB: Completion changes as episodes are watched, so the order has to
+        // be kept up to date.
+        if self.completed_last {
+            self.refilter(cx);
+        }
+
+        self.selected.retain(|id| cx.service.series(id).is_some());
+
         if let Some(filtered) = &self.filtered {
             let series = filtered.iter().flat_map(|id| cx.service.series(id));
             self.actions.init_from_iter(series.clone().map(|s| s.id));
@@ -38,21 +73,15 @@ impl SeriesList {
         match message {
             Message::ChangeFilter(filter) => {
                 self.filter = filter;
-                let filter = crate::search::Tokens::new(&self.filter);
-
-                self.filtered = if !filter.is_empty() {
-                    let mut filtered = Vec::new();
-
-                    for s in cx.service.series_by_name() {
-                        if filter.matches(&s.title) {
-                            filtered.push(s.id);
-                        }
-                    }
-
-                    Some(filtered.into())
-                } else {
-                    None
-                };
+                self.refilter(cx);
+            }
+            Message::ChangeGenre(genre) => {
+                self.genre = genre;
+                self.refilter(cx);
+            }
+            Message::CompletedLast(value) => {
+                self.completed_last = value;
+                self.refilter(cx);
             }
             Message::SeriesActions(index, message) => {
                 if let Some(actions) = self.actions.get_mut(index) {
@@ -62,7 +91,55 @@ impl SeriesList {
             Message::Navigate(page) => {
                 cx.push_history(page);
             }
+            Message::RestoreSeries(series_id) => {
+                cx.service.restore_series(&series_id);
+            }
+            Message::Select(series_id, selected) => {
+                if selected {
+                    self.selected.insert(series_id);
+                } else {
+                    self.selected.remove(&series_id);
+                }
+            }
+            Message::ClearSelection => {
+                self.selected.clear();
+            }
+            Message::RemoveSelected => {
+                let selected = std::mem::take(&mut self.selected);
+                let now = Utc::now();
+                cx.service
+                    .remove_series_where(&now, |s| selected.contains(&s.id));
+            }
+        }
+    }
+
+    /// Update the list of series matching the current filters.
+    fn refilter(&mut self, cx: &mut Ctxt<'_>) {
+        let filter = crate::search::Tokens::new(&self.filter);
+
+        if filter.is_empty() && self.genre.is_none() && !self.completed_last {
+            self.filtered = None;
+            return;
+        }
+
+        let genre = self.genre.as_deref();
+
+        let mut filtered = cx
+            .service
+            .series_by_name()
+            .filter(|s| {
+                genre.map_or(true, |genre| {
+                    s.genres.iter().any(|g| g.eq_ignore_ascii_case(genre))
+                }) && filter.matches(&s.title)
+            })
+            .map(|s| s.id)
+            .collect::<Vec<_>>();
+
+        if self.completed_last {
+            filtered.sort_by_key(|id| self.complete.contains(id));
         }
+
+        self.filtered = Some(filtered.into());
     }
 
     pub(crate) fn view(&self, cx: &CtxtRef<'_>) -> Element<'static, Message> {
@@ -85,7 +162,7 @@ impl SeriesList {
                 .and_then(|i| cx.assets.image_with_hint(i, POSTER_HINT))
             {
                 Some(handle) => handle,
-                None => cx.missing_poster(),
+                None => cx.poster_placeholder(series.poster(), &series.title),
             };
 
             let graphic = link(w::image(poster).height(IMAGE_HEIGHT))
@@ -106,13 +183,29 @@ impl SeriesList {
 
             let mut content = w::Column::new().width(Length::Fill);
 
-            content = content.push(
-                w::Column::new()
-                    .push(title)
-                    .push(w::text(format!("{} episode(s)", episodes.len())))
-                    .push(actions)
-                    .spacing(SPACE),
-            );
+            let series_id = series.id;
+
+            let select = w::checkbox("", self.selected.contains(&series_id))
+                .on_toggle(move |value| Message::Select(series_id, value));
+
+            let title = w::Row::new()
+                .push(select)
+                .push(title)
+                .align_items(Alignment::Center);
+
+            let mut info = w::Column::new()
+                .push(title)
+                .push(w::text(format!("{} episode(s)", episodes.len())));
+
+            if self.complete.contains(&series.id) {
+                info = info.push(
+                    w::text("✓ Watched")
+                        .shaping(w::text::Shaping::Advanced)
+                        .size(SMALL_SIZE),
+                );
+            }
+
+            content = content.push(info.push(actions).spacing(SPACE));
 
             if !series.overview.is_empty() {
                 content =
@@ -136,13 +229,99 @@ impl SeriesList {
             .on_input(Message::ChangeFilter)
             .width(Length::Fill);
 
-        w::Column::new()
-            .push(centered(
-                w::Row::new().push(filter).padding(GAP).width(Length::Fill),
-                None,
-            ))
-            .push(rows.spacing(GAP2))
-            .width(Length::Fill)
-            .into()
+        let mut removed = w::Column::new();
+
+        for series in cx.service.removed_series() {
+            let title = link(w::text(&series.title).shaping(w::text::Shaping::Advanced))
+                .on_press(Message::Navigate(page::series::page(series.id)))
+                .width(Length::Fill);
+
+            let mut row = w::Row::new()
+                .push(title)
+                .align_items(Alignment::Center)
+                .spacing(SPACE);
+
+            if let Some(deleted_at) = series.deleted_at(cx.service.config()) {
+                row = row.push(
+                    w::text(format_args!("Deleted on {}", deleted_at.date_naive()))
+                        .size(SMALL_SIZE),
+                );
+            }
+
+            row = row.push(
+                w::button(w::text("Undo removal").size(SMALL_SIZE))
+                    .style(theme::Button::Positive)
+                    .on_press(Message::RestoreSeries(series.id)),
+            );
+
+            removed = removed.push(row);
+        }
+
+        let mut filters = w::Row::new()
+            .push(filter)
+            .push(
+                w::pick_list(self.genres.clone(), self.genre.clone(), |genre| {
+                    Message::ChangeGenre(Some(genre))
+                })
+                .placeholder("Any genre"),
+            )
+            .push(
+                w::checkbox("Completed last", self.completed_last)
+                    .on_toggle(Message::CompletedLast),
+            )
+            .align_items(Alignment::Center)
+            .spacing(SPACE);
+
+        if self.genre.is_some() {
+            filters = filters.push(
+                w::button(w::text("Any genre").size(SMALL_SIZE))
+                    .style(theme::Button::Secondary)
+                    .on_press(Message::ChangeGenre(None)),
+            );
+        }
+
+        let mut page =
+            w::Column::new().push(centered(filters.padding(GAP).width(Length::Fill), None));
+
+        if !self.selected.is_empty() {
+            let selection = w::Row::new()
+                .push(w::text(format_args!(
+                    "{} series selected",
+                    self.selected.len()
+                )))
+                .push(w::horizontal_space())
+                .push(
+                    w::button(w::text("Remove selected").size(SMALL_SIZE))
+                        .style(theme::Button::Destructive)
+                        .on_press(Message::RemoveSelected),
+                )
+                .push(
+                    w::button(w::text("Clear selection").size(SMALL_SIZE))
+                        .style(theme::Button::Secondary)
+                        .on_press(Message::ClearSelection),
+                )
+                .align_items(Alignment::Center)
+                .spacing(SPACE)
+                .width(Length::Fill);
+
+            page = page.push(centered(selection, Some(style::weak)).padding(GAP));
+        }
+
+        page = page.push(rows.spacing(GAP2));
+
+        if cx.service.removed_series().next().is_some() {
+            page = page.push(
+                centered(
+                    w::Column::new()
+                        .push(w::text("Recently removed").size(SUBTITLE_SIZE))
+                        .push(removed.spacing(SPACE))
+                        .spacing(GAP),
+                    None,
+                )
+                .padding(GAP),
+            );
+        }
+
+        page.width(Length::Fill).into()
     }
 }
@@ -1,32 +1,229 @@
+use std::path::{Path, PathBuf};
+
+use chrono::Weekday;
+
 use crate::prelude::*;
 
 #[derive(Debug, Clone)]
 pub(crate) enum Message {
     ThemeChanged(ThemeType),
+    CustomAccent(bool),
+    AccentChannel(usize, String),
     TvdbLegacyApiKeyChange(String),
     TmdbApiKeyChange(String),
+    OmdbApiKeyChange(String),
+    DashboardUpcomingOnly(bool),
+    DashboardUpcomingIncludeToday(bool),
+    MovieReleaseKind(MovieReleaseKind, bool),
+    MovieReleaseCountryChange(String),
+    MovieReleaseCountrySubmit,
+    EpisodeLanguageChange(String),
+    AirGraceHours(u32),
+    ScheduleWindow(ScheduleWindow),
+    WeekStart(Weekday),
+    ScheduleShowEmptyDays(bool),
+    CountSkippedAsWatched(bool),
+    SkipFiller(bool),
+    IncludeSpecials(bool),
+    RemoveFinishedPending(bool),
+    ConfirmNextSeason(bool),
+    SeasonHideWatched(bool),
+    BackupPathChange(String),
+    BackupPathSubmit,
+    BackupKeep(usize),
+    ImageConcurrency(usize),
+    BackupExcludeSecrets(bool),
     ClearSync,
+    OpenConfigDirectory,
+    OpenCacheDirectory,
+    PurgeOrphanedImages,
+    ImagesPurged(Result<usize, ErrorInfo>),
 }
 
+/// Days which can be selected as the first day of the week.
+const WEEKDAYS: &[Weekday] = &[
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+
 #[derive(Default)]
-pub(crate) struct Settings;
+pub(crate) struct Settings {
+    /// Number of images removed by the last purge.
+    purged: Option<usize>,
+    /// If images are currently being purged.
+    purging: bool,
+    /// Release country being edited, which is applied once submitted.
+    release_country: Option<String>,
+    /// Backup directory being edited, which is applied once submitted.
+    backup_path: Option<String>,
+}
 
 impl Settings {
     /// Handle theme change.
-    pub(crate) fn update(&mut self, cx: &mut Ctxt<'_>, message: Message) {
+    pub(crate) fn update(
+        &mut self,
+        cx: &mut Ctxt<'_>,
+        message: Message,
+        mut commands: impl Commands<Message>,
+    ) {
         match message {
             Message::ThemeChanged(theme) => {
                 cx.service.set_theme(theme);
             }
+            Message::CustomAccent(enabled) => {
+                let accent = enabled.then(|| {
+                    let [r, g, b, _] = cx.service.theme().palette().primary.into_rgba8();
+                    [r, g, b]
+                });
+
+                cx.service.set_accent(accent);
+            }
+            Message::AccentChannel(index, value) => {
+                let Some(mut accent) = cx.service.config().accent else {
+                    return;
+                };
+
+                let value = value.trim();
+
+                let value = if value.is_empty() {
+                    0
+                } else {
+                    match value.parse::<u8>() {
+                        Ok(value) => value,
+                        Err(..) => return,
+                    }
+                };
+
+                if let Some(channel) = accent.get_mut(index) {
+                    *channel = value;
+                    cx.service.set_accent(Some(accent));
+                }
+            }
             Message::TvdbLegacyApiKeyChange(string) => {
                 cx.service.set_tvdb_legacy_api_key(string);
             }
             Message::TmdbApiKeyChange(string) => {
                 cx.service.set_tmdb_api_key(string);
             }
+            Message::OmdbApiKeyChange(string) => {
+                cx.service.set_omdb_api_key(string);
+            }
+            Message::DashboardUpcomingOnly(value) => {
+                cx.service.config_mut().dashboard_upcoming_only = value;
+            }
+            Message::DashboardUpcomingIncludeToday(value) => {
+                cx.service.config_mut().dashboard_upcoming_include_today = value;
+            }
+            Message::MovieReleaseKind(kind, enabled) => {
+                let kinds = &mut cx.service.config_mut().movie_release_kinds;
+                kinds.retain(|k| *k != kind);
+
+                if enabled {
+                    kinds.push(kind);
+                    kinds.sort();
+                }
+            }
+            Message::MovieReleaseCountryChange(country) => {
+                self.release_country = Some(country);
+            }
+            Message::MovieReleaseCountrySubmit => {
+                if let Some(country) = self.release_country.as_deref().and_then(country_code) {
+                    self.release_country = None;
+                    cx.service.set_movie_release_country(country);
+                }
+            }
+            Message::EpisodeLanguageChange(language) => {
+                let language = language.trim();
+                cx.service.config_mut().episode_language =
+                    (!language.is_empty()).then(|| language.to_owned());
+            }
+            Message::AirGraceHours(hours) => {
+                cx.service.set_air_grace_hours(hours);
+                let today = cx.service.config().air_today(&Utc::now());
+                cx.state.set_today(today);
+            }
+            Message::ScheduleWindow(window) => {
+                cx.service.set_schedule_window(window);
+            }
+            Message::WeekStart(day) => {
+                cx.service.config_mut().week_start = day;
+            }
+            Message::ScheduleShowEmptyDays(value) => {
+                cx.service.config_mut().schedule_show_empty_days = value;
+            }
+            Message::CountSkippedAsWatched(value) => {
+                cx.service.config_mut().count_skipped_as_watched = value;
+            }
+            Message::ConfirmNextSeason(value) => {
+                cx.service.config_mut().confirm_next_season = value;
+            }
+            Message::SeasonHideWatched(value) => {
+                cx.service.config_mut().season_hide_watched = value;
+            }
+            Message::RemoveFinishedPending(value) => {
+                cx.service.set_remove_finished_pending(value);
+            }
+            Message::SkipFiller(value) => {
+                let now = Utc::now();
+                cx.service.set_skip_filler(&now, value);
+            }
+            Message::IncludeSpecials(value) => {
+                let now = Utc::now();
+                cx.service.set_include_specials(&now, value);
+            }
+            Message::BackupPathChange(path) => {
+                self.backup_path = Some(path);
+            }
+            Message::BackupPathSubmit => {
+                if let Some(path) = self.backup_path.take() {
+                    cx.service.config_mut().backup_path =
+                        (!path.trim().is_empty()).then(|| PathBuf::from(path));
+                }
+            }
+            Message::ImageConcurrency(value) => {
+                cx.service.config_mut().image_concurrency = value.max(1);
+            }
+            Message::BackupKeep(keep) => {
+                cx.service.config_mut().backup_keep = keep;
+            }
+            Message::BackupExcludeSecrets(value) => {
+                cx.service.config_mut().backup_exclude_secrets = value;
+            }
             Message::ClearSync => {
                 cx.service.clear_sync();
             }
+            Message::OpenConfigDirectory => {
+                open_directory(cx, cx.service.config_dir().to_owned());
+            }
+            Message::OpenCacheDirectory => {
+                open_directory(cx, cx.service.cache_dir().to_owned());
+            }
+            Message::PurgeOrphanedImages => {
+                if !self.purging {
+                    self.purging = true;
+                    let future = cx.service.purge_orphaned_images();
+                    commands.perform(future, |result| {
+                        Message::ImagesPurged(result.map_err(Into::into))
+                    });
+                }
+            }
+            Message::ImagesPurged(result) => {
+                self.purging = false;
+
+                match result {
+                    Ok(count) => {
+                        self.purged = Some(count);
+                    }
+                    Err(error) => {
+                        cx.state.handle_error(error);
+                    }
+                }
+            }
         }
     }
 
@@ -48,6 +245,26 @@ impl Settings {
             },
         ));
 
+        let mut accent = w::Row::new()
+            .push(
+                w::checkbox("Custom accent color", config.accent.is_some())
+                    .on_toggle(Message::CustomAccent),
+            )
+            .spacing(SPACE)
+            .align_items(Alignment::Center);
+
+        if let Some(color) = config.accent {
+            for (index, (label, value)) in ["R", "G", "B"].into_iter().zip(color).enumerate() {
+                accent = accent.push(w::text(label).size(SMALL_SIZE)).push(
+                    w::text_input("0", &value.to_string())
+                        .on_input(move |value| Message::AccentChannel(index, value))
+                        .width(Length::Fixed(60.0)),
+                );
+            }
+        }
+
+        page = page.push(accent);
+
         page = page.push(
             w::Column::new()
                 .push(w::text("TheTVDB Legacy API Key:"))
@@ -68,8 +285,398 @@ impl Settings {
                 .spacing(SPACE),
         );
 
+        page = page.push(
+            w::Column::new()
+                .push(w::text("OMDb API Key (for shows only known by IMDb):"))
+                .push(
+                    w::text_input("Key...", &config.omdb_api_key)
+                        .on_input(Message::OmdbApiKeyChange),
+                )
+                .spacing(SPACE),
+        );
+
+        let mut dashboard = w::Column::new().push(w::text("Dashboard:")).push(
+            w::checkbox("Only show upcoming", config.dashboard_upcoming_only)
+                .on_toggle(Message::DashboardUpcomingOnly),
+        );
+
+        if config.dashboard_upcoming_only {
+            dashboard = dashboard.push(
+                w::checkbox("Include today", config.dashboard_upcoming_include_today)
+                    .on_toggle(Message::DashboardUpcomingIncludeToday),
+            );
+        }
+
+        page = page.push(dashboard.spacing(SPACE));
+
+        let mut movies = w::Column::new().push(w::text("Movie releases to schedule:"));
+
+        for kind in MovieReleaseKind::ALL {
+            let enabled = config.movie_release_kinds.contains(&kind);
+
+            movies = movies.push(
+                w::checkbox(kind.to_string(), enabled)
+                    .on_toggle(move |value| Message::MovieReleaseKind(kind, value)),
+            );
+        }
+
+        let release_country = self
+            .release_country
+            .as_deref()
+            .unwrap_or(&config.movie_release_country);
+
+        let apply = w::button(w::text("Apply").size(SMALL_SIZE))
+            .style(theme::Button::Primary)
+            .on_press_maybe(
+                (self.release_country.is_some() && country_code(release_country).is_some())
+                    .then_some(Message::MovieReleaseCountrySubmit),
+            );
+
+        movies = movies.push(w::text("Preferred release country:")).push(
+            w::Row::new()
+                .push(
+                    w::text_input("Country code, like US...", release_country)
+                        .on_input(Message::MovieReleaseCountryChange)
+                        .on_submit(Message::MovieReleaseCountrySubmit),
+                )
+                .push(apply)
+                .spacing(SPACE)
+                .align_items(Alignment::Center),
+        );
+
+        page = page.push(movies.spacing(SPACE));
+
+        page = page.push(
+            w::Column::new()
+                .push(w::text("Episode title language:"))
+                .push(
+                    w::text_input(
+                        "Original language, or a language like en-US...",
+                        config.episode_language.as_deref().unwrap_or_default(),
+                    )
+                    .on_input(Message::EpisodeLanguageChange),
+                )
+                .push(
+                    w::text("Applies to TheMovieDB series the next time they are refreshed")
+                        .size(SMALL_SIZE),
+                )
+                .spacing(SPACE),
+        );
+
+        let hours = config.air_grace_hours;
+
+        let mut grace = w::Row::new()
+            .push(w::text(format_args!("Air grace period: {hours} hours")))
+            .spacing(SPACE)
+            .align_items(Alignment::Center);
+
+        let decrement = w::button(w::text("-").size(SMALL_SIZE)).style(theme::Button::Secondary);
+
+        grace = grace.push(if hours > 0 {
+            decrement.on_press(Message::AirGraceHours(hours - 1))
+        } else {
+            decrement
+        });
+
+        grace = grace.push(
+            w::button(w::text("+").size(SMALL_SIZE))
+                .style(theme::Button::Secondary)
+                .on_press(Message::AirGraceHours((hours + 1).min(48))),
+        );
+
+        page = page.push(
+            w::Column::new()
+                .push(grace)
+                .push(
+                    w::text(
+                        "Episodes are considered aired this many hours after their air date starts. \
+                         Increase this if you're in a time zone ahead of where shows air, \
+                         so that episodes don't show up as available before they've actually aired.",
+                    )
+                    .size(SMALL_SIZE),
+                )
+                .spacing(SPACE),
+        );
+
+        let window = config.schedule_window;
+        let count = window.count();
+
+        let (text, other) = match window {
+            ScheduleWindow::Days(..) => ("days", ScheduleWindow::Weeks(count.div_ceil(7))),
+            ScheduleWindow::Weeks(..) => ("weeks", ScheduleWindow::Days(window.days())),
+        };
+
+        let mut schedule = w::Row::new()
+            .push(w::text(format_args!("Schedule window: {count} {text}")))
+            .spacing(SPACE)
+            .align_items(Alignment::Center);
+
+        let decrement = w::button(w::text("-").size(SMALL_SIZE)).style(theme::Button::Secondary);
+
+        schedule = schedule.push(if count > 1 {
+            decrement.on_press(Message::ScheduleWindow(window.with_count(count - 1)))
+        } else {
+            decrement
+        });
+
+        schedule = schedule
+            .push(
+                w::button(w::text("+").size(SMALL_SIZE))
+                    .style(theme::Button::Secondary)
+                    .on_press(Message::ScheduleWindow(window.with_count(count + 1))),
+            )
+            .push(
+                w::checkbox("In weeks", matches!(window, ScheduleWindow::Weeks(..)))
+                    .on_toggle(move |_| Message::ScheduleWindow(other)),
+            );
+
+        page = page.push(schedule);
+
+        page = page.push(
+            w::Row::new()
+                .push(w::text("First day of the week:"))
+                .push(w::pick_list(
+                    WEEKDAYS,
+                    Some(config.week_start),
+                    Message::WeekStart,
+                ))
+                .spacing(SPACE)
+                .align_items(Alignment::Center),
+        );
+
+        page = page.push(
+            w::checkbox(
+                "Show days with nothing scheduled",
+                config.schedule_show_empty_days,
+            )
+            .on_toggle(Message::ScheduleShowEmptyDays),
+        );
+
+        page = page.push(
+            w::checkbox(
+                "Count skipped episodes as watched in completion",
+                config.count_skipped_as_watched,
+            )
+            .on_toggle(Message::CountSkippedAsWatched),
+        );
+
+        page = page.push(
+            w::checkbox(
+                "Skip recaps and other flagged episodes in watch next",
+                config.skip_filler,
+            )
+            .on_toggle(Message::SkipFiller),
+        );
+
+        page = page.push(
+            w::checkbox(
+                "Include specials in watch next and the schedule",
+                config.include_specials,
+            )
+            .on_toggle(Message::IncludeSpecials),
+        );
+
+        page = page.push(
+            w::checkbox(
+                "Remove series from watch next when nothing more is upcoming",
+                config.remove_finished_pending,
+            )
+            .on_toggle(Message::RemoveFinishedPending),
+        );
+
+        page = page.push(
+            w::checkbox(
+                "Ask before starting the next season of a series",
+                config.confirm_next_season,
+            )
+            .on_toggle(Message::ConfirmNextSeason),
+        );
+
+        page = page.push(
+            w::checkbox(
+                "Hide watched episodes on season pages",
+                config.season_hide_watched,
+            )
+            .on_toggle(Message::SeasonHideWatched),
+        );
+
+        let images = config.image_concurrency();
+
+        let decrement = w::button(w::text("-").size(SMALL_SIZE)).style(theme::Button::Secondary);
+
+        page = page.push(
+            w::Row::new()
+                .push(w::text(format_args!("Download {images} images at a time")))
+                .push(if images > 1 {
+                    decrement.on_press(Message::ImageConcurrency(images - 1))
+                } else {
+                    decrement
+                })
+                .push(
+                    w::button(w::text("+").size(SMALL_SIZE))
+                        .style(theme::Button::Secondary)
+                        .on_press(Message::ImageConcurrency(images + 1)),
+                )
+                .spacing(SPACE)
+                .align_items(Alignment::Center),
+        );
+
+        let mut backup = w::Column::new();
+
+        let backup_path = match &self.backup_path {
+            Some(path) => path.clone(),
+            None => config
+                .backup_path
+                .as_ref()
+                .map(|p| p.display().to_string())
+                .unwrap_or_default(),
+        };
+
+        let apply = w::button(w::text("Apply").size(SMALL_SIZE)).style(theme::Button::Primary);
+
+        backup = backup.push(w::text("Backup directory:")).push(
+            w::Row::new()
+                .push(
+                    w::text_input("Leave empty to disable backups...", &backup_path)
+                        .on_input(Message::BackupPathChange)
+                        .on_submit(Message::BackupPathSubmit),
+                )
+                .push(if self.backup_path.is_some() {
+                    apply.on_press(Message::BackupPathSubmit)
+                } else {
+                    apply
+                })
+                .spacing(SPACE)
+                .align_items(Alignment::Center),
+        );
+
+        let keep = config.backup_keep;
+
+        let decrement = w::button(w::text("-").size(SMALL_SIZE)).style(theme::Button::Secondary);
+
+        backup = backup.push(
+            w::Row::new()
+                .push(w::text(format_args!("Keep {keep} backups")))
+                .push(if keep > 1 {
+                    decrement.on_press(Message::BackupKeep(keep - 1))
+                } else {
+                    decrement
+                })
+                .push(
+                    w::button(w::text("+").size(SMALL_SIZE))
+                        .style(theme::Button::Secondary)
+                        .on_press(Message::BackupKeep(keep + 1)),
+                )
+                .spacing(SPACE)
+                .align_items(Alignment::Center),
+        );
+
+        backup = backup.push(
+            w::checkbox(
+                "Exclude API keys from backups",
+                config.backup_exclude_secrets,
+            )
+            .on_toggle(Message::BackupExcludeSecrets),
+        );
+
+        page = page.push(backup.spacing(SPACE));
+
+        page = page.push(
+            w::Column::new()
+                .push(directory(
+                    "Config directory:",
+                    cx.service.config_dir(),
+                    Message::OpenConfigDirectory,
+                ))
+                .push(directory(
+                    "Cache directory:",
+                    cx.service.cache_dir(),
+                    Message::OpenCacheDirectory,
+                ))
+                .push(
+                    w::Row::new()
+                        .push(
+                            w::button(w::text("Remove unused images").size(SMALL_SIZE))
+                                .style(theme::Button::Secondary)
+                                .on_press_maybe(
+                                    (!self.purging).then_some(Message::PurgeOrphanedImages),
+                                ),
+                        )
+                        .push_maybe(self.purged.map(|count| {
+                            w::text(format_args!("Removed {count} images")).size(SMALL_SIZE)
+                        }))
+                        .spacing(SPACE)
+                        .align_items(Alignment::Center),
+                )
+                .spacing(SPACE),
+        );
+
+        page = page.push(
+            SHORTCUTS.iter().fold(
+                w::Column::new()
+                    .push(w::text("Keyboard shortcuts:"))
+                    .spacing(SPACE),
+                |column, (keys, description)| {
+                    column.push(
+                        w::Row::new()
+                            .push(w::text(keys).size(SMALL_SIZE).width(Length::Fixed(120.0)))
+                            .push(w::text(description).size(SMALL_SIZE))
+                            .spacing(SPACE),
+                    )
+                },
+            ),
+        );
+
         page = page.push(w::horizontal_rule(1));
         page = page.push(w::button("Clear sync information").on_press(Message::ClearSync));
         default_container(page.spacing(GAP).padding(GAP)).into()
     }
 }
+
+/// Keyboard shortcuts, which are handled by the application.
+const SHORTCUTS: &[(&str, &str)] = &[
+    ("g d", "Go to the dashboard"),
+    ("g s", "Go to the series overview"),
+    ("g w", "Go to watch next"),
+    ("/", "Search"),
+    ("j / k", "Focus the next or previous card in watch next"),
+    ("w", "Mark the focused card in watch next as watched"),
+    ("Backspace", "Go back"),
+    ("Ctrl+Z", "Undo the last watch or skip"),
+];
+
+/// Open a directory in the file browser, or copy its path to the clipboard if
+/// that isn't possible.
+fn open_directory(cx: &mut Ctxt<'_>, path: PathBuf) {
+    if let Err(error) = crate::utils::open_directory(&path) {
+        tracing::warn!("Failed to open {}: {error}", path.display());
+        cx.state.copy_to_clipboard(path.display().to_string());
+    }
+}
+
+/// Render a directory with a button to open it.
+fn directory(label: &str, path: &Path, message: Message) -> Element<'static, Message> {
+    w::Row::new()
+        .push(w::text(label))
+        .push(w::text(path.display()).size(SMALL_SIZE))
+        .push(w::horizontal_space())
+        .push(
+            w::button(w::text("Open").size(SMALL_SIZE))
+                .style(theme::Button::Secondary)
+                .on_press(message),
+        )
+        .spacing(SPACE)
+        .align_items(Alignment::Center)
+        .into()
+}
+
+/// Parse a two letter ISO 3166-1 country code.
+fn country_code(country: &str) -> Option<String> {
+    let country = country.trim();
+
+    if country.len() != 2 || !country.bytes().all(|b| b.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    Some(country.to_ascii_uppercase())
+}
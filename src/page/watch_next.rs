@@ -1,11 +1,22 @@
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
 use serde::{Deserialize, Serialize};
 
 use crate::prelude::*;
+use crate::service::{PendingRef, RemainingSeason};
+
+/// Presses of "watched, show next" closer together than this are ignored.
+const ADVANCE_DEBOUNCE: Duration = Duration::from_millis(500);
+/// Number of days a series is snoozed for.
+const SNOOZE_DAYS: i64 = 7;
 
 #[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub(crate) struct State {
     future: bool,
+    #[serde(default)]
+    backlog: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -13,20 +24,51 @@ pub(crate) enum Message {
     Future(usize, comps::episode_or_movie::Message),
     Episode(usize, comps::episode_or_movie::Message),
     ToggleFuture(bool),
+    ToggleBacklog(bool),
+    OrderByRelease(bool),
+    GroupBySeries(bool),
+    /// Expand or collapse the group of the given series.
+    ToggleGroup(SeriesId),
+    /// Start the next season of a series.
+    StartSeason(SeriesId),
+    /// Decline starting the next season of a series.
+    DeclineSeason(SeriesId),
+    /// Mark the pending episode of a series as watched and show the next one in
+    /// its place.
+    Advance(usize, SeriesId, EpisodeId),
+    /// Hide what's pending for a series for a while.
+    Snooze(SeriesId),
 }
 
 #[derive(Default)]
 pub(crate) struct WatchNext {
     future: Vec<comps::EpisodeOrMovie>,
     episodes: Vec<comps::EpisodeOrMovie>,
+    /// The series and episode of each available card which can be advanced.
+    advance: Vec<Option<(SeriesId, EpisodeId)>>,
+    /// Series which have been advanced, and the position they are kept at.
+    pinned: Vec<(usize, SeriesId)>,
+    /// Advanced series which no longer have an available episode.
+    caught_up: Vec<(usize, SeriesId)>,
+    /// When the last advance happened.
+    last_advance: Option<Instant>,
+    /// Series groups which have been expanded.
+    expanded: HashSet<SeriesId>,
 }
 
 impl WatchNext {
     pub(crate) fn prepare(&mut self, cx: &mut Ctxt<'_>, state: &State) {
+        let now = Utc::now();
         let today = cx.state.today();
 
+        let order = cx.service.config().watch_next_order;
+
         if state.future {
-            let future = cx.service.pending().rev().filter(|p| p.will_air(today));
+            let future = cx
+                .service
+                .pending_ordered(&now, order)
+                .into_iter()
+                .filter(|p| p.will_air(today));
 
             self.future.init_from_iter(future.map(|p| match p {
                 crate::service::PendingRef::Episode { episode, .. } => {
@@ -47,29 +89,84 @@ impl WatchNext {
             self.future.clear();
         }
 
-        let episodes = cx.service.pending().rev().filter(|p| p.has_aired(today));
+        let mut episodes = if state.backlog {
+            cx.service.backlog(&now, today)
+        } else {
+            cx.service
+                .pending_ordered(&now, order)
+                .into_iter()
+                .filter(|p| p.has_aired(today))
+                .collect::<Vec<_>>()
+        };
 
-        self.episodes.init_from_iter(episodes.map(|p| match p {
-            crate::service::PendingRef::Episode { episode, .. } => {
-                comps::episode_or_movie::Props::Episode(comps::episode::Props {
-                    include_series: true,
-                    episode_id: episode.id,
-                    watched: cx.service.watched_by_episode(&episode.id),
-                })
-            }
-            crate::service::PendingRef::Movie { movie } => {
-                comps::episode_or_movie::Props::Movie(comps::movie_item::Props {
-                    movie_id: movie.id,
-                    watched: cx.service.watched_by_movie(&movie.id),
-                })
+        // Keep advanced series where they were, so that the next episode
+        // shows up in place.
+        self.caught_up.clear();
+
+        for &(index, series_id) in &self.pinned {
+            let position = episodes.iter().position(
+                |p| matches!(p, PendingRef::Episode { series, .. } if series.id == series_id),
+            );
+
+            if let Some(position) = position {
+                let p = episodes.remove(position);
+                episodes.insert(index.min(episodes.len()), p);
+            } else {
+                self.caught_up.push((index, series_id));
             }
-        }));
+        }
+
+        self.advance = episodes
+            .iter()
+            .map(|p| match p {
+                PendingRef::Episode {
+                    series, episode, ..
+                } => Some((series.id, episode.id)),
+                PendingRef::Movie { .. } => None,
+            })
+            .collect();
+
+        self.episodes
+            .init_from_iter(episodes.into_iter().map(|p| match p {
+                crate::service::PendingRef::Episode { episode, .. } => {
+                    comps::episode_or_movie::Props::Episode(comps::episode::Props {
+                        include_series: true,
+                        episode_id: episode.id,
+                        watched: cx.service.watched_by_episode(&episode.id),
+                    })
+                }
+                crate::service::PendingRef::Movie { movie } => {
+                    comps::episode_or_movie::Props::Movie(comps::movie_item::Props {
+                        movie_id: movie.id,
+                        watched: cx.service.watched_by_movie(&movie.id),
+                    })
+                }
+            }));
+
+        cx.state.clamp_focus(self.episodes.len());
 
         for e in self.future.iter_mut().chain(&mut self.episodes) {
             e.prepare(cx);
         }
     }
 
+    /// Mark the available episode or movie which has keyboard focus as
+    /// watched.
+    pub(crate) fn watch_focused(&mut self, cx: &mut Ctxt<'_>, state: &mut State) {
+        let index = cx.state.focus();
+
+        if let Some(&(series_id, episode_id)) = self.advance.get(index).and_then(Option::as_ref) {
+            self.update(cx, state, Message::Advance(index, series_id, episode_id));
+            return;
+        }
+
+        if let Some(comps::EpisodeOrMovie::Movie(movie)) = self.episodes.get(index) {
+            let now = Utc::now();
+            cx.service
+                .watch_movie(&now, movie.movie_id(), RemainingSeason::Aired);
+        }
+    }
+
     pub(crate) fn update(&mut self, cx: &mut Ctxt<'_>, state: &mut State, message: Message) {
         match message {
             Message::Future(index, m) => {
@@ -85,6 +182,61 @@ impl WatchNext {
             Message::ToggleFuture(value) => {
                 state.future = value;
             }
+            Message::ToggleBacklog(value) => {
+                state.backlog = value;
+                self.pinned.clear();
+            }
+            Message::OrderByRelease(value) => {
+                cx.service.config_mut().watch_next_order = if value {
+                    PendingOrder::Released
+                } else {
+                    PendingOrder::Pending
+                };
+            }
+            Message::GroupBySeries(value) => {
+                cx.service.config_mut().group_watch_next = value;
+            }
+            Message::ToggleGroup(series_id) => {
+                if !self.expanded.remove(&series_id) {
+                    self.expanded.insert(series_id);
+                }
+            }
+            Message::StartSeason(series_id) => {
+                let now = Utc::now();
+                cx.service.start_next_season(&now, &series_id);
+            }
+            Message::DeclineSeason(series_id) => {
+                cx.service.decline_next_season(&series_id);
+            }
+            Message::Snooze(series_id) => {
+                let until = Utc::now() + chrono::Duration::days(SNOOZE_DAYS);
+                cx.service.snooze_pending(&series_id, Some(until));
+            }
+            Message::Advance(index, series_id, episode_id) => {
+                if matches!(self.last_advance, Some(last) if last.elapsed() < ADVANCE_DEBOUNCE) {
+                    return;
+                }
+
+                // NB: Ignore presses for an episode which is no longer
+                // pending, since the card has already advanced.
+                let is_pending = matches!(
+                    cx.service.pending_ref_by_series(&series_id),
+                    Some(PendingRef::Episode { episode, .. }) if episode.id == episode_id
+                );
+
+                if !is_pending {
+                    return;
+                }
+
+                self.last_advance = Some(Instant::now());
+
+                let now = Utc::now();
+                cx.service.watch(&now, &episode_id, RemainingSeason::Aired);
+
+                self.pinned.retain(|(_, s)| *s != series_id);
+                self.pinned.push((index, series_id));
+                self.pinned.sort_by_key(|(index, _)| *index);
+            }
         }
     }
 
@@ -110,12 +262,55 @@ impl WatchNext {
                     w::checkbox("Show future episodes", state.future)
                         .on_toggle(Message::ToggleFuture),
                 )
+                .push(
+                    w::checkbox("Backlog, oldest first", state.backlog)
+                        .on_toggle(Message::ToggleBacklog),
+                )
+                .push(
+                    w::checkbox(
+                        "Order by release date",
+                        cx.service.config().watch_next_order == PendingOrder::Released,
+                    )
+                    .on_toggle(Message::OrderByRelease),
+                )
+                .push(
+                    w::checkbox("Group by series", cx.service.config().group_watch_next)
+                        .on_toggle(Message::GroupBySeries),
+                )
+                .spacing(GAP)
                 .width(Length::Fill),
             None,
         ));
 
         list = list.push(options.width(Length::Fill));
 
+        for (series, episode) in cx.service.next_season_prompts() {
+            let prompt = w::Row::new()
+                .push(
+                    w::text(format_args!(
+                        "Start {} of {}?",
+                        episode.season, series.title
+                    ))
+                    .shaping(w::text::Shaping::Advanced),
+                )
+                .push(w::horizontal_space())
+                .push(
+                    w::button(w::text("Start").size(SMALL_SIZE))
+                        .style(theme::Button::Positive)
+                        .on_press(Message::StartSeason(series.id)),
+                )
+                .push(
+                    w::button(w::text("Not now").size(SMALL_SIZE))
+                        .style(theme::Button::Secondary)
+                        .on_press(Message::DeclineSeason(series.id)),
+                )
+                .spacing(SPACE)
+                .align_items(Alignment::Center)
+                .width(Length::Fill);
+
+            list = list.push(centered(prompt, Some(style::weak)).padding(GAP));
+        }
+
         if !self.future.is_empty() {
             list = list.push(centered(
                 w::text("Future episodes:")
@@ -146,18 +341,67 @@ impl WatchNext {
             ));
 
             for (index, episode) in self.episodes.iter().enumerate() {
-                list = list.push(
-                    centered(
-                        episode
-                            .view(cx, true)?
-                            .map(move |m| Message::Episode(index, m)),
-                        Some(style::weak),
-                    )
-                    .padding(GAP),
+                for &(_, series_id) in self.caught_up.iter().filter(|(i, _)| *i == index) {
+                    list = list.push(caught_up(cx, &series_id));
+                }
+
+                let advance = self.advance.get(index).and_then(Option::as_ref);
+
+                let mut card = w::Column::new();
+
+                if let Some((series_id, episode_id)) =
+                    advance.filter(|_| cx.service.config().group_watch_next)
+                {
+                    let expanded = self.expanded.contains(series_id);
+                    card = card.push(group_header(cx, series_id, episode_id, expanded));
+
+                    if !expanded {
+                        list = list.push(centered(card, Some(style::weak)).padding(GAP));
+                        continue;
+                    }
+                }
+
+                card = card.push(
+                    episode
+                        .view(cx, true)?
+                        .map(move |m| Message::Episode(index, m)),
                 );
+
+                if let Some((series_id, episode_id)) = advance {
+                    card = card.push(
+                        w::Row::new()
+                            .push(
+                                w::button(w::text("Watched, show next").size(SMALL_SIZE))
+                                    .style(theme::Button::Positive)
+                                    .on_press(Message::Advance(index, *series_id, *episode_id)),
+                            )
+                            .push(
+                                w::button(w::text("Snooze for a week").size(SMALL_SIZE))
+                                    .style(theme::Button::Secondary)
+                                    .on_press(Message::Snooze(*series_id)),
+                            )
+                            .spacing(SPACE),
+                    );
+                }
+
+                let card_style = if index == cx.state.focus() {
+                    style::focused
+                } else {
+                    style::weak
+                };
+
+                list = list.push(centered(card.spacing(SPACE), Some(card_style)).padding(GAP));
             }
         }
 
+        for &(_, series_id) in self
+            .caught_up
+            .iter()
+            .filter(|(i, _)| *i >= self.episodes.len())
+        {
+            list = list.push(caught_up(cx, &series_id));
+        }
+
         Ok(w::Column::new()
             .push(list.spacing(GAP2))
             .width(Length::Fill)
@@ -165,3 +409,76 @@ impl WatchNext {
             .into())
     }
 }
+
+/// Render the collapsible header for a series group, showing its next episode
+/// and how many episodes are available in its season.
+fn group_header(
+    cx: &CtxtRef<'_>,
+    series_id: &SeriesId,
+    episode_id: &EpisodeId,
+    expanded: bool,
+) -> Element<'static, Message> {
+    let mut row = w::Row::new().spacing(SPACE).align_items(Alignment::Center);
+
+    if let Some(series) = cx.service.series(series_id) {
+        row = row.push(w::text(&series.title).shaping(w::text::Shaping::Advanced));
+    }
+
+    if let Some(episode) = cx.service.episode(episode_id) {
+        let mut next = format!("S{}E{}", episode.season.short(), episode.number);
+
+        if let Some(name) = &episode.name {
+            next.push(' ');
+            next.push_str(name);
+        }
+
+        let available =
+            cx.service
+                .available_in_season(series_id, &episode.season, cx.state.today());
+
+        row = row.push(
+            w::text(next)
+                .size(SMALL_SIZE)
+                .shaping(w::text::Shaping::Advanced),
+        );
+
+        row = row.push(
+            w::text(format_args!("{available} available in {}", episode.season)).size(SMALL_SIZE),
+        );
+    }
+
+    row = row.push(w::horizontal_space());
+
+    let label = if expanded { "Collapse" } else { "Expand" };
+
+    row = row.push(
+        w::button(w::text(label).size(SMALL_SIZE))
+            .style(theme::Button::Secondary)
+            .on_press(Message::ToggleGroup(*series_id)),
+    );
+
+    row.width(Length::Fill).into()
+}
+
+/// Render a placeholder for a series which has been caught up with.
+fn caught_up(cx: &CtxtRef<'_>, series_id: &SeriesId) -> Element<'static, Message> {
+    let mut column = w::Column::new();
+
+    if let Some(series) = cx.service.series(series_id) {
+        column = column.push(
+            w::text(format_args!("Caught up with {}", series.title))
+                .shaping(w::text::Shaping::Advanced),
+        );
+    }
+
+    if let Some(PendingRef::Episode { episode, .. }) = cx.service.pending_ref_by_series(series_id) {
+        if let Some(aired) = &episode.aired {
+            column =
+                column.push(w::text(format_args!("Next episode airs {aired}")).size(SMALL_SIZE));
+        }
+    }
+
+    centered(column.spacing(SPACE).width(Length::Fill), Some(style::weak))
+        .padding(GAP)
+        .into()
+}
@@ -80,6 +80,17 @@ where
     container
 }
 
+/// Display how long something has been going on for, or took.
+pub(crate) fn elapsed_display(d: Duration) -> String {
+    let seconds = d.num_seconds().max(0);
+
+    match seconds {
+        n if n >= 3600 => format!("{}h {}m", n / 3600, (n % 3600) / 60),
+        n if n >= 60 => format!("{}m {}s", n / 60, n % 60),
+        n => format!("{n}s"),
+    }
+}
+
 /// Convert a chrono duration into something that is pretty to display.
 pub(crate) fn duration_display(d: Duration) -> Text<'static> {
     let seconds = d.num_seconds();
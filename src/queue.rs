@@ -1,7 +1,9 @@
 use std::collections::{HashMap, VecDeque};
 
+use anyhow::{bail, Result};
 use arrayvec::ArrayVec;
 use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
 
 use crate::prelude::{MovieId, RemoteId, SeriesId, TaskId};
 
@@ -32,7 +34,13 @@ pub(crate) enum TaskRef {
     RemoteMovie { remote_id: RemoteId },
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(
+    tag = "type",
+    rename_all = "kebab-case",
+    rename_all_fields = "kebab-case",
+    deny_unknown_fields
+)]
 pub(crate) enum TaskKind {
     /// Check for updates.
     CheckForUpdates {
@@ -54,6 +62,11 @@ pub(crate) enum TaskKind {
         last_modified: Option<DateTime<Utc>>,
         force: bool,
     },
+    /// Re-resolve the remote identifiers of every episode in a series.
+    ResolveEpisodeRemotes {
+        series_id: SeriesId,
+        remote_id: RemoteId,
+    },
     /// Task to add a series by a remote identifier.
     DownloadSeriesByRemoteId { remote_id: RemoteId },
     /// Task to add download a movie by a remote identifier.
@@ -89,6 +102,13 @@ impl TaskKind {
                 ids.push(TaskRef::Movie { movie_id });
                 ids.push(TaskRef::RemoteSeries { remote_id });
             }
+            TaskKind::ResolveEpisodeRemotes {
+                series_id,
+                remote_id,
+            } => {
+                ids.push(TaskRef::Series { series_id });
+                ids.push(TaskRef::RemoteSeries { remote_id });
+            }
             TaskKind::DownloadSeriesByRemoteId { remote_id, .. } => {
                 ids.push(TaskRef::RemoteSeries { remote_id });
             }
@@ -102,7 +122,8 @@ impl TaskKind {
 }
 
 /// A task in a queue.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
 #[must_use]
 pub(crate) struct Task {
     /// The identifier of the task.
@@ -110,7 +131,11 @@ pub(crate) struct Task {
     /// The kind of the task.
     pub(crate) kind: TaskKind,
     /// When the task is scheduled for.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub(crate) scheduled: Option<DateTime<Utc>>,
+    /// Number of times the task has failed and been retried.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub(crate) attempts: u8,
 }
 
 impl Task {
@@ -119,6 +144,7 @@ impl Task {
         match &self.kind {
             TaskKind::DownloadSeries { series_id, .. } => *series_id == *id,
             TaskKind::CheckForUpdates { series_id, .. } => *series_id == *id,
+            TaskKind::ResolveEpisodeRemotes { series_id, .. } => *series_id == *id,
             _ => false,
         }
     }
@@ -132,11 +158,43 @@ impl Task {
     }
 }
 
+/// A task which is currently running.
+#[derive(Debug, Clone)]
+pub(crate) struct RunningTask {
+    /// When the task was started.
+    pub(crate) started: DateTime<Utc>,
+    pub(crate) task: Task,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
 pub(crate) struct CompletedTask {
     pub(crate) at: DateTime<Utc>,
+    /// When the task was started, if it was ever run.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) started: Option<DateTime<Utc>>,
     pub(crate) task: Task,
 }
 
+impl CompletedTask {
+    /// How long the task took to run.
+    pub(crate) fn duration(&self) -> Option<Duration> {
+        Some(self.at.signed_duration_since(self.started?))
+    }
+}
+
+/// A serialized snapshot of the queue, used for debugging.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub(crate) struct QueueDump {
+    #[serde(default)]
+    pub(crate) pending: Vec<Task>,
+    #[serde(default)]
+    pub(crate) running: Vec<Task>,
+    #[serde(default)]
+    pub(crate) completed: Vec<CompletedTask>,
+}
+
 /// Queue of scheduled actions.
 #[derive(Default)]
 pub(crate) struct Queue {
@@ -147,9 +205,12 @@ pub(crate) struct Queue {
     /// Items in the download queue.
     pending: VecDeque<Task>,
     /// Collection of running tasks.
-    running: Vec<Task>,
+    running: Vec<RunningTask>,
     /// Completed tasks.
     completed: VecDeque<CompletedTask>,
+    /// No tasks are started before this time, since a remote asked us to
+    /// back off.
+    backoff: Option<DateTime<Utc>>,
     /// Test if queue has been locally modified.
     modified: bool,
 }
@@ -166,20 +227,76 @@ impl Queue {
     /// present in the queue.
     #[inline]
     pub(crate) fn complete(&mut self, now: &DateTime<Utc>, task: Task) -> Option<TaskStatus> {
-        self.running.retain(|t| t.id != task.id);
+        let mut started = None;
+
+        self.running.retain(|t| {
+            if t.task.id == task.id {
+                started = Some(t.started);
+                false
+            } else {
+                true
+            }
+        });
+
         let status = self.status.remove(&task.id)?;
 
         for id in task.kind.task_refs() {
             let _ = self.task_ids.remove(&id);
         }
 
-        self.completed.push_front(CompletedTask { at: *now, task });
+        self.completed.push_front(CompletedTask {
+            at: *now,
+            started,
+            task,
+        });
+
+        // NB: Completing a task frees up room for the next one to run.
+        self.modified = true;
         Some(status)
     }
 
+    /// Put a running task back in front of the queue, and hold off starting
+    /// any tasks until the given time.
+    pub(crate) fn retry_after(&mut self, task: Task, until: DateTime<Utc>) {
+        self.running.retain(|t| t.task.id != task.id);
+
+        if self.status.insert(task.id, TaskStatus::Pending).is_none() {
+            for task_ref in task.kind.task_refs() {
+                self.task_ids.insert(task_ref, task.id);
+            }
+        }
+
+        self.pending.push_front(task);
+        self.backoff = Some(self.backoff.map_or(until, |b| b.max(until)));
+        self.modified = true;
+    }
+
+    /// Put a running task which failed at the back of the queue, to be retried
+    /// no earlier than the given time.
+    pub(crate) fn retry_failed(&mut self, mut task: Task, at: DateTime<Utc>) {
+        self.running.retain(|t| t.task.id != task.id);
+
+        if self.status.insert(task.id, TaskStatus::Pending).is_none() {
+            for task_ref in task.kind.task_refs() {
+                self.task_ids.insert(task_ref, task.id);
+            }
+        }
+
+        let at = self
+            .pending
+            .back()
+            .and_then(|t| t.scheduled)
+            .map_or(at, |s| s.max(at));
+
+        task.attempts = task.attempts.saturating_add(1);
+        task.scheduled = Some(at);
+        self.pending.push_back(task);
+        self.modified = true;
+    }
+
     /// Running tasks.
     #[inline]
-    pub(crate) fn running(&self) -> impl ExactSizeIterator<Item = &Task> {
+    pub(crate) fn running(&self) -> impl ExactSizeIterator<Item = &RunningTask> {
         self.running.iter()
     }
 
@@ -219,6 +336,98 @@ impl Queue {
         removed
     }
 
+    /// Move a pending task up or down by `delta` positions.
+    ///
+    /// Scheduled times stay with the positions in the queue, so that the task
+    /// takes over the time slot of the position it's moved to. Returns `true`
+    /// if the task was moved.
+    pub(crate) fn move_task(&mut self, id: TaskId, delta: isize) -> bool {
+        let Some(from) = self.pending.iter().position(|t| t.id == id) else {
+            return false;
+        };
+
+        let to = from
+            .saturating_add_signed(delta)
+            .min(self.pending.len().saturating_sub(1));
+
+        if from == to {
+            return false;
+        }
+
+        let scheduled = self.pending.iter().map(|t| t.scheduled).collect::<Vec<_>>();
+
+        if let Some(task) = self.pending.remove(from) {
+            self.pending.insert(to, task);
+        }
+
+        for (task, scheduled) in self.pending.iter_mut().zip(scheduled) {
+            task.scheduled = scheduled;
+        }
+
+        self.modified = true;
+        true
+    }
+
+    /// Test if the queue has no pending or running tasks.
+    #[inline]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.pending.is_empty() && self.running.is_empty()
+    }
+
+    /// Dump the current state of the queue.
+    pub(crate) fn dump(&self) -> QueueDump {
+        QueueDump {
+            pending: self.pending.iter().cloned().collect(),
+            running: self.running.iter().map(|t| t.task.clone()).collect(),
+            completed: self.completed.iter().cloned().collect(),
+        }
+    }
+
+    /// Export tasks which should be persisted, running tasks first since they
+    /// have to be started again.
+    pub(crate) fn export(&self) -> Vec<Task> {
+        self.running
+            .iter()
+            .map(|t| t.task.clone())
+            .chain(self.pending.iter().cloned())
+            .collect()
+    }
+
+    /// Restore the queue from a dump, replacing its current state.
+    ///
+    /// Tasks which were running when the dump was taken are put back in front
+    /// of the pending tasks, since nothing is running them any longer.
+    pub(crate) fn restore(&mut self, dump: QueueDump) -> Result<()> {
+        let mut status = HashMap::new();
+        let mut task_ids = HashMap::new();
+        let mut pending = VecDeque::new();
+
+        for task in dump.running.into_iter().chain(dump.pending) {
+            if status.insert(task.id, TaskStatus::Pending).is_some() {
+                bail!("Duplicate task id {}", task.id);
+            }
+
+            for task_ref in task.kind.task_refs() {
+                if let Some(existing) = task_ids.insert(task_ref, task.id) {
+                    bail!(
+                        "Task {} conflicts with task {existing} over {task_ref:?}",
+                        task.id
+                    );
+                }
+            }
+
+            pending.push_back(task);
+        }
+
+        self.status = status;
+        self.task_ids = task_ids;
+        self.pending = pending;
+        self.running.clear();
+        self.completed = dump.completed.into();
+        self.modified = true;
+        Ok(())
+    }
+
     /// Take if the queue has been modified.
     #[inline]
     pub(crate) fn take_modified(&mut self) -> bool {
@@ -233,15 +442,24 @@ impl Queue {
     ) -> Option<Task> {
         let task = self.pending.front()?;
 
-        if !matches!(timed_out, Some(id) if id == task.id)
-            && task.scheduled.map(|s| s > *now).unwrap_or_default()
-        {
-            return None;
+        if !matches!(timed_out, Some(id) if id == task.id) {
+            if task.scheduled.map(|s| s > *now).unwrap_or_default() {
+                return None;
+            }
+
+            if self.backoff.is_some_and(|b| b > *now) {
+                return None;
+            }
         }
 
+        self.backoff = None;
+
         let task = self.pending.pop_front()?;
         self.status.insert(task.id, TaskStatus::Running);
-        self.running.push(task.clone());
+        self.running.push(RunningTask {
+            started: *now,
+            task: task.clone(),
+        });
         Some(task)
     }
 
@@ -250,7 +468,7 @@ impl Queue {
         let task = self.pending.front()?;
         let id = task.id;
 
-        let Some(scheduled) = &task.scheduled else {
+        let Some(scheduled) = task.scheduled.into_iter().chain(self.backoff).max() else {
             return Some((0, id));
         };
 
@@ -281,19 +499,21 @@ impl Queue {
             id,
             kind,
             scheduled: None,
+            attempts: 0,
         });
 
         self.modified = true;
         true
     }
 
-    /// Push a task onto the queue.
-    pub(crate) fn push(&mut self, now: &DateTime<Utc>, kind: TaskKind) {
+    /// Push a task onto the queue, returns `false` if an equivalent task is
+    /// already queued.
+    pub(crate) fn push(&mut self, now: &DateTime<Utc>, kind: TaskKind) -> bool {
         let task_ids = kind.task_refs();
 
         for task_id in &task_ids {
             if self.task_ids.contains_key(task_id) {
-                return;
+                return false;
             }
         }
 
@@ -315,8 +535,15 @@ impl Queue {
             id,
             kind,
             scheduled: Some(scheduled + DELAY),
+            attempts: 0,
         });
 
         self.modified = true;
+        true
     }
 }
+
+#[inline]
+fn is_zero(value: &u8) -> bool {
+    *value == 0
+}
@@ -26,6 +26,26 @@ impl Tokens {
     }
 }
 
+/// Calculate how similar two strings are, as a number between `0.0` and `1.0`
+/// based on the tokens they have in common.
+pub(crate) fn similarity(a: &str, b: &str) -> f32 {
+    let mut a_tokens = HashSet::new();
+    tokenize(a, &mut a_tokens);
+    let mut b_tokens = HashSet::new();
+    tokenize(b, &mut b_tokens);
+
+    a_tokens.remove("");
+    b_tokens.remove("");
+
+    let union = a_tokens.union(&b_tokens).count();
+
+    if union == 0 {
+        return 0.0;
+    }
+
+    a_tokens.intersection(&b_tokens).count() as f32 / union as f32
+}
+
 /// Tokenize a string for filtering.
 fn tokenize(input: &str, output: &mut HashSet<String>) {
     let mut string = String::new();
@@ -1,28 +1,52 @@
 pub(crate) mod paths;
 
+use std::cmp::Ordering;
+use std::collections::btree_map;
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::fmt;
 use std::future::Future;
+use std::io::{Read, Write};
 use std::path::Path;
 use std::sync::Arc;
 
 use anyhow::{anyhow, bail, Context, Error, Result};
-use chrono::{DateTime, Days, Local, NaiveDate, Utc};
+use chrono::{DateTime, Datelike, Days, Local, NaiveDate, NaiveTime, Utc, Weekday};
 use futures::stream::FuturesUnordered;
 use iced::advanced::image::Handle;
 use iced::Theme;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Semaphore;
 use tracing_futures::Instrument;
 
+use crate::api::omdb;
 use crate::api::themoviedb;
 use crate::api::thetvdb;
-use crate::assets::ImageKey;
+use crate::assets::{ImageFailure, ImageKey};
 use crate::cache::{self};
 use crate::database::{Change, Database, EpisodeRef, SeasonRef};
 use crate::model::*;
-use crate::queue::{CompletedTask, Task, TaskKind, TaskRef, TaskStatus};
+use crate::queue::{CompletedTask, QueueDump, RunningTask, Task, TaskKind, TaskRef, TaskStatus};
 
 // Cache series updates for 12 hours.
 const CACHE_TIME: i64 = 3600 * 12;
+// The shortest refresh interval a series can be configured to use, since
+// anything shorter would just result in needless API requests.
+const MIN_CACHE_TIME: i64 = 3600;
+// How long after airing missing episode metadata is considered pending rather
+// than the episode simply not having any.
+const METADATA_PENDING_DAYS: i64 = 14;
+// Number of seasons to download episodes for at the same time.
+const SEASON_CONCURRENCY: usize = 4;
+// The number of actions which can be undone.
+const UNDO_LIMIT: usize = 20;
+// Number of seconds to wait before retrying a failed task the first time,
+// which is doubled for every subsequent attempt.
+const RETRY_DELAY_SECONDS: i64 = 30;
 
 /// A movie update as produced by an API.
 #[derive(Debug, Clone)]
@@ -36,6 +60,8 @@ pub(crate) struct UpdateMovie {
     pub(crate) graphics: MovieGraphics,
     pub(crate) remote_id: RemoteId,
     pub(crate) release_dates: Vec<MovieReleaseDates>,
+    pub(crate) collection: Option<String>,
+    pub(crate) content_rating: Option<String>,
 }
 
 /// A series update as produced by an API.
@@ -48,6 +74,10 @@ pub(crate) struct UpdateSeries {
     pub(crate) overview: String,
     pub(crate) graphics: SeriesGraphics,
     pub(crate) remote_id: RemoteId,
+    pub(crate) content_rating: Option<String>,
+    pub(crate) genres: Vec<String>,
+    /// Time of day episodes of the series air, if known.
+    pub(crate) air_time: Option<NaiveTime>,
 }
 
 /// New episode.
@@ -64,10 +94,132 @@ pub(crate) struct NewSeries {
     pub(crate) remote_ids: BTreeSet<RemoteId>,
     pub(crate) last_etag: Option<Etag>,
     pub(crate) last_modified: Option<DateTime<Utc>>,
+    /// Etags of episodes for remotes which list them separately.
+    pub(crate) episode_etags: Vec<Etag>,
     pub(crate) episodes: Vec<NewEpisode>,
     pub(crate) seasons: Vec<Season>,
 }
 
+/// An episode as part of a series export.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct EpisodeExport {
+    #[serde(flatten)]
+    pub(crate) episode: Episode,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) remote_ids: Vec<RemoteEpisodeId>,
+}
+
+/// All data associated with a single series.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct SeriesExport {
+    pub(crate) series: Series,
+    #[serde(default)]
+    pub(crate) remote_ids: Vec<RemoteId>,
+    #[serde(default)]
+    pub(crate) seasons: Vec<Season>,
+    #[serde(default)]
+    pub(crate) episodes: Vec<EpisodeExport>,
+    /// Watch history, if it was included in the export.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) watched: Option<Vec<Watched>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) pending: Option<Pending>,
+}
+
+/// Statistics over how many times the episodes of a series have been watched.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct RewatchStats {
+    /// Number of episodes in the series.
+    pub(crate) episodes: usize,
+    /// Total number of watches.
+    pub(crate) total: usize,
+    /// Number of distinct episodes which have been watched.
+    pub(crate) distinct: usize,
+    /// The most times any single episode has been watched.
+    pub(crate) max: usize,
+}
+
+impl RewatchStats {
+    /// The average number of times each episode has been watched.
+    pub(crate) fn average(&self) -> Option<f32> {
+        if self.episodes == 0 {
+            return None;
+        }
+
+        Some(self.total as f32 / self.episodes as f32)
+    }
+}
+
+/// Version of the format written by [`Service::export_database_json`].
+const DATABASE_SCHEMA_VERSION: u32 = 1;
+
+/// All data associated with a single movie.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct MovieExport {
+    pub(crate) movie: Movie,
+    #[serde(default)]
+    pub(crate) remote_ids: Vec<RemoteId>,
+    #[serde(default)]
+    pub(crate) watched: Vec<Watched>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) pending: Option<Pending>,
+}
+
+/// A snapshot of the whole database.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct DatabaseExport {
+    pub(crate) schema_version: u32,
+    #[serde(default)]
+    pub(crate) series: Vec<SeriesExport>,
+    #[serde(default)]
+    pub(crate) movies: Vec<MovieExport>,
+}
+
+/// Number of shows listed in [`StatsReport`].
+const STATS_TOP_SHOWS: usize = 5;
+
+/// A plain text report of watch statistics.
+pub struct StatsReport {
+    tracked_series: usize,
+    watches: usize,
+    distinct_episodes: usize,
+    minutes: u64,
+    default_runtime_minutes: u32,
+    top: Vec<(String, usize)>,
+}
+
+impl fmt::Display for StatsReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Tracked series: {}", self.tracked_series)?;
+        writeln!(
+            f,
+            "Watched episodes: {} ({} watches)",
+            self.distinct_episodes, self.watches
+        )?;
+        writeln!(
+            f,
+            "Estimated watch time: {}h {}m (assuming {} minutes for episodes without a runtime)",
+            self.minutes / 60,
+            self.minutes % 60,
+            self.default_runtime_minutes
+        )?;
+
+        if !self.top.is_empty() {
+            writeln!(f, "Most watched:")?;
+
+            for (n, (title, count)) in self.top.iter().enumerate() {
+                writeln!(f, "{:>3}. {title} ({count} episodes)", n + 1)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
 /// Data encapsulating a newly added movie.
 #[derive(Debug, Clone)]
 pub(crate) struct NewMovie {
@@ -77,6 +229,14 @@ pub(crate) struct NewMovie {
     pub(crate) last_modified: Option<DateTime<Utc>>,
 }
 
+/// A week of the schedule.
+pub(crate) struct ScheduleWeek<'a> {
+    /// The first day of the week.
+    pub(crate) start: NaiveDate,
+    /// Days in the week and what is scheduled on them.
+    pub(crate) days: Vec<(NaiveDate, &'a [Scheduled])>,
+}
+
 /// A pending thing to watch.
 #[derive(Debug, Clone, Copy)]
 pub(crate) enum PendingRef<'a> {
@@ -99,6 +259,14 @@ impl<'a> PendingRef<'a> {
         }
     }
 
+    /// Get the title of the series or movie being referenced.
+    pub(crate) fn title(&self) -> &'a str {
+        match self {
+            PendingRef::Episode { series, .. } => &series.title,
+            PendingRef::Movie { movie } => &movie.title,
+        }
+    }
+
     /// Get poster for the given pending reference.
     pub(crate) fn poster(&self) -> Option<&'a ImageV2> {
         match self {
@@ -138,10 +306,15 @@ pub struct Service {
     db: Database,
     tvdb: thetvdb::Client,
     tmdb: themoviedb::Client,
+    omdb: omdb::Client,
     do_not_save: bool,
     current_theme: Theme,
     schedule: Vec<ScheduledDay>,
+    recently_aired: Vec<(NaiveDate, EpisodeId)>,
+    recently_added: Vec<(SeriesId, EpisodeId)>,
     now: NaiveDate,
+    undo: VecDeque<Undo>,
+    images: Arc<Mutex<cache::Index>>,
 }
 
 impl Service {
@@ -154,23 +327,30 @@ impl Service {
             std::fs::create_dir_all(&paths.images)?;
         }
 
+        let images = cache::Index::load(&paths.images)?;
         let db = Database::load(&paths)?;
         let tvdb = thetvdb::Client::new(&db.config.tvdb_legacy_apikey)?;
         let tmdb = themoviedb::Client::new(&db.config.tmdb_api_key)?;
+        let omdb = omdb::Client::new(&db.config.omdb_api_key)?;
 
         let current_theme = db.config.iced_theme();
 
-        let now = Local::now();
+        let now = db.config.air_today(&Local::now());
 
         let mut this = Self {
             paths: Arc::new(paths),
             db,
             tvdb,
             tmdb,
+            omdb,
             do_not_save: false,
             current_theme,
             schedule: Vec::new(),
-            now: now.date_naive(),
+            recently_aired: Vec::new(),
+            recently_added: Vec::new(),
+            now,
+            undo: VecDeque::new(),
+            images: Arc::new(Mutex::new(images)),
         };
 
         this.rebuild_schedule();
@@ -187,6 +367,169 @@ impl Service {
         &self.schedule
     }
 
+    /// The schedule grouped into weeks starting on the configured first day of
+    /// the week.
+    ///
+    /// Days without anything scheduled are only included if
+    /// [`Config::schedule_show_empty_days`] is set.
+    pub(crate) fn schedule_by_week(&self) -> Vec<ScheduleWeek<'_>> {
+        let week_start = self.config().week_start;
+        let show_empty = self.config().schedule_show_empty_days;
+
+        let last = self
+            .now
+            .checked_add_days(Days::new(self.config().schedule_window.days()));
+
+        let mut weeks = Vec::<ScheduleWeek<'_>>::new();
+        let mut days = self.schedule.iter().peekable();
+        let mut current = self.now;
+
+        loop {
+            let scheduled = days.next_if(|day| !show_empty || day.date == current);
+
+            let (date, schedule) = match scheduled {
+                Some(day) => (day.date, day.schedule.as_slice()),
+                None if show_empty && last.is_some_and(|last| current <= last) => {
+                    (current, &[][..])
+                }
+                None => break,
+            };
+
+            let Some(start) = week_of(&date, week_start) else {
+                break;
+            };
+
+            match weeks.last_mut() {
+                Some(week) if week.start == start => {
+                    week.days.push((date, schedule));
+                }
+                _ => {
+                    weeks.push(ScheduleWeek {
+                        start,
+                        days: vec![(date, schedule)],
+                    });
+                }
+            }
+
+            let Some(next) = date.checked_add_days(Days::new(1)) else {
+                break;
+            };
+
+            current = next;
+        }
+
+        weeks
+    }
+
+    /// Episodes of tracked series which have aired recently but haven't been
+    /// watched, oldest first.
+    pub(crate) fn catch_up(&self) -> impl Iterator<Item = EpisodeRef<'_>> + '_ {
+        let include_specials = self.db.config.catch_up_include_specials;
+
+        self.recently_aired
+            .iter()
+            .filter_map(|(_, id)| self.db.episodes.get(id))
+            .filter(move |e| include_specials || !e.season.is_special())
+            .filter(|e| !self.is_ignored_special(e))
+            .filter(|e| self.db.watched.by_episode(&e.id).len() == 0)
+    }
+
+    /// Revision of the watch history, which changes every time a watch is
+    /// added or removed.
+    #[inline]
+    pub(crate) fn watch_revision(&self) -> u64 {
+        self.db.watched.revision()
+    }
+
+    /// Count watches made today in the local timezone.
+    ///
+    /// Watches dated after `now`, such as ones marked with a future air date,
+    /// are not counted.
+    pub(crate) fn watched_today_count(&self, now: &DateTime<Utc>) -> usize {
+        let today = now.with_timezone(&Local).date_naive();
+
+        self.db
+            .watched
+            .iter()
+            .filter(|w| w.timestamp <= *now)
+            .filter(|w| w.timestamp.with_timezone(&Local).date_naive() == today)
+            .count()
+    }
+
+    /// Get the number of consecutive days up until now on which something has
+    /// been watched, using local day boundaries.
+    ///
+    /// A streak is still ongoing if nothing has been watched yet today but
+    /// something was watched yesterday.
+    pub(crate) fn watch_streak(&self, now: &DateTime<Utc>) -> usize {
+        let days = self
+            .db
+            .watched
+            .iter()
+            .filter(|w| w.timestamp <= *now)
+            .map(|w| w.timestamp.with_timezone(&Local).date_naive())
+            .collect::<BTreeSet<_>>();
+
+        let mut day = now.with_timezone(&Local).date_naive();
+
+        if !days.contains(&day) {
+            let Some(yesterday) = day.pred_opt() else {
+                return 0;
+            };
+
+            day = yesterday;
+        }
+
+        let mut streak = 0;
+
+        while days.contains(&day) {
+            streak += 1;
+
+            let Some(previous) = day.pred_opt() else {
+                break;
+            };
+
+            day = previous;
+        }
+
+        streak
+    }
+
+    /// Count watches per week over the given number of weeks leading up to and
+    /// including the week of `today`, oldest first.
+    pub(crate) fn weekly_watches(
+        &self,
+        today: &NaiveDate,
+        week_start: Weekday,
+        weeks: usize,
+    ) -> Vec<usize> {
+        let mut counts = vec![0; weeks];
+
+        if weeks == 0 {
+            return counts;
+        }
+
+        let Some(first) = week_of(today, week_start)
+            .and_then(|start| start.checked_sub_days(Days::new((weeks as u64 - 1) * 7)))
+        else {
+            return counts;
+        };
+
+        for w in self.db.watched.iter() {
+            let date = w.timestamp.with_timezone(&Local).date_naive();
+
+            let Ok(days) = usize::try_from((date - first).num_days()) else {
+                continue;
+            };
+
+            if let Some(count) = counts.get_mut(days / 7) {
+                *count += 1;
+            }
+        }
+
+        counts
+    }
+
     /// Get a single series.
     pub(crate) fn series(&self, id: &SeriesId) -> Option<&Series> {
         self.db.series.get(id)
@@ -202,6 +545,19 @@ impl Service {
         self.db.series.iter_by_name()
     }
 
+    /// Get every known series genre, sorted.
+    pub(crate) fn genres(&self) -> Vec<String> {
+        let genres = self
+            .db
+            .series
+            .iter()
+            .flat_map(|s| &s.genres)
+            .filter(|g| !g.is_empty())
+            .collect::<BTreeSet<_>>();
+
+        genres.into_iter().cloned().collect()
+    }
+
     /// Get list of series.
     pub(crate) fn movies_by_name(&self) -> impl DoubleEndedIterator<Item = &Movie> {
         self.db.movies.iter_by_name()
@@ -251,6 +607,12 @@ impl Service {
         self.db.seasons.by_series(series_id)
     }
 
+    /// Get every watch in the database.
+    #[inline]
+    pub(crate) fn watched(&self) -> impl Iterator<Item = &Watched> {
+        self.db.watched.iter()
+    }
+
     /// Get all the watches for the given episode.
     #[inline]
     pub(crate) fn watched_by_episode(
@@ -275,7 +637,7 @@ impl Service {
     }
 
     /// Get task queue.
-    pub(crate) fn running_tasks(&self) -> impl ExactSizeIterator<Item = &Task> {
+    pub(crate) fn running_tasks(&self) -> impl ExactSizeIterator<Item = &RunningTask> {
         self.db.tasks.running()
     }
 
@@ -284,683 +646,2696 @@ impl Service {
         self.db.tasks.completed()
     }
 
-    /// Get season summary statistics.
-    pub(crate) fn season_watched(
-        &self,
-        series_id: &SeriesId,
-        season: &SeasonNumber,
-    ) -> (usize, usize) {
-        let mut total = 0;
-        let mut watched = 0;
+    /// Dump the task queue as JSON.
+    pub fn dump_queue(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(&self.db.tasks.dump())?)
+    }
 
-        for episode in self.episodes(series_id).filter(|e| e.season == *season) {
-            total += 1;
-            watched += usize::from(self.watched_by_episode(&episode.id).len() != 0);
+    /// Load the task queue from a JSON dump at the given path.
+    ///
+    /// Refuses to replace a non-empty queue unless `force` is set.
+    pub fn load_queue(&mut self, path: &Path, force: bool) -> Result<()> {
+        if !force && !self.db.tasks.is_empty() {
+            bail!("Refusing to replace non-empty queue without force");
         }
 
-        (watched, total)
+        let bytes = std::fs::read(path).with_context(|| anyhow!("{}", path.display()))?;
+        let dump: QueueDump =
+            serde_json::from_slice(&bytes).with_context(|| anyhow!("{}", path.display()))?;
+        self.db.tasks.restore(dump)?;
+        self.db.changes.change(Change::Queue);
+        Ok(())
     }
 
-    /// Get the pending episode for the given movie.
-    pub(crate) fn pending_by_movie(&self, movie_id: &MovieId) -> Option<&Pending> {
-        self.db.pending.by_movie(movie_id)
-    }
+    /// Export a single series as JSON.
+    pub fn export_series_json(&self, id: &str, include_watched: bool) -> Result<String> {
+        let id = id.parse::<SeriesId>().context("bad series id")?;
 
-    /// Return list of pending episodes.
-    pub(crate) fn pending(&self) -> impl DoubleEndedIterator<Item = PendingRef<'_>> + Clone {
-        self.db
-            .pending
-            .iter()
-            .flat_map(move |p| self.pending_ref(p))
-    }
+        let Some(export) = self.export_series(&id, include_watched) else {
+            bail!("No series with id {id}");
+        };
 
-    /// Get pending by series.
-    pub(crate) fn pending_ref_by_series(&self, series_id: &SeriesId) -> Option<PendingRef<'_>> {
-        let p = self.db.pending.get(series_id)?;
-        self.pending_ref(p)
+        Ok(serde_json::to_string_pretty(&export)?)
     }
 
-    fn pending_ref(&self, p: &Pending) -> Option<PendingRef<'_>> {
-        match &p.kind {
-            PendingKind::Episode { series, episode } => {
-                let series = self.db.series.get(series)?;
+    /// Export everything pending as a Markdown checklist grouped by show, in
+    /// the same order as watch next.
+    pub fn export_pending_markdown(&self) -> Result<String> {
+        use std::fmt::Write as _;
 
-                if !series.tracked {
-                    return None;
-                }
+        let mut out = String::new();
+        writeln!(out, "# Watch next")?;
 
-                let episode = self.db.episodes.get(episode)?;
-                let season = self.season(&series.id, &episode.season);
+        for p in self.pending_ordered(&Utc::now(), self.db.config.watch_next_order) {
+            writeln!(out)?;
 
-                Some(PendingRef::Episode {
-                    series,
-                    season,
-                    episode,
-                })
-            }
-            PendingKind::Movie { movie } => {
-                let movie = self.db.movies.get(movie)?;
+            match p {
+                PendingRef::Episode {
+                    series, episode, ..
+                } => {
+                    writeln!(out, "## {}", markdown_escape(&series.title))?;
+                    writeln!(out)?;
 
-                Some(PendingRef::Movie { movie })
-            }
-        }
-    }
+                    let season = match episode.season {
+                        SeasonNumber::Specials => 0,
+                        SeasonNumber::Number(n) => n,
+                    };
 
-    /// Test if we have changes.
-    pub(crate) fn has_changes(&self) -> bool {
-        self.db.changes.has_changes()
-    }
+                    write!(out, "- [ ] S{season:02}E{:02}", episode.number)?;
 
-    /// Find updates that need to be performed.
-    pub(crate) fn find_updates(&mut self, now: &DateTime<Utc>) {
-        for s in self.db.series.iter() {
-            // Ignore series which are no longer tracked.
-            if !s.tracked {
-                continue;
-            }
+                    if let Some(name) = &episode.name {
+                        write!(out, " {}", markdown_escape(name))?;
+                    }
 
-            let Some(remote_id) = s.remote_id else {
-                continue;
-            };
+                    if let Some(aired) = &episode.aired {
+                        write!(out, " (aired {aired})")?;
+                    }
+                }
+                PendingRef::Movie { movie } => {
+                    writeln!(out, "## {}", markdown_escape(&movie.title))?;
+                    writeln!(out)?;
+                    write!(out, "- [ ] Movie")?;
 
-            // Reduce the number of API requests by ensuring we don't check for
-            // updates more than each CACHE_TIME interval.
-            if let Some(last_sync) = self.db.sync.last_sync(&remote_id) {
-                if now.signed_duration_since(*last_sync).num_seconds() < CACHE_TIME {
-                    continue;
+                    if let Some(date) = p.date() {
+                        write!(out, " (released {date})")?;
+                    }
                 }
             }
 
-            let last_modified = self.db.sync.last_modified(&remote_id).copied();
+            writeln!(out)?;
+        }
 
-            if matches!(last_modified, Some(last_modified) if now.signed_duration_since(last_modified).num_seconds() < CACHE_TIME)
-            {
-                continue;
-            }
+        Ok(out)
+    }
 
-            let kind = match remote_id {
-                RemoteId::Tvdb { .. } => TaskKind::CheckForUpdates {
-                    series_id: s.id,
-                    remote_id,
-                    last_modified,
-                },
-                RemoteId::Tmdb { .. } => TaskKind::DownloadSeries {
-                    series_id: s.id,
-                    remote_id,
-                    last_modified,
-                    force: false,
-                },
-                RemoteId::Imdb { .. } => continue,
-            };
+    /// Build a text report of watch statistics.
+    pub fn text_stats(&self) -> StatsReport {
+        let mut watches = 0;
+        let mut minutes = 0u64;
+        let mut by_series = HashMap::<SeriesId, HashSet<EpisodeId>>::new();
 
-            self.db.tasks.push(now, kind);
-        }
+        for w in self.db.watched.iter() {
+            if let WatchedKind::Series { series, episode } = &w.kind {
+                watches += 1;
 
-        for m in self.db.movies.iter() {
-            let Some(remote_id) = m.remote_id else {
-                continue;
-            };
+                minutes += u64::from(match self.db.episodes.get(episode) {
+                    Some(e) => e.runtime_or(&self.db.config),
+                    None => self.db.config.default_runtime_minutes,
+                });
 
-            // Reduce the number of API requests by ensuring we don't check for
-            // updates more than each CACHE_TIME interval.
-            if let Some(last_sync) = self.db.sync.last_sync(&remote_id) {
-                if now.signed_duration_since(*last_sync).num_seconds() < CACHE_TIME {
-                    continue;
-                }
+                by_series.entry(*series).or_default().insert(*episode);
             }
+        }
 
-            let last_modified = self.db.sync.last_modified(&remote_id).copied();
+        let distinct_episodes = by_series.values().map(HashSet::len).sum();
 
-            if matches!(last_modified, Some(last_modified) if now.signed_duration_since(last_modified).num_seconds() < CACHE_TIME)
-            {
-                continue;
-            }
+        let mut top = by_series
+            .iter()
+            .flat_map(|(id, episodes)| Some((self.db.series.get(id)?, episodes.len())))
+            .collect::<Vec<_>>();
 
-            let kind = match remote_id {
-                RemoteId::Tmdb { .. } => TaskKind::DownloadMovie {
-                    movie_id: m.id,
-                    remote_id,
-                    last_modified,
-                    force: false,
-                },
-                _ => continue,
-            };
+        top.sort_by(|(a, a_count), (b, b_count)| {
+            b_count.cmp(a_count).then_with(|| a.title.cmp(&b.title))
+        });
 
-            self.db.tasks.push(now, kind);
+        StatsReport {
+            tracked_series: self.db.series.iter().filter(|s| s.tracked).count(),
+            watches,
+            distinct_episodes,
+            minutes,
+            default_runtime_minutes: self.db.config.default_runtime_minutes,
+            top: top
+                .into_iter()
+                .take(STATS_TOP_SHOWS)
+                .map(|(s, count)| (s.title.clone(), count))
+                .collect(),
         }
     }
 
-    /// Check for update for the given series.
-    pub(crate) fn check_for_updates(
-        &mut self,
-        series_id: SeriesId,
-        remote_id: RemoteId,
-        last_modified: Option<DateTime<Utc>>,
-    ) -> impl Future<Output = Result<Option<TaskKind>>> {
-        let tvdb = self.tvdb.clone();
+    /// Import a single series from a JSON export at the given path.
+    pub fn import_series_json(&mut self, path: &Path) -> Result<()> {
+        let bytes = std::fs::read(path).with_context(|| anyhow!("{}", path.display()))?;
+        let export: SeriesExport =
+            serde_json::from_slice(&bytes).with_context(|| anyhow!("{}", path.display()))?;
+        let id = self.import_series(export)?;
+        tracing::info!("Imported series {id}");
+        Ok(())
+    }
 
-        let future = async move {
-            match remote_id {
-                RemoteId::Tvdb { id } => {
-                    let Some(update) = tvdb.series_last_modified(id).await? else {
-                        bail!("{series_id}/{remote_id}: missing last-modified in api");
-                    };
+    /// Write a snapshot of the whole database as a single JSON document.
+    pub fn export_database_json(&self, out: &mut impl Write) -> Result<()> {
+        let series = self
+            .db
+            .series
+            .iter()
+            .flat_map(|s| self.export_series(&s.id, true))
+            .collect();
 
-                    tracing::trace!(?update, ?last_modified, ?series_id, ?remote_id,);
+        let movies = self
+            .db
+            .movies
+            .iter()
+            .map(|m| MovieExport {
+                movie: m.clone(),
+                remote_ids: self.db.remotes.get_by_movie(&m.id).collect(),
+                watched: self.db.watched.by_movie(&m.id).cloned().collect(),
+                pending: self.db.pending.by_movie(&m.id).copied(),
+            })
+            .collect();
+
+        let export = DatabaseExport {
+            schema_version: DATABASE_SCHEMA_VERSION,
+            series,
+            movies,
+        };
 
-                    if matches!(last_modified, Some(last_modified) if last_modified >= update) {
-                        return Ok(None);
-                    }
+        serde_json::to_writer_pretty(out, &export)?;
+        Ok(())
+    }
 
-                    let kind = TaskKind::DownloadSeries {
-                        series_id,
-                        remote_id,
-                        last_modified: Some(update),
-                        force: false,
-                    };
+    /// Import a snapshot written by [`Service::export_database_json`],
+    /// replacing any existing data for the series and movies in it.
+    pub fn import_database_json(&mut self, input: impl Read) -> Result<()> {
+        let export: DatabaseExport = serde_json::from_reader(input)?;
 
-                    Ok(Some(kind))
+        if export.schema_version != DATABASE_SCHEMA_VERSION {
+            bail!(
+                "Unsupported schema version {}, expected {DATABASE_SCHEMA_VERSION}",
+                export.schema_version
+            );
+        }
+
+        let series = export.series.len();
+        let movies = export.movies.len();
+
+        for s in export.series {
+            self.import_series(s)?;
+        }
+
+        for m in export.movies {
+            self.import_movie(m);
+        }
+
+        tracing::info!("Imported {series} series and {movies} movies");
+        Ok(())
+    }
+
+    /// Import a movie export, replacing any existing data for the same movie.
+    fn import_movie(&mut self, export: MovieExport) {
+        let movie_id = export.movie.id;
+
+        if self.db.remotes.replace_movie(movie_id, export.remote_ids) {
+            self.db.changes.change(Change::Remotes);
+        }
+
+        let _ = self.db.movies.remove(&movie_id);
+        self.db.movies.insert(export.movie);
+        self.db.changes.add_movie(&movie_id);
+
+        let existing = self
+            .db
+            .watched
+            .by_movie(&movie_id)
+            .map(|w| w.id)
+            .collect::<Vec<_>>();
+
+        for id in existing {
+            self.db.watched.remove_watch(&id);
+        }
+
+        for w in export.watched {
+            self.db.watched.insert(w);
+        }
+
+        self.db.changes.change(Change::Watched);
+
+        if self.db.pending.remove_movie(&movie_id).is_some() {
+            self.db.changes.change(Change::Pending);
+        }
+
+        if let Some(pending) = export.pending {
+            self.db.pending.extend([pending]);
+            self.db.changes.change(Change::Pending);
+        }
+    }
+
+    /// Collect all data associated with the given series.
+    pub(crate) fn export_series(
+        &self,
+        id: &SeriesId,
+        include_watched: bool,
+    ) -> Option<SeriesExport> {
+        let series = self.db.series.get(id)?.clone();
+
+        let seasons = self
+            .db
+            .seasons
+            .by_series(id)
+            .map(|s| s.into_season().clone())
+            .collect();
+
+        let episodes = self
+            .db
+            .episodes
+            .by_series(id)
+            .map(|e| EpisodeExport {
+                episode: (*e).clone(),
+                remote_ids: self.db.remotes.get_by_episode(&e.id).collect(),
+            })
+            .collect();
+
+        let watched = include_watched.then(|| self.db.watched.by_series(id).cloned().collect());
+
+        Some(SeriesExport {
+            series,
+            remote_ids: self.db.remotes.get_by_series(id).collect(),
+            seasons,
+            episodes,
+            watched,
+            pending: self.db.pending.get(id).copied(),
+        })
+    }
+
+    /// Import a series export, replacing any existing data for the same
+    /// series.
+    ///
+    /// This fails if any of its remotes belong to a different series which
+    /// already exists.
+    pub(crate) fn import_series(&mut self, export: SeriesExport) -> Result<SeriesId> {
+        let series_id = export.series.id;
+
+        for remote_id in &export.remote_ids {
+            if let Some(existing) = self.db.remotes.get_series(remote_id) {
+                if existing != series_id && self.db.series.get(&existing).is_some() {
+                    bail!("Remote {remote_id} already belongs to series {existing}");
                 }
-                // Nothing to do with the IMDB remote.
-                remote_id => Ok(Some(TaskKind::DownloadSeries {
-                    series_id,
+            }
+        }
+
+        if self.db.remotes.replace_series(series_id, export.remote_ids) {
+            self.db.changes.change(Change::Remotes);
+        }
+
+        // NB: Episodes which are no longer part of the series lose their
+        // remotes.
+        let stale = self
+            .db
+            .episodes
+            .by_series(&series_id)
+            .map(|e| e.id)
+            .filter(|id| !export.episodes.iter().any(|e| e.episode.id == *id))
+            .collect::<Vec<_>>();
+
+        for episode_id in stale {
+            if self.db.remotes.replace_episode(episode_id, []) {
+                self.db.changes.change(Change::Remotes);
+            }
+        }
+
+        let mut episodes = Vec::with_capacity(export.episodes.len());
+
+        for e in export.episodes {
+            if self.db.remotes.replace_episode(e.episode.id, e.remote_ids) {
+                self.db.changes.change(Change::Remotes);
+            }
+
+            episodes.push(e.episode);
+        }
+
+        self.db.episodes.insert(series_id, episodes);
+        self.db.seasons.insert(series_id, export.seasons);
+        let _ = self.db.series.remove(&series_id);
+        self.db.series.insert(export.series);
+        self.db.changes.add_series(&series_id);
+
+        if let Some(watched) = export.watched {
+            self.db.watched.remove_by_series(&series_id);
+
+            for w in watched {
+                self.db.watched.insert(w);
+            }
+
+            self.db.changes.change(Change::Watched);
+        }
+
+        if let Some(pending) = export.pending {
+            self.db.pending.extend([pending]);
+            self.db.changes.change(Change::Pending);
+        }
+
+        Ok(series_id)
+    }
+
+    /// Test if every season of a series outside of specials which has started
+    /// airing has been watched, as counted by [`Service::season_watched`].
+    ///
+    /// Series which haven't started airing yet are not complete.
+    pub(crate) fn is_series_complete(&self, series_id: &SeriesId, today: &NaiveDate) -> bool {
+        let mut any = false;
+
+        for season in self
+            .seasons(series_id)
+            .filter(|s| !s.number.is_special() && s.air_date.is_some_and(|d| d <= *today))
+        {
+            let (watched, total) = self.season_watched(series_id, &season.number);
+
+            if watched < total {
+                return false;
+            }
+
+            any = true;
+        }
+
+        any
+    }
+
+    /// Get season summary statistics.
+    pub(crate) fn season_watched(
+        &self,
+        series_id: &SeriesId,
+        season: &SeasonNumber,
+    ) -> (usize, usize) {
+        let mut total = 0;
+        let mut watched = 0;
+
+        for episode in self.episodes(series_id).filter(|e| e.season == *season) {
+            total += 1;
+
+            let skipped = match self.episode_skip(&episode) {
+                Some(Skip::Permanent) => true,
+                Some(Skip::Once) => self.db.config.count_skipped_as_watched,
+                None => false,
+            };
+
+            let done = self.watched_by_episode(&episode.id).len() != 0 || skipped;
+
+            watched += usize::from(done);
+        }
+
+        (watched, total)
+    }
+
+    /// Get statistics over how many times a series has been watched.
+    pub(crate) fn series_rewatch_stats(&self, series_id: &SeriesId) -> RewatchStats {
+        let mut stats = RewatchStats::default();
+
+        for episode in self.episodes(series_id) {
+            let count = self.watched_by_episode(&episode.id).len();
+            stats.episodes += 1;
+            stats.total += count;
+            stats.distinct += usize::from(count > 0);
+            stats.max = stats.max.max(count);
+        }
+
+        stats
+    }
+
+    /// Get the average rating of watches in the given season, if any of them
+    /// have been rated.
+    pub(crate) fn season_rating(&self, series_id: &SeriesId, season: &SeasonNumber) -> Option<f32> {
+        let mut total = 0u32;
+        let mut count = 0u32;
+
+        for episode in self.episodes_by_season(series_id, season) {
+            for rating in self.watched_by_episode(&episode.id).flat_map(|w| w.rating) {
+                total += u32::from(rating);
+                count += 1;
+            }
+        }
+
+        if count == 0 {
+            return None;
+        }
+
+        Some(total as f32 / count as f32)
+    }
+
+    /// Count aired episodes in the given season which haven't been watched.
+    pub(crate) fn available_in_season(
+        &self,
+        series_id: &SeriesId,
+        season: &SeasonNumber,
+        today: &NaiveDate,
+    ) -> usize {
+        self.episodes_by_season(series_id, season)
+            .filter(|e| e.has_aired(today) && self.watched_by_episode(&e.id).len() == 0)
+            .count()
+    }
+
+    /// Directory where configuration and data is stored.
+    #[inline]
+    pub(crate) fn config_dir(&self) -> &Path {
+        &self.paths.root
+    }
+
+    /// Directory where cached images are stored.
+    #[inline]
+    pub(crate) fn cache_dir(&self) -> &Path {
+        &self.paths.cache
+    }
+
+    /// Get the pending episode for the given movie.
+    pub(crate) fn pending_by_movie(&self, movie_id: &MovieId) -> Option<&Pending> {
+        self.db.pending.by_movie(movie_id)
+    }
+
+    /// Return list of pending episodes.
+    ///
+    /// Items which are snoozed are left out until their snooze has expired.
+    pub(crate) fn pending(
+        &self,
+        now: &DateTime<Utc>,
+    ) -> impl DoubleEndedIterator<Item = PendingRef<'_>> + Clone {
+        let now = *now;
+
+        self.db
+            .pending
+            .iter()
+            .filter(move |p| !p.is_snoozed(&now))
+            .flat_map(move |p| self.pending_ref(p))
+    }
+
+    /// Snooze what's pending for a series until the given time, or wake it up
+    /// if `until` is `None`.
+    pub(crate) fn snooze_pending(&mut self, series_id: &SeriesId, until: Option<DateTime<Utc>>) {
+        if self.db.pending.snooze(series_id, until) {
+            self.db.changes.change(Change::Pending);
+            self.rebuild_schedule();
+        }
+    }
+
+    /// Get when what's pending for a series is snoozed until, if it's
+    /// currently snoozed.
+    pub(crate) fn snoozed_until(
+        &self,
+        series_id: &SeriesId,
+        now: &DateTime<Utc>,
+    ) -> Option<DateTime<Utc>> {
+        let p = self.db.pending.get(series_id)?;
+        p.snoozed_until.filter(|_| p.is_snoozed(now))
+    }
+
+    /// Get pending episodes and movies, most recent first, in the given
+    /// order.
+    ///
+    /// Items which compare equal keep their pending order, and items without
+    /// a release date are put last when ordering by release.
+    pub(crate) fn pending_ordered(
+        &self,
+        now: &DateTime<Utc>,
+        order: PendingOrder,
+    ) -> Vec<PendingRef<'_>> {
+        let mut pending = self.pending(now).rev().collect::<Vec<_>>();
+
+        match order {
+            PendingOrder::Pending => {}
+            PendingOrder::Released => {
+                pending.sort_by_key(|p| std::cmp::Reverse(p.date()));
+            }
+        }
+
+        pending
+    }
+
+    /// Get pending episodes and movies which have aired, oldest first.
+    ///
+    /// Items released on the same day are ordered by title, and items without
+    /// a release date are put last.
+    pub(crate) fn backlog(&self, now: &DateTime<Utc>, today: &NaiveDate) -> Vec<PendingRef<'_>> {
+        let mut backlog = self
+            .pending(now)
+            .filter(|p| p.has_aired(today))
+            .collect::<Vec<_>>();
+
+        backlog.sort_by(|a, b| match (a.date(), b.date()) {
+            (Some(a_date), Some(b_date)) => {
+                a_date.cmp(&b_date).then_with(|| a.title().cmp(b.title()))
+            }
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => a.title().cmp(b.title()),
+        });
+
+        backlog
+    }
+
+    /// Get pending episodes and movies, most recent first, with episodes of
+    /// favorite series put before everything else.
+    pub(crate) fn pending_favorites_first(&self, now: &DateTime<Utc>) -> Vec<PendingRef<'_>> {
+        let mut pending = self.pending(now).rev().collect::<Vec<_>>();
+        pending
+            .sort_by_key(|p| !matches!(p, PendingRef::Episode { series, .. } if series.favorite));
+        pending
+    }
+
+    /// Get pending by series.
+    pub(crate) fn pending_ref_by_series(&self, series_id: &SeriesId) -> Option<PendingRef<'_>> {
+        let p = self.db.pending.get(series_id)?;
+        self.pending_ref(p)
+    }
+
+    fn pending_ref(&self, p: &Pending) -> Option<PendingRef<'_>> {
+        match &p.kind {
+            PendingKind::Episode { series, episode } => {
+                let series = self.db.series.get(series)?;
+
+                if !series.tracked || series.removed.is_some() {
+                    return None;
+                }
+
+                let episode = self.db.episodes.get(episode)?;
+                let season = self.season(&series.id, &episode.season);
+
+                Some(PendingRef::Episode {
+                    series,
+                    season,
+                    episode,
+                })
+            }
+            PendingKind::Movie { movie } => {
+                let movie = self.db.movies.get(movie)?;
+
+                Some(PendingRef::Movie { movie })
+            }
+        }
+    }
+
+    /// Test if we have changes.
+    pub(crate) fn has_changes(&self) -> bool {
+        self.db.changes.has_changes()
+    }
+
+    /// Find updates that need to be performed.
+    pub(crate) fn find_updates(&mut self, now: &DateTime<Utc>) {
+        for s in self.db.series.iter() {
+            // Ignore series which are no longer tracked.
+            if !s.tracked {
+                continue;
+            }
+
+            // NB: Snoozed series are checked again once their snooze expires.
+            if self
+                .db
+                .pending
+                .get(&s.id)
+                .is_some_and(|p| p.is_snoozed(now))
+            {
+                continue;
+            }
+
+            let Some(remote_id) = s.remote_id else {
+                continue;
+            };
+
+            let cache_time = series_cache_time(s);
+
+            // Reduce the number of API requests by ensuring we don't check for
+            // updates more than each cache time interval.
+            if let Some(last_sync) = self.db.sync.last_sync(&remote_id) {
+                if now.signed_duration_since(*last_sync).num_seconds() < cache_time {
+                    continue;
+                }
+            }
+
+            let last_modified = self.db.sync.last_modified(&remote_id).copied();
+
+            if matches!(last_modified, Some(last_modified) if now.signed_duration_since(last_modified).num_seconds() < cache_time)
+            {
+                continue;
+            }
+
+            let kind = match remote_id {
+                RemoteId::Tvdb { .. } => TaskKind::CheckForUpdates {
+                    series_id: s.id,
+                    remote_id,
+                    last_modified,
+                },
+                RemoteId::Tmdb { .. } => TaskKind::DownloadSeries {
+                    series_id: s.id,
                     remote_id,
                     last_modified,
                     force: false,
-                })),
+                },
+                RemoteId::Imdb { .. } => continue,
+            };
+
+            if self.db.tasks.push(now, kind) {
+                self.db.changes.change(Change::Queue);
             }
+        }
+
+        for m in self.db.movies.iter() {
+            let Some(remote_id) = m.remote_id else {
+                continue;
+            };
+
+            // Reduce the number of API requests by ensuring we don't check for
+            // updates more than each CACHE_TIME interval.
+            if let Some(last_sync) = self.db.sync.last_sync(&remote_id) {
+                if now.signed_duration_since(*last_sync).num_seconds() < CACHE_TIME {
+                    continue;
+                }
+            }
+
+            let last_modified = self.db.sync.last_modified(&remote_id).copied();
+
+            if matches!(last_modified, Some(last_modified) if now.signed_duration_since(last_modified).num_seconds() < CACHE_TIME)
+            {
+                continue;
+            }
+
+            let kind = match remote_id {
+                RemoteId::Tmdb { .. } => TaskKind::DownloadMovie {
+                    movie_id: m.id,
+                    remote_id,
+                    last_modified,
+                    force: false,
+                },
+                _ => continue,
+            };
+
+            if self.db.tasks.push(now, kind) {
+                self.db.changes.change(Change::Queue);
+            }
+        }
+    }
+
+    /// Check for update for the given series.
+    pub(crate) fn check_for_updates(
+        &mut self,
+        series_id: SeriesId,
+        remote_id: RemoteId,
+        last_modified: Option<DateTime<Utc>>,
+    ) -> impl Future<Output = Result<Option<TaskKind>>> {
+        let tvdb = self.tvdb.clone();
+
+        let future = async move {
+            match remote_id {
+                RemoteId::Tvdb { id } => {
+                    let Some(update) = tvdb.series_last_modified(id).await? else {
+                        bail!("{series_id}/{remote_id}: missing last-modified in api");
+                    };
+
+                    tracing::trace!(?update, ?last_modified, ?series_id, ?remote_id,);
+
+                    if matches!(last_modified, Some(last_modified) if last_modified >= update) {
+                        return Ok(None);
+                    }
+
+                    let kind = TaskKind::DownloadSeries {
+                        series_id,
+                        remote_id,
+                        last_modified: Some(update),
+                        force: false,
+                    };
+
+                    Ok(Some(kind))
+                }
+                // Nothing to do with the IMDB remote.
+                remote_id => Ok(Some(TaskKind::DownloadSeries {
+                    series_id,
+                    remote_id,
+                    last_modified,
+                    force: false,
+                })),
+            }
+        };
+
+        future.in_current_span()
+    }
+
+    /// Push a single task to the queue.
+    pub(crate) fn push_task_without_delay(&mut self, kind: TaskKind) -> bool {
+        if !self.db.tasks.push_without_delay(kind) {
+            return false;
+        }
+
+        self.db.changes.change(Change::Queue);
+        true
+    }
+
+    /// Test if a completed task can be run again, which requires that what it
+    /// targets still exists and that no equivalent task is queued.
+    pub(crate) fn can_rerun_task(&self, kind: &TaskKind) -> bool {
+        let exists = match kind {
+            TaskKind::CheckForUpdates { series_id, .. }
+            | TaskKind::DownloadSeries { series_id, .. }
+            | TaskKind::ResolveEpisodeRemotes { series_id, .. } => {
+                self.db.series.get(series_id).is_some()
+            }
+            TaskKind::DownloadMovie { movie_id, .. } => self.db.movies.get(movie_id).is_some(),
+            TaskKind::DownloadSeriesByRemoteId { .. }
+            | TaskKind::DownloadMovieByRemoteId { .. } => true,
+        };
+
+        exists && self.task_status_any(kind.task_refs()).is_none()
+    }
+
+    /// Move a pending task up or down in the queue, returning if it was moved.
+    pub(crate) fn move_task(&mut self, id: TaskId, delta: isize) -> bool {
+        if !self.db.tasks.move_task(id, delta) {
+            return false;
+        }
+
+        self.db.changes.change(Change::Queue);
+        true
+    }
+
+    /// Run a completed task again right away, returning if it was queued.
+    pub(crate) fn rerun_task(&mut self, kind: &TaskKind) -> bool {
+        if !self.can_rerun_task(kind) {
+            return false;
+        }
+
+        self.push_task_without_delay(kind.clone())
+    }
+
+    /// Force a refresh of the given series right away, regardless of when it
+    /// was last synced.
+    ///
+    /// Any download of the series which is already queued is replaced, and
+    /// nothing happens if one is currently running.
+    pub(crate) fn refresh_series(&mut self, series_id: &SeriesId, remote_id: RemoteId) -> bool {
+        if let Some(TaskStatus::Running) = self.task_status(TaskRef::Series {
+            series_id: *series_id,
+        }) {
+            return false;
+        }
+
+        self.remove_tasks_by(|t| {
+            t.is_series(series_id)
+                && matches!(
+                    t.kind,
+                    TaskKind::DownloadSeries { .. } | TaskKind::CheckForUpdates { .. }
+                )
+        });
+
+        self.push_task_without_delay(TaskKind::DownloadSeries {
+            series_id: *series_id,
+            remote_id,
+            last_modified: None,
+            force: true,
+        })
+    }
+
+    /// Force a refresh of the given movie right away.
+    ///
+    /// Any download of the movie which is already queued is replaced, and
+    /// nothing happens if one is currently running.
+    pub(crate) fn refresh_movie(&mut self, movie_id: &MovieId, remote_id: RemoteId) -> bool {
+        if let Some(TaskStatus::Running) = self.task_status(TaskRef::Movie {
+            movie_id: *movie_id,
+        }) {
+            return false;
+        }
+
+        self.remove_tasks_by(|t| t.is_movie(movie_id));
+
+        self.push_task_without_delay(TaskKind::DownloadMovie {
+            movie_id: *movie_id,
+            remote_id,
+            last_modified: None,
+            force: true,
+        })
+    }
+
+    /// Remove all pending tasks matching the given predicate.
+    fn remove_tasks_by<P>(&mut self, predicate: P)
+    where
+        P: FnMut(&Task) -> bool,
+    {
+        if self.db.tasks.remove_tasks_by(predicate) > 0 {
+            self.db.changes.change(Change::Queue);
+        }
+    }
+
+    /// Add updates to download to the queue.
+    pub(crate) fn push_task(&mut self, now: &DateTime<Utc>, task: TaskKind) {
+        if self.db.tasks.push(now, task) {
+            self.db.changes.change(Change::Queue);
+        }
+    }
+
+    /// Mark the remaining aired episodes in a season as watched.
+    pub(crate) fn watch_remaining_season(
+        &mut self,
+        now: &DateTime<Utc>,
+        series_id: &SeriesId,
+        season: &SeasonNumber,
+        remaining_season: RemainingSeason,
+    ) {
+        self.watch_remaining(now, series_id, Some(season), remaining_season);
+    }
+
+    /// Mark the remaining aired episodes in every season of a series as
+    /// watched.
+    ///
+    /// Ignored specials are left alone.
+    pub(crate) fn watch_remaining_series(
+        &mut self,
+        now: &DateTime<Utc>,
+        series_id: &SeriesId,
+        remaining_season: RemainingSeason,
+    ) {
+        self.watch_remaining(now, series_id, None, remaining_season);
+    }
+
+    fn watch_remaining(
+        &mut self,
+        now: &DateTime<Utc>,
+        series_id: &SeriesId,
+        season: Option<&SeasonNumber>,
+        remaining_season: RemainingSeason,
+    ) {
+        let today = self.db.config.air_today(now);
+        let mut last = None;
+        let mut undo = self.undo_series(series_id);
+
+        for episode in self.db.episodes.by_series(series_id) {
+            match season {
+                Some(season) => {
+                    if episode.season != *season {
+                        continue;
+                    }
+                }
+                None => {
+                    if self.is_ignored_season(series_id, &episode.season) {
+                        continue;
+                    }
+                }
+            }
+
+            if self.watched_by_episode(&episode.id).len() > 0 {
+                continue;
+            }
+
+            // NB: only mark episodes which have actually aired.
+            if !episode.has_aired(&today) {
+                continue;
+            }
+
+            let Some(timestamp) = remaining_season.timestamp(now, episode.aired_timestamp()) else {
+                continue;
+            };
+
+            let id = WatchedId::random();
+
+            self.db.watched.insert(Watched {
+                id,
+                timestamp,
+                kind: WatchedKind::Series {
+                    series: *series_id,
+                    episode: episode.id,
+                },
+                rating: None,
+            });
+
+            self.db.changes.change(Change::Watched);
+            undo.added.push(id);
+            last = Some(episode.id);
+        }
+
+        if !undo.added.is_empty() {
+            self.push_undo(undo);
+        }
+
+        if let Some(last) = last {
+            let now = remaining_season.pending_timestamp(now);
+            self.populate_pending_from(&now, series_id, &last);
+        } else if self.db.pending.remove_series(series_id).is_some() {
+            self.db.changes.change(Change::Pending);
+        }
+    }
+
+    /// Capture what's needed to undo an action on a series.
+    fn undo_series(&self, series_id: &SeriesId) -> Undo {
+        Undo {
+            target: UndoTarget::Series(*series_id),
+            pending: self.db.pending.get(series_id).copied(),
+            next_season: self.db.series.get(series_id).and_then(|s| s.next_season),
+            current_episode: self
+                .db
+                .series
+                .get(series_id)
+                .and_then(|s| s.current_episode),
+            added: Vec::new(),
+            removed: Vec::new(),
+            skipped: None,
+        }
+    }
+
+    /// Capture what's needed to undo an action on a movie.
+    fn undo_movie(&self, movie_id: &MovieId) -> Undo {
+        Undo {
+            target: UndoTarget::Movie(*movie_id),
+            pending: self.db.pending.by_movie(movie_id).copied(),
+            next_season: None,
+            current_episode: None,
+            added: Vec::new(),
+            removed: Vec::new(),
+            skipped: None,
+        }
+    }
+
+    fn push_undo(&mut self, undo: Undo) {
+        if self.undo.len() >= UNDO_LIMIT {
+            self.undo.pop_front();
+        }
+
+        self.undo.push_back(undo);
+    }
+
+    /// Undo the most recent watch, skip or removal of a watch, returns `true`
+    /// if something was undone.
+    ///
+    /// This restores the watch history and what was pending from before the
+    /// action.
+    pub(crate) fn undo_last_action(&mut self) -> bool {
+        let Some(undo) = self.undo.pop_back() else {
+            return false;
+        };
+
+        for id in &undo.added {
+            self.db.watched.remove_watch(id);
+        }
+
+        for w in undo.removed {
+            self.db.watched.insert(w);
+        }
+
+        self.db.changes.change(Change::Watched);
+
+        match undo.target {
+            UndoTarget::Series(series_id) => {
+                self.db.pending.remove_series(&series_id);
+
+                if let Some(s) = self.db.series.get_mut(&series_id) {
+                    s.next_season = undo.next_season;
+                    s.current_episode = undo.current_episode;
+
+                    // NB: A skip which has since been made permanent is kept.
+                    if let Some(episode_id) = &undo.skipped {
+                        if s.skipped.get(episode_id) == Some(&Skip::Once) {
+                            s.skipped.remove(episode_id);
+                        }
+                    }
+
+                    self.db.changes.change(Change::Series);
+                }
+            }
+            UndoTarget::Movie(movie_id) => {
+                self.db.pending.remove_movie(&movie_id);
+            }
+        }
+
+        self.db.pending.extend(undo.pending);
+        self.db.changes.change(Change::Pending);
+        true
+    }
+
+    /// Mark an episode as watched.
+    #[tracing::instrument(skip(self))]
+    pub(crate) fn watch(
+        &mut self,
+        now: &DateTime<Utc>,
+        episode_id: &EpisodeId,
+        remaining_season: RemainingSeason,
+    ) {
+        tracing::trace!("Marking as watched");
+
+        let Some(episode) = self.db.episodes.get(episode_id) else {
+            tracing::warn!(?episode_id, "Episode missing");
+            return;
+        };
+
+        let Some(timestamp) = remaining_season.timestamp(now, episode.aired_timestamp()) else {
+            return;
+        };
+
+        let series = *episode.series();
+        let episode = episode.id;
+        let id = WatchedId::random();
+
+        let mut undo = self.undo_series(&series);
+        undo.added.push(id);
+        self.push_undo(undo);
+
+        self.db.watched.insert(Watched {
+            id,
+            timestamp,
+            kind: WatchedKind::Series { series, episode },
+            rating: None,
+        });
+
+        self.db.changes.change(Change::Watched);
+        let now = remaining_season.pending_timestamp(now);
+        self.populate_pending_from(&now, &series, &episode);
+    }
+
+    /// Mark an episode as watched.
+    #[tracing::instrument(skip(self))]
+    pub(crate) fn watch_movie(
+        &mut self,
+        now: &DateTime<Utc>,
+        movie: &MovieId,
+        remaining_season: RemainingSeason,
+    ) {
+        tracing::trace!("Marking as watched");
+
+        let Some(m) = self.db.movies.get(movie) else {
+            tracing::warn!(?movie, "Movie missing");
+            return;
+        };
+
+        let Some(timestamp) = remaining_season.timestamp(now, m.release()) else {
+            return;
+        };
+
+        let id = WatchedId::random();
+
+        self.db.watched.insert(Watched {
+            id,
+            timestamp,
+            kind: WatchedKind::Movie { movie: m.id },
+            rating: None,
+        });
+
+        let mut undo = self.undo_movie(movie);
+        undo.added.push(id);
+        self.push_undo(undo);
+
+        self.db.changes.change(Change::Watched);
+
+        if self.db.pending.remove_movie(movie).is_some() {
+            self.db.changes.change(Change::Pending);
+        }
+    }
+
+    /// Get all movies belonging to the given collection.
+    pub(crate) fn movies_by_collection<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = &'a Movie> + 'a {
+        self.db
+            .movies
+            .iter_by_name()
+            .filter(move |m| m.collection.as_deref() == Some(name))
+    }
+
+    /// Mark every released movie in a collection as watched.
+    ///
+    /// Movies which have already been watched are left alone.
+    #[tracing::instrument(skip(self))]
+    pub(crate) fn watch_collection(
+        &mut self,
+        now: &DateTime<Utc>,
+        name: &str,
+        remaining_season: RemainingSeason,
+    ) {
+        let today = self.db.config.air_today(now);
+
+        let movies = self
+            .movies_by_collection(name)
+            .filter(|m| m.has_released(&today))
+            .filter(|m| self.watched_by_movie(&m.id).len() == 0)
+            .map(|m| m.id)
+            .collect::<Vec<_>>();
+
+        tracing::trace!(count = movies.len(), "Watching collection");
+
+        for movie_id in movies {
+            self.watch_movie(now, &movie_id, remaining_season);
+        }
+    }
+
+    /// Skip an episode.
+    #[tracing::instrument(skip(self))]
+    pub(crate) fn skip(&mut self, now: &DateTime<Utc>, series_id: &SeriesId, id: &EpisodeId) {
+        tracing::trace!("Skipping episode");
+
+        let mut undo = self.undo_series(series_id);
+
+        if let Some(series) = self.db.series.get_mut(series_id) {
+            // NB: Only a newly added skip is removed when undoing.
+            if let btree_map::Entry::Vacant(e) = series.skipped.entry(*id) {
+                e.insert(Skip::Once);
+                self.db.changes.change(Change::Series);
+                undo.skipped = Some(*id);
+            }
+        }
+
+        self.push_undo(undo);
+
+        self.populate_pending_from(now, series_id, id);
+    }
+
+    /// Permanently skip an episode, or stop skipping it.
+    ///
+    /// Unlike [`Service::skip`] this is kept regardless of what's pending, and
+    /// the episode counts as watched.
+    #[tracing::instrument(skip(self))]
+    pub(crate) fn mark_episode_skipped(
+        &mut self,
+        now: &DateTime<Utc>,
+        episode_id: &EpisodeId,
+        skipped: bool,
+    ) {
+        let Some(e) = self.db.episodes.get(episode_id) else {
+            return;
+        };
+
+        let series_id = *e.series();
+
+        let Some(series) = self.db.series.get_mut(&series_id) else {
+            return;
+        };
+
+        let changed = if skipped {
+            series.skipped.insert(*episode_id, Skip::Permanent) != Some(Skip::Permanent)
+        } else if series.skipped.get(episode_id) == Some(&Skip::Permanent) {
+            series.skipped.remove(episode_id).is_some()
+        } else {
+            false
+        };
+
+        if !changed {
+            return;
+        }
+
+        self.db.changes.change(Change::Series);
+
+        let is_pending = matches!(
+            self.pending_ref_by_series(&series_id),
+            Some(PendingRef::Episode { episode, .. }) if episode.id == *episode_id
+        );
+
+        if skipped && is_pending {
+            self.populate_pending_from(now, &series_id, episode_id);
+        }
+    }
+
+    /// Skip an episode.
+    #[tracing::instrument(skip(self))]
+    pub(crate) fn skip_movie(&mut self, now: &DateTime<Utc>, id: &MovieId) {
+        tracing::trace!("Skipping movie");
+
+        let undo = self.undo_movie(id);
+
+        if self.db.pending.remove_movie(id).is_some() {
+            self.db.changes.change(Change::Pending);
+            self.push_undo(undo);
+        }
+    }
+
+    /// Select the next pending episode to use for a show.
+    #[tracing::instrument(skip(self))]
+    pub(crate) fn select_pending(&mut self, now: &DateTime<Utc>, episode_id: &EpisodeId) {
+        tracing::trace!("Selecting pending series");
+
+        let Some(episode) = self.db.episodes.get(episode_id) else {
+            tracing::warn!("Episode missing");
+            return;
+        };
+
+        let aired = self
+            .db
+            .episodes
+            .get(episode_id)
+            .and_then(|e| e.aired_timestamp());
+
+        let timestamp = self
+            .db
+            .watched
+            .by_series(episode.series())
+            .next_back()
+            .map(|w| w.timestamp);
+
+        self.db.pending.extend([Pending {
+            timestamp: pending_timestamp(now, &[timestamp, aired]),
+            kind: PendingKind::Episode {
+                series: *episode.series(),
+                episode: episode.id,
+            },
+            snoozed_until: None,
+        }]);
+
+        self.db.changes.change(Change::Pending);
+    }
+
+    /// Select the next pending movie.
+    #[tracing::instrument(skip(self))]
+    pub(crate) fn select_pending_movie(&mut self, now: &DateTime<Utc>, movie_id: &MovieId) {
+        tracing::trace!("Selecting pending movie");
+
+        let m = self.db.movies.get(movie_id);
+        let earliest = m.and_then(|m| m.earliest());
+
+        if self.db.watched.by_movie(movie_id).len() == 0 {
+            let timestamp = pending_timestamp(now, &[earliest]);
+
+            self.db.pending.extend([Pending {
+                timestamp,
+                kind: PendingKind::Movie { movie: *movie_id },
+                snoozed_until: None,
+            }]);
+
+            self.db.changes.change(Change::Pending);
+        }
+    }
+
+    /// Set the episode to watch next for a series without marking any other
+    /// episodes as watched.
+    ///
+    /// The episode is kept as the next one to watch until it has been watched
+    /// or is explicitly changed.
+    #[tracing::instrument(skip(self))]
+    pub(crate) fn set_current_episode(
+        &mut self,
+        now: &DateTime<Utc>,
+        series_id: &SeriesId,
+        episode_id: &EpisodeId,
+    ) {
+        if !matches!(self.db.episodes.get(episode_id), Some(e) if e.series() == series_id) {
+            tracing::warn!("Episode missing from series");
+            return;
+        }
+
+        let Some(s) = self.db.series.get_mut(series_id) else {
+            tracing::warn!("Series missing");
+            return;
+        };
+
+        s.current_episode = Some(*episode_id);
+        s.next_season = None;
+        self.db.changes.change(Change::Series);
+        self.select_pending(now, episode_id);
+    }
+
+    /// Get the episode which has been manually set as the next one to watch
+    /// for a series, clearing it if it has been watched, skipped, is otherwise
+    /// passed over or no longer exists.
+    fn current_episode(&mut self, series_id: &SeriesId) -> Option<EpisodeId> {
+        let id = self.db.series.get(series_id)?.current_episode?;
+
+        if let Some(e) = self.db.episodes.get(&id) {
+            if self.episode_skip(&e).is_none()
+                && !self.is_skipped_filler(&e)
+                && !self.is_ignored_special(&e)
+                && self.db.watched.by_episode(&id).len() == 0
+            {
+                return Some(id);
+            }
+        }
+
+        tracing::trace!(?id, "Clearing current episode");
+
+        if let Some(s) = self.db.series.get_mut(series_id) {
+            s.current_episode = None;
+            self.db.changes.change(Change::Series);
+        }
+
+        None
+    }
+
+    /// Clear next episode as pending.
+    #[tracing::instrument(skip(self))]
+    pub(crate) fn clear_pending(&mut self, episode_id: &EpisodeId) {
+        tracing::trace!("Clearing pending");
+
+        self.db.changes.change(Change::Pending);
+
+        if let Some(e) = self.db.episodes.get(episode_id) {
+            let series_id = *e.series();
+            self.db.pending.remove_series(&series_id);
+
+            if let Some(s) = self.db.series.get_mut(&series_id) {
+                if s.current_episode.take().is_some() {
+                    self.db.changes.change(Change::Series);
+                }
+            }
+        }
+    }
+
+    /// Clear next episode as pending.
+    #[tracing::instrument(skip(self))]
+    pub(crate) fn clear_pending_movie(&mut self, movie_id: &MovieId) {
+        tracing::trace!("Clearing pending movie");
+
+        self.db.changes.change(Change::Pending);
+        self.db.pending.remove_movie(movie_id);
+    }
+
+    /// Set the rating of a watch, which is clamped to be between 1 and 10.
+    pub(crate) fn set_watch_rating(&mut self, watch_id: &WatchedId, rating: Option<u8>) {
+        let Some(w) = self.db.watched.get_mut(watch_id) else {
+            return;
+        };
+
+        w.rating = rating.map(|r| r.clamp(1, 10));
+        self.db.changes.change(Change::Watched);
+    }
+
+    /// Remove a watch of the given episode.
+    #[tracing::instrument(skip(self))]
+    pub(crate) fn remove_episode_watch(&mut self, episode_id: &EpisodeId, watch_id: &WatchedId) {
+        tracing::trace!("Removing episode watch");
+
+        let Some(w) = self.db.watched.remove_watch(watch_id) else {
+            tracing::warn!("Watch missing");
+            return;
+        };
+
+        if let WatchedKind::Series { series, .. } = &w.kind {
+            let mut undo = self.undo_series(series);
+            undo.removed.push(w);
+            self.push_undo(undo);
+        }
+
+        self.db.changes.change(Change::Watched);
+
+        if let Some(e) = self.db.episodes.get(episode_id) {
+            if self.db.watched.by_episode(&e.id).len() == 0 {
+                self.db.pending.extend([Pending {
+                    timestamp: w.timestamp,
+                    kind: PendingKind::Episode {
+                        series: *e.series(),
+                        episode: e.id,
+                    },
+                    snoozed_until: None,
+                }]);
+
+                self.db.changes.change(Change::Pending);
+            }
+        }
+    }
+
+    /// Remove a single watch for the given movie.
+    #[tracing::instrument(skip(self))]
+    pub(crate) fn remove_movie_watch(&mut self, movie_id: &MovieId, watch_id: &WatchedId) {
+        tracing::trace!("Removing episode watch");
+
+        let Some(w) = self.db.watched.remove_watch(watch_id) else {
+            tracing::warn!("Watch missing");
+            return;
+        };
+
+        let mut undo = self.undo_movie(movie_id);
+        undo.removed.push(w);
+        self.push_undo(undo);
+
+        self.db.changes.change(Change::Watched);
+        // if let Some(m) = self.db.movies.get(movie_id) {
+        //     if self.db.watched.by_movie(&m.id).len() == 0 {
+        //         self.db.pending.extend([Pending {
+        //             series: *m.series(),
+        //             episode: m.id,
+        //             timestamp: w.timestamp,
+        //         }]);
+
+        //         self.db.changes.change(Change::Pending);
+        //     }
+        // }
+    }
+
+    /// Remove all watches of the given episode.
+    #[tracing::instrument(skip(self))]
+    pub(crate) fn remove_season_watches(
+        &mut self,
+        now: &DateTime<Utc>,
+        series_id: &SeriesId,
+        season: &SeasonNumber,
+    ) {
+        tracing::trace!("Removing season watches");
+
+        let mut removed = 0;
+
+        for e in self.db.episodes.by_series(series_id) {
+            if e.season == *season {
+                removed += self.db.watched.remove_by_episode(&e.id);
+            }
+        }
+
+        if removed == 0 {
+            return;
+        }
+
+        self.db.changes.change(Change::Watched);
+
+        if self.db.pending.remove_series(series_id).is_some() {
+            self.db.changes.change(Change::Pending);
+        }
+
+        // Find the first episode matching the cleared season.
+        let Some(e) = self
+            .db
+            .episodes
+            .by_series(series_id)
+            .find(|e| e.season == *season)
+        else {
+            self.populate_pending(now, series_id);
+            return;
+        };
+
+        let timestamp = self
+            .db
+            .watched
+            .by_series(series_id)
+            .next_back()
+            .map(|w| w.timestamp);
+
+        self.db.pending.extend([Pending {
+            timestamp: pending_timestamp(now, &[timestamp, e.aired_timestamp()]),
+            kind: PendingKind::Episode {
+                series: *series_id,
+                episode: e.id,
+            },
+            snoozed_until: None,
+        }]);
+
+        self.db.changes.change(Change::Pending);
+    }
+
+    /// Save changes made.
+    #[tracing::instrument(skip(self))]
+    pub fn save_changes(&mut self) -> impl Future<Output = Result<()>> {
+        if [
+            Change::Series,
+            Change::Movie,
+            Change::Watched,
+            Change::Config,
+            Change::Schedule,
+        ]
+        .into_iter()
+        .any(|c| self.db.changes.contains(c))
+        {
+            self.rebuild_schedule();
+        }
+
+        let save = self.db.save_changes(&self.paths, self.do_not_save);
+
+        let images = (!self.do_not_save).then(|| {
+            let mut images = self.images.lock();
+
+            let evicted = match self.db.config.image_cache_max_bytes() {
+                Some(max_bytes) => images.evict(max_bytes),
+                None => Vec::new(),
+            };
+
+            (evicted, images.take_modified())
+        });
+
+        let dir = self.paths.images.to_path_buf();
+
+        let future = async move {
+            save.await?;
+
+            if let Some((evicted, index)) = images {
+                cache::save_index(dir, evicted, index?).await?;
+            }
+
+            Ok(())
+        };
+
+        future.in_current_span()
+    }
+
+    /// Populate pending from a series where we don't know which episode to
+    /// populate from.
+    #[tracing::instrument(skip(self))]
+    pub(crate) fn populate_pending(&mut self, now: &DateTime<Utc>, id: &SeriesId) {
+        tracing::trace!("Populate pending");
+
+        if let Some(pending) = self.db.pending.get(id) {
+            // Do nothing since we already have a pending episode.
+            tracing::trace!(?pending, "pending exists");
+            return;
+        }
+
+        if let Some(current) = self.current_episode(id) {
+            tracing::trace!(?current, "Using current episode");
+            self.select_pending(now, &current);
+            return;
+        }
+
+        if self
+            .db
+            .series
+            .get(id)
+            .is_some_and(|s| s.next_season.is_some())
+        {
+            tracing::trace!("Waiting for next season to be started");
+            return;
+        }
+
+        let last = self.db.watched.by_series(id).next_back();
+        let order = self.watch_order(id);
+
+        let mut cur = if let Some(WatchedKind::Series { episode, .. }) = last.map(|w| &w.kind) {
+            tracing::trace!(?episode, "Episode after watched");
+            self.next_episode(order.as_deref(), episode)
+        } else {
+            tracing::trace!("Finding next unwatched episode");
+
+            match &order {
+                Some(order) => order.first().and_then(|id| self.db.episodes.get(id)),
+                None => self.db.episodes.by_series(id).next(),
+            }
+        };
+
+        while let Some(e) = cur {
+            let listed = order.as_ref().is_some_and(|o| o.contains(&e.id));
+
+            if (listed || !e.season.is_special())
+                && !self.is_skipped_filler(&e)
+                && !self.is_permanently_skipped(&e)
+                && !self.is_ignored_special(&e)
+                && self.db.watched.by_episode(&e.id).len() == 0
+            {
+                break;
+            }
+
+            cur = self.next_episode(order.as_deref(), &e.id);
+        }
+
+        let Some(e) = cur else {
+            return;
+        };
+
+        if self.is_finished_from(order.as_deref(), e) {
+            tracing::trace!(episode = ?e.id, "Nothing upcoming");
+            return;
+        }
+
+        tracing::trace!(episode = ?e.id, "Set pending");
+
+        let timestamp = pending_timestamp(now, &[last.map(|w| w.timestamp), e.aired_timestamp()]);
+        let episode = e.id;
+
+        self.db.changes.change(Change::Pending);
+        // Mark the next episode in the show as pending.
+        self.db.pending.extend([Pending {
+            timestamp,
+            kind: PendingKind::Episode {
+                series: *id,
+                episode,
+            },
+            snoozed_until: None,
+        }]);
+    }
+
+    /// Populate pending from a known episode ID.
+    fn populate_pending_from(&mut self, now: &DateTime<Utc>, series_id: &SeriesId, id: &EpisodeId) {
+        // NB: A manually set episode stays pending until it has been watched.
+        if let Some(current) = self.current_episode(series_id) {
+            tracing::trace!(?current, "Using current episode");
+            self.select_pending(now, &current);
+            return;
+        }
+
+        // NB: Moving on from an episode supersedes a season waiting to be
+        // started, it's detected again below if this is a season end.
+        if let Some(s) = self.db.series.get_mut(series_id) {
+            if s.next_season.take().is_some() {
+                self.db.changes.change(Change::Series);
+            }
+        }
+
+        let order = self.watch_order(series_id);
+
+        let mut cur = self.next_episode(order.as_deref(), id);
+
+        while let Some(e) = cur.filter(|e| {
+            self.is_skipped_filler(e)
+                || self.is_permanently_skipped(e)
+                || self.is_ignored_special(e)
+        }) {
+            cur = self.next_episode(order.as_deref(), &e.id);
+        }
+
+        // NB: A series is finished if there are no more episodes, or if the
+        // remaining ones don't have an air date yet. Episodes which will air at
+        // a known date are kept pending while waiting between seasons.
+        let Some(e) = cur.filter(|e| !self.is_finished_from(order.as_deref(), *e)) else {
+            if self.db.pending.remove_series(series_id).is_some() {
+                self.db.changes.change(Change::Pending);
+            }
+
+            return;
+        };
+
+        if self.db.config.confirm_next_season && self.is_season_end(id, &e) {
+            tracing::trace!(episode = ?e.id, "Season finished, waiting for next season");
+
+            let episode = e.id;
+
+            if let Some(s) = self.db.series.get_mut(series_id) {
+                s.next_season = Some(NextSeason {
+                    episode,
+                    declined: false,
+                });
+
+                self.db.changes.change(Change::Series);
+            }
+
+            if self.db.pending.remove_series(series_id).is_some() {
+                self.db.changes.change(Change::Pending);
+            }
+
+            return;
+        }
+
+        let timestamp = e.aired_timestamp().map(|t| t.max(*now)).unwrap_or(*now);
+        let episode = e.id;
+
+        self.db.changes.change(Change::Pending);
+
+        self.db.pending.extend([Pending {
+            timestamp,
+            kind: PendingKind::Episode {
+                series: *series_id,
+                episode,
+            },
+            snoozed_until: None,
+        }]);
+    }
+
+    /// Test if going from the given episode to the next one crosses from one
+    /// regular season into another.
+    fn is_season_end(&self, id: &EpisodeId, next: &Episode) -> bool {
+        let Some(e) = self.db.episodes.get(id) else {
+            return false;
+        };
+
+        !e.season.is_special() && !next.season.is_special() && e.season != next.season
+    }
+
+    /// Series which have finished a season and are asking to start the next
+    /// one.
+    pub(crate) fn next_season_prompts(&self) -> impl Iterator<Item = (&Series, EpisodeRef<'_>)> {
+        self.db
+            .series
+            .iter_by_name()
+            .filter(|s| s.removed.is_none())
+            .filter_map(|s| {
+                let next = s.next_season.filter(|n| !n.declined)?;
+                Some((s, self.db.episodes.get(&next.episode)?))
+            })
+    }
+
+    /// Start the next season of a series which is waiting for it, making its
+    /// first episode the next one to watch.
+    pub(crate) fn start_next_season(&mut self, now: &DateTime<Utc>, series_id: &SeriesId) {
+        let Some(next) = self
+            .db
+            .series
+            .get_mut(series_id)
+            .and_then(|s| s.next_season.take())
+        else {
+            return;
         };
 
-        future.in_current_span()
+        self.db.changes.change(Change::Series);
+        self.select_pending(now, &next.episode);
     }
 
-    /// Push a single task to the queue.
-    pub(crate) fn push_task_without_delay(&mut self, kind: TaskKind) -> bool {
-        self.db.tasks.push_without_delay(kind)
+    /// Decline starting the next season of a series, which keeps it out of
+    /// what to watch next until it's started.
+    pub(crate) fn decline_next_season(&mut self, series_id: &SeriesId) {
+        if let Some(next) = self
+            .db
+            .series
+            .get_mut(series_id)
+            .and_then(|s| s.next_season.as_mut())
+        {
+            next.declined = true;
+            self.db.changes.change(Change::Series);
+        }
     }
 
-    /// Add updates to download to the queue.
-    pub(crate) fn push_task(&mut self, now: &DateTime<Utc>, task: TaskKind) {
-        self.db.tasks.push(now, task);
+    /// Test if nothing is upcoming from the given episode onwards, in that
+    /// none of the remaining episodes have an air date, when finished series
+    /// are removed from what to watch next.
+    fn is_finished_from(&self, order: Option<&[EpisodeId]>, e: EpisodeRef<'_>) -> bool {
+        if !self.db.config.remove_finished_pending {
+            return false;
+        }
+
+        let mut cur = Some(e);
+
+        while let Some(e) = cur {
+            if e.aired.is_some() {
+                return false;
+            }
+
+            cur = self.next_episode(order, &e.id);
+        }
+
+        true
     }
 
-    /// Mark an episode as watched at the given timestamp.
-    pub(crate) fn watch_remaining_season(
-        &mut self,
-        now: &DateTime<Utc>,
-        series_id: &SeriesId,
-        season: &SeasonNumber,
-        remaining_season: RemainingSeason,
-    ) {
-        let today = now.date_naive();
-        let mut last = None;
+    /// Set whether finished series are removed from what to watch next.
+    pub(crate) fn set_remove_finished_pending(&mut self, value: bool) {
+        self.db.config.remove_finished_pending = value;
+        self.db.changes.change(Change::Config);
+
+        if !value {
+            return;
+        }
 
-        for episode in self
+        let pending = self
             .db
-            .episodes
-            .by_series(series_id)
-            .filter(|e| e.season == *season)
-        {
-            if self.watched_by_episode(&episode.id).len() > 0 {
-                continue;
-            }
+            .pending
+            .iter()
+            .filter_map(|p| match p.kind {
+                PendingKind::Episode { series, episode } => Some((series, episode)),
+                PendingKind::Movie { .. } => None,
+            })
+            .collect::<Vec<_>>();
 
-            // NB: only mark episodes which have actually aired.
-            if !episode.has_aired(&today) {
+        for (series_id, episode_id) in pending {
+            let order = self.watch_order(&series_id);
+
+            let Some(e) = self.db.episodes.get(&episode_id) else {
                 continue;
+            };
+
+            if self.is_finished_from(order.as_deref(), e) {
+                self.db.pending.remove_series(&series_id);
+                self.db.changes.change(Change::Pending);
             }
+        }
+    }
 
-            let timestamp = match remaining_season {
-                RemainingSeason::Aired => *now,
-                RemainingSeason::AirDate => {
-                    let Some(air_date) = episode.aired_timestamp() else {
-                        continue;
-                    };
+    /// Test if the given episode should be passed over when picking what to
+    /// watch next.
+    fn is_skipped_filler(&self, e: &Episode) -> bool {
+        self.db.config.skip_filler && e.kind.is_some()
+    }
 
-                    air_date
-                }
-            };
+    /// Get how the given episode has been skipped, if at all.
+    fn episode_skip(&self, e: &EpisodeRef<'_>) -> Option<Skip> {
+        self.db.series.get(e.series())?.skipped.get(&e.id).copied()
+    }
 
-            self.db.watched.insert(Watched {
-                id: WatchedId::random(),
-                timestamp,
-                kind: WatchedKind::Series {
-                    series: *series_id,
-                    episode: episode.id,
-                },
-            });
+    /// Test if the given episode has been permanently skipped.
+    pub(crate) fn is_permanently_skipped(&self, e: &EpisodeRef<'_>) -> bool {
+        matches!(self.episode_skip(e), Some(Skip::Permanent))
+    }
 
-            self.db.changes.change(Change::Watched);
-            last = Some(episode.id);
+    /// Test if the given episode is a special which is ignored.
+    fn is_ignored_special(&self, e: &EpisodeRef<'_>) -> bool {
+        self.is_ignored_season(e.series(), &e.season)
+    }
+
+    /// Test if the given season is ignored, which is the case for specials of
+    /// series which ignore them or when specials are excluded altogether.
+    pub(crate) fn is_ignored_season(&self, series_id: &SeriesId, season: &SeasonNumber) -> bool {
+        season.is_special()
+            && (!self.db.config.include_specials
+                || self
+                    .db
+                    .series
+                    .get(series_id)
+                    .is_some_and(|s| s.ignore_specials))
+    }
+
+    /// Set the notes of a series.
+    pub(crate) fn set_series_notes(&mut self, series_id: &SeriesId, notes: String) {
+        let Some(s) = self.db.series.get_mut(series_id) else {
+            return;
+        };
+
+        if s.notes != notes {
+            s.notes = notes;
+            self.db.changes.change(Change::Series);
         }
+    }
 
-        if let Some(last) = last {
-            self.populate_pending_from(now, series_id, &last);
-        } else if self.db.pending.remove_series(series_id).is_some() {
+    /// Set the notes of a movie.
+    pub(crate) fn set_movie_notes(&mut self, movie_id: &MovieId, notes: String) {
+        let Some(m) = self.db.movies.get_mut(movie_id) else {
+            return;
+        };
+
+        if m.notes != notes {
+            m.notes = notes;
+            self.db.changes.change(Change::Movie);
+        }
+    }
+
+    /// Toggle whether a series is a favorite.
+    pub(crate) fn toggle_favorite(&mut self, series_id: &SeriesId) {
+        let Some(s) = self.db.series.get_mut(series_id) else {
+            return;
+        };
+
+        s.favorite = !s.favorite;
+        self.db.changes.change(Change::Series);
+    }
+
+    /// Set whether a series ignores its specials, picking what to watch next
+    /// again if it's a special.
+    pub(crate) fn set_ignore_specials(
+        &mut self,
+        now: &DateTime<Utc>,
+        series_id: &SeriesId,
+        ignore_specials: bool,
+    ) {
+        let Some(s) = self.db.series.get_mut(series_id) else {
+            return;
+        };
+
+        s.ignore_specials = ignore_specials;
+        self.db.changes.change(Change::Series);
+
+        let is_special = matches!(
+            self.db.pending.get(series_id).map(|p| &p.kind),
+            Some(PendingKind::Episode { episode, .. })
+                if self.db.episodes.get(episode).is_some_and(|e| e.season.is_special())
+        );
+
+        if ignore_specials && is_special && self.db.pending.remove_series(series_id).is_some() {
             self.db.changes.change(Change::Pending);
         }
+
+        self.populate_pending(now, series_id);
+        self.rebuild_schedule();
     }
 
-    /// Mark an episode as watched.
+    /// Set the kind of an episode.
     #[tracing::instrument(skip(self))]
-    pub(crate) fn watch(
+    pub(crate) fn set_episode_kind(
         &mut self,
         now: &DateTime<Utc>,
         episode_id: &EpisodeId,
-        remaining_season: RemainingSeason,
+        kind: Option<EpisodeKind>,
     ) {
-        tracing::trace!("Marking as watched");
+        let Some(e) = self.db.episodes.get_mut(episode_id) else {
+            tracing::warn!("Episode missing");
+            return;
+        };
 
-        let Some(episode) = self.db.episodes.get(episode_id) else {
-            tracing::warn!(?episode_id, "Episode missing");
+        if e.kind == kind {
+            return;
+        }
+
+        e.kind = kind;
+
+        let Some(series_id) = self.db.episodes.get(episode_id).map(|e| *e.series()) else {
             return;
         };
 
-        let timestamp = match remaining_season {
-            RemainingSeason::Aired => *now,
-            RemainingSeason::AirDate => {
-                let Some(air_date) = episode.aired_timestamp() else {
-                    return;
-                };
+        self.db.changes.add_series(&series_id);
+
+        let is_pending = matches!(
+            self.db.pending.get(&series_id).map(|p| &p.kind),
+            Some(PendingKind::Episode { episode, .. }) if episode == episode_id
+        );
+
+        if is_pending && self.db.config.skip_filler && kind.is_some() {
+            self.populate_pending_from(now, &series_id, episode_id);
+        }
+    }
+
+    /// Set the country whose release dates are preferred for movies,
+    /// rebuilding the release dates of existing movies.
+    pub(crate) fn set_movie_release_country(&mut self, country: String) {
+        if self.db.config.movie_release_country == country {
+            return;
+        }
+
+        for movie in self.db.movies.iter_mut() {
+            movie.rebuild_earliest_releases(Some(&country));
+        }
 
-                air_date
+        self.db.config.movie_release_country = country;
+        self.db.changes.change(Change::Config);
+        self.db.changes.change(Change::Movie);
+        self.rebuild_schedule();
+    }
+
+    /// Set how far ahead the schedule looks.
+    pub(crate) fn set_schedule_window(&mut self, window: ScheduleWindow) {
+        self.db.config.schedule_window = window;
+        self.db.changes.change(Change::Config);
+        self.rebuild_schedule();
+    }
+
+    /// Set whether flagged episodes are passed over when picking what to watch
+    /// next, moving any pending flagged episodes along.
+    pub(crate) fn set_skip_filler(&mut self, now: &DateTime<Utc>, skip_filler: bool) {
+        self.db.config.skip_filler = skip_filler;
+        self.db.changes.change(Change::Config);
+
+        if !skip_filler {
+            return;
+        }
+
+        let flagged = self
+            .db
+            .pending
+            .iter()
+            .filter_map(|p| match p.kind {
+                PendingKind::Episode { series, episode } => Some((series, episode)),
+                PendingKind::Movie { .. } => None,
+            })
+            .filter(|(_, episode)| {
+                self.db
+                    .episodes
+                    .get(episode)
+                    .is_some_and(|e| e.kind.is_some())
+            })
+            .collect::<Vec<_>>();
+
+        for (series_id, episode_id) in flagged {
+            self.populate_pending_from(now, &series_id, &episode_id);
+        }
+    }
+
+    /// Set whether specials are included in what to watch next and the
+    /// schedule, moving pending specials on to the next episode when they're
+    /// excluded.
+    pub(crate) fn set_include_specials(&mut self, now: &DateTime<Utc>, include_specials: bool) {
+        self.db.config.include_specials = include_specials;
+        self.db.changes.change(Change::Config);
+
+        if !include_specials {
+            let specials = self
+                .db
+                .pending
+                .iter()
+                .filter_map(|p| match p.kind {
+                    PendingKind::Episode { series, episode } => Some((series, episode)),
+                    PendingKind::Movie { .. } => None,
+                })
+                .filter(|(_, episode)| {
+                    self.db
+                        .episodes
+                        .get(episode)
+                        .is_some_and(|e| e.season.is_special())
+                })
+                .collect::<Vec<_>>();
+
+            for (series_id, episode_id) in specials {
+                self.populate_pending_from(now, &series_id, &episode_id);
+            }
+        }
+
+        self.rebuild_schedule();
+    }
+
+    /// Get the full custom watch order of a series, if it has one.
+    ///
+    /// This consists of the listed episodes followed by every unlisted episode
+    /// in its default order.
+    fn watch_order(&self, series_id: &SeriesId) -> Option<Vec<EpisodeId>> {
+        let series = self.db.series.get(series_id)?;
+
+        if series.watch_order.is_empty() {
+            return None;
+        }
+
+        let episodes = self
+            .db
+            .episodes
+            .by_series(series_id)
+            .map(|e| e.id)
+            .collect::<Vec<_>>();
+
+        let mut order = series
+            .watch_order
+            .iter()
+            .filter(|id| episodes.contains(id))
+            .copied()
+            .collect::<Vec<_>>();
+
+        for id in episodes {
+            if !series.watch_order.contains(&id) {
+                order.push(id);
             }
+        }
+
+        Some(order)
+    }
+
+    /// Get the episode following the given one, using the custom watch order
+    /// if one is specified.
+    fn next_episode(&self, order: Option<&[EpisodeId]>, id: &EpisodeId) -> Option<EpisodeRef<'_>> {
+        let Some(order) = order else {
+            return self.db.episodes.get(id)?.next();
         };
 
-        let series = *episode.series();
-        let episode = episode.id;
+        let n = order.iter().position(|e| e == id)?;
+        self.db.episodes.get(order.get(n + 1)?)
+    }
 
-        self.db.watched.insert(Watched {
-            id: WatchedId::random(),
-            timestamp,
-            kind: WatchedKind::Series { series, episode },
-        });
+    /// Import a custom watch order for a series from the given path.
+    pub fn import_watch_order(&mut self, series_id: &str, path: &Path) -> Result<()> {
+        let series_id = series_id.parse::<SeriesId>().context("bad series id")?;
 
-        self.db.changes.change(Change::Watched);
-        self.populate_pending_from(now, &series, &episode);
+        if self.db.series.get(&series_id).is_none() {
+            bail!("No series with id {series_id}");
+        }
+
+        let input = std::fs::read_to_string(path).with_context(|| anyhow!("{}", path.display()))?;
+        let entries = parse_watch_order(&input).with_context(|| anyhow!("{}", path.display()))?;
+
+        let mut order = Vec::with_capacity(entries.len());
+
+        for entry in entries {
+            let id = match entry {
+                WatchOrderEntry::Number { season, number } => self
+                    .db
+                    .episodes
+                    .by_series(&series_id)
+                    .find(|e| e.season == season && e.number == number)
+                    .map(|e| e.id),
+                WatchOrderEntry::Remote(remote_id) => self
+                    .db
+                    .remotes
+                    .proxy()
+                    .find_episode_by_remote(remote_id)
+                    .filter(|id| {
+                        self.db
+                            .episodes
+                            .get(id)
+                            .is_some_and(|e| *e.series() == series_id)
+                    }),
+            };
+
+            let Some(id) = id else {
+                tracing::warn!(?entry, "No matching episode in series");
+                continue;
+            };
+
+            if !order.contains(&id) {
+                order.push(id);
+            }
+        }
+
+        tracing::info!(count = order.len(), "Imported watch order for {series_id}");
+        self.set_watch_order(&Utc::now(), &series_id, order);
+        Ok(())
     }
 
-    /// Mark an episode as watched.
-    #[tracing::instrument(skip(self))]
-    pub(crate) fn watch_movie(
+    /// Set the custom watch order of a series, an empty order clears it.
+    pub(crate) fn set_watch_order(
         &mut self,
         now: &DateTime<Utc>,
-        movie: &MovieId,
-        remaining_season: RemainingSeason,
+        series_id: &SeriesId,
+        order: Vec<EpisodeId>,
     ) {
-        tracing::trace!("Marking as watched");
+        let Some(series) = self.db.series.get_mut(series_id) else {
+            return;
+        };
+
+        series.watch_order = order;
+        self.db.changes.change(Change::Series);
+
+        if self.db.pending.remove_series(series_id).is_some() {
+            self.db.changes.change(Change::Pending);
+        }
+
+        self.populate_pending(now, series_id);
+    }
+
+    /// Get current configuration.
+    pub(crate) fn config(&self) -> &Config {
+        &self.db.config
+    }
+
+    /// Get configuration mutably indicating that it has been changed.
+    pub(crate) fn config_mut(&mut self) -> &mut Config {
+        self.db.changes.change(Change::Config);
+        &mut self.db.config
+    }
+
+    /// Notes about data which was upgraded when the database was loaded.
+    pub(crate) fn migration_notes(&self) -> &[&'static str] {
+        &self.db.migration_notes
+    }
+
+    /// Dismiss any notes about upgraded data.
+    pub(crate) fn dismiss_migration_notes(&mut self) {
+        self.db.migration_notes.clear();
+    }
+
+    /// Set the number of hours after the air date until episodes are
+    /// considered aired.
+    pub(crate) fn set_air_grace_hours(&mut self, hours: u32) {
+        self.config_mut().air_grace_hours = hours;
+        self.now = self.db.config.air_today(&Local::now());
+        self.rebuild_schedule();
+    }
+
+    /// Set the number of days to look back when catching up.
+    pub(crate) fn set_catch_up_days(&mut self, days: u64) {
+        self.config_mut().catch_up_days = days;
+        self.rebuild_schedule();
+    }
+
+    /// Get the current theme.
+    pub(crate) fn theme(&self) -> &Theme {
+        &self.current_theme
+    }
+
+    /// Set the theme configuration option.
+    pub(crate) fn set_theme(&mut self, theme: ThemeType) {
+        self.db.config.theme = theme;
+        self.db.changes.change(Change::Config);
+        self.current_theme = self.db.config.iced_theme();
+    }
+
+    /// Set the custom accent color, or `None` to use the one of the theme.
+    pub(crate) fn set_accent(&mut self, accent: Option<[u8; 3]>) {
+        self.db.config.accent = accent;
+        self.db.changes.change(Change::Config);
+        self.current_theme = self.db.config.iced_theme();
+    }
+
+    /// Set the theme configuration option.
+    pub(crate) fn set_tvdb_legacy_api_key(&mut self, api_key: String) {
+        self.tvdb.set_api_key(&api_key);
+        self.db.config.tvdb_legacy_apikey = api_key;
+        self.db.changes.change(Change::Config);
+    }
+
+    /// Set the theme configuration option.
+    pub(crate) fn set_tmdb_api_key(&mut self, api_key: String) {
+        self.tmdb.set_api_key(&api_key);
+        self.db.config.tmdb_api_key = api_key;
+        self.db.changes.change(Change::Config);
+    }
 
-        let Some(m) = self.db.movies.get(movie) else {
-            tracing::warn!(?movie, "Movie missing");
-            return;
-        };
+    /// Test if an OMDb API key is configured.
+    pub(crate) fn has_omdb_api_key(&self) -> bool {
+        self.omdb.has_api_key()
+    }
 
-        let timestamp = match remaining_season {
-            RemainingSeason::Aired => *now,
-            RemainingSeason::AirDate => {
-                let Some(release_date) = m.release() else {
-                    return;
-                };
+    /// Set OMDb API key.
+    pub(crate) fn set_omdb_api_key(&mut self, api_key: String) {
+        self.omdb.set_api_key(&api_key);
+        self.db.config.omdb_api_key = api_key;
+        self.db.changes.change(Change::Config);
+    }
 
-                release_date
-            }
-        };
+    /// Check if series is tracked.
+    pub(crate) fn get_series_by_remote(&self, id: &RemoteId) -> Option<&Series> {
+        let id = self.db.remotes.get_series(id)?;
+        self.db.series.get(&id)
+    }
 
-        self.db.watched.insert(Watched {
-            id: WatchedId::random(),
-            timestamp,
-            kind: WatchedKind::Movie { movie: m.id },
-        });
+    /// Search the local library, matching the titles and overviews of series
+    /// and movies as well as episode names against every word in the query.
+    ///
+    /// Series and movies are listed before episodes.
+    pub(crate) fn search_local(&self, query: &str) -> Vec<LocalSearchHit> {
+        let query = crate::search::Tokens::new(query);
 
-        self.db.changes.change(Change::Watched);
+        if query.is_empty() {
+            return Vec::new();
+        }
 
-        if self.db.pending.remove_movie(movie).is_some() {
-            self.db.changes.change(Change::Pending);
+        let matches = |s: &str| query.matches(s);
+
+        let series = self
+            .db
+            .series
+            .iter_by_name()
+            .filter(|s| s.removed.is_none())
+            .collect::<Vec<_>>();
+
+        let mut hits = series
+            .iter()
+            .filter(|s| matches(&s.title) || matches(&s.overview))
+            .map(|s| LocalSearchHit::Series(s.id))
+            .collect::<Vec<_>>();
+
+        hits.extend(
+            self.db
+                .movies
+                .iter_by_name()
+                .filter(|m| matches(&m.title) || matches(&m.overview))
+                .map(|m| LocalSearchHit::Movie(m.id)),
+        );
+
+        for s in &series {
+            hits.extend(
+                self.episodes(&s.id)
+                    .filter(|e| e.name.as_deref().is_some_and(matches))
+                    .map(|e| LocalSearchHit::Episode(s.id, e.id)),
+            );
         }
+
+        hits
     }
 
-    /// Skip an episode.
-    #[tracing::instrument(skip(self))]
-    pub(crate) fn skip(&mut self, now: &DateTime<Utc>, series_id: &SeriesId, id: &EpisodeId) {
-        tracing::trace!("Skipping episode");
-        self.populate_pending_from(now, series_id, id);
+    /// Check if movie is tracked.
+    pub(crate) fn get_movie_by_remote(&self, id: &RemoteId) -> Option<&Movie> {
+        let id = self.db.remotes.get_movie(id)?;
+        self.db.movies.get(&id)
     }
 
-    /// Skip an episode.
-    #[tracing::instrument(skip(self))]
-    pub(crate) fn skip_movie(&mut self, now: &DateTime<Utc>, id: &MovieId) {
-        tracing::trace!("Skipping movie");
-        self.db.pending.remove_movie(id);
+    /// Get series which have been removed, but not yet deleted.
+    pub(crate) fn removed_series(&self) -> impl DoubleEndedIterator<Item = &Series> {
+        self.db.series.iter_removed()
     }
 
-    /// Select the next pending episode to use for a show.
+    /// Remove the given series.
+    ///
+    /// The series and all of its data is kept until the removal grace period
+    /// has expired, at which point it's deleted by [`Service::purge_removed_series`].
     #[tracing::instrument(skip(self))]
-    pub(crate) fn select_pending(&mut self, now: &DateTime<Utc>, episode_id: &EpisodeId) {
-        tracing::trace!("Selecting pending series");
+    pub(crate) fn remove_series(&mut self, now: &DateTime<Utc>, id: &SeriesId) {
+        tracing::info!("Remove series");
 
-        let Some(episode) = self.db.episodes.get(episode_id) else {
-            tracing::warn!("Episode missing");
+        let Some(series) = self.db.series.get_mut(id) else {
             return;
         };
 
-        let aired = self
-            .db
-            .episodes
-            .get(episode_id)
-            .and_then(|e| e.aired_timestamp());
+        series.removed = Some(*now);
+        self.db.changes.change(Change::Series);
+        self.remove_tasks_by(|t| t.is_series(id));
+    }
 
-        let timestamp = self
+    /// Remove all series matching the given predicate, returning the number
+    /// of series removed.
+    ///
+    /// Series which have already been removed are left alone.
+    pub(crate) fn remove_series_where(
+        &mut self,
+        now: &DateTime<Utc>,
+        predicate: impl Fn(&Series) -> bool,
+    ) -> usize {
+        let ids = self
             .db
-            .watched
-            .by_series(episode.series())
-            .next_back()
-            .map(|w| w.timestamp);
+            .series
+            .iter()
+            .filter(|s| s.removed.is_none() && predicate(s))
+            .map(|s| s.id)
+            .collect::<Vec<_>>();
 
-        self.db.pending.extend([Pending {
-            timestamp: pending_timestamp(now, &[timestamp, aired]),
-            kind: PendingKind::Episode {
-                series: *episode.series(),
-                episode: episode.id,
-            },
-        }]);
+        for id in &ids {
+            self.remove_series(now, id);
+        }
 
-        self.db.changes.change(Change::Pending);
+        ids.len()
     }
 
-    /// Select the next pending movie.
-    #[tracing::instrument(skip(self))]
-    pub(crate) fn select_pending_movie(&mut self, now: &DateTime<Utc>, movie_id: &MovieId) {
-        tracing::trace!("Selecting pending movie");
+    /// Remove all series which aren't tracked, returning the number of series
+    /// removed.
+    pub fn prune_untracked(&mut self) -> usize {
+        self.remove_series_where(&Utc::now(), |s| !s.tracked)
+    }
 
-        let m = self.db.movies.get(movie_id);
-        let earliest = m.and_then(|m| m.earliest());
+    /// Remove duplicate watches of the same episode or movie, returning the
+    /// number of watches removed.
+    ///
+    /// Watches are duplicates if they're within the configured dedupe window
+    /// of the earliest watch, which is the one that is kept. A rating is moved
+    /// over to the kept watch if it doesn't have one.
+    pub fn dedupe_watches(&mut self) -> usize {
+        let window = self
+            .db
+            .config
+            .dedupe_window_hours
+            .map(|hours| chrono::Duration::hours(i64::from(hours)));
 
-        if self.db.watched.by_movie(movie_id).len() == 0 {
-            let timestamp = pending_timestamp(now, &[earliest]);
+        let mut by_target = HashMap::<_, Vec<&Watched>>::new();
 
-            self.db.pending.extend([Pending {
-                timestamp,
-                kind: PendingKind::Movie { movie: *movie_id },
-            }]);
+        for w in self.db.watched.iter() {
+            let target = match w.kind {
+                WatchedKind::Series { episode, .. } => *episode.id(),
+                WatchedKind::Movie { movie } => *movie.id(),
+            };
 
-            self.db.changes.change(Change::Pending);
+            by_target.entry(target).or_default().push(w);
+        }
+
+        let mut remove = Vec::new();
+        let mut ratings = Vec::new();
+
+        for watches in by_target.values_mut() {
+            watches.sort_by_key(|w| (w.timestamp, w.id));
+
+            let mut it = watches.iter();
+
+            let Some(mut kept) = it.next() else {
+                continue;
+            };
+
+            let mut rating = kept.rating;
+
+            for w in it {
+                let duplicate = match window {
+                    Some(window) => w.timestamp - kept.timestamp <= window,
+                    None => {
+                        w.timestamp.with_timezone(&Local).date_naive()
+                            == kept.timestamp.with_timezone(&Local).date_naive()
+                    }
+                };
+
+                if !duplicate {
+                    kept = w;
+                    rating = kept.rating;
+                    continue;
+                }
+
+                if rating.is_none() && w.rating.is_some() {
+                    rating = w.rating;
+                    ratings.push((kept.id, rating));
+                }
+
+                remove.push(w.id);
+            }
+        }
+
+        for (id, rating) in ratings {
+            if let Some(w) = self.db.watched.get_mut(&id) {
+                w.rating = rating;
+            }
+        }
+
+        for id in &remove {
+            self.db.watched.remove_watch(id);
         }
+
+        if !remove.is_empty() {
+            self.db.changes.change(Change::Watched);
+        }
+
+        remove.len()
     }
 
-    /// Clear next episode as pending.
+    /// Undo the removal of the given series.
     #[tracing::instrument(skip(self))]
-    pub(crate) fn clear_pending(&mut self, episode_id: &EpisodeId) {
-        tracing::trace!("Clearing pending");
+    pub(crate) fn restore_series(&mut self, id: &SeriesId) {
+        tracing::info!("Restore series");
 
-        self.db.changes.change(Change::Pending);
+        if let Some(series) = self.db.series.get_mut(id) {
+            if series.removed.take().is_some() {
+                self.db.changes.change(Change::Series);
+            }
+        }
+    }
 
-        if let Some(e) = self.db.episodes.get(episode_id) {
-            self.db.pending.remove_series(e.series());
+    /// Permanently delete any removed series whose grace period has expired.
+    pub(crate) fn purge_removed_series(&mut self, now: &DateTime<Utc>) {
+        let expired = self
+            .db
+            .series
+            .iter_removed()
+            .filter(|s| matches!(s.deleted_at(&self.db.config), Some(at) if at <= *now))
+            .map(|s| s.id)
+            .collect::<Vec<_>>();
+
+        for id in expired {
+            self.delete_series(&id);
         }
     }
 
-    /// Clear next episode as pending.
+    /// Permanently delete the given series.
     #[tracing::instrument(skip(self))]
-    pub(crate) fn clear_pending_movie(&mut self, movie_id: &MovieId) {
-        tracing::trace!("Clearing pending movie");
+    pub(crate) fn delete_series(&mut self, id: &SeriesId) {
+        tracing::info!("Delete series");
 
-        self.db.changes.change(Change::Pending);
-        self.db.pending.remove_movie(movie_id);
+        let _ = self.db.series.remove(id);
+        self.db.episodes.remove(id);
+        self.db.seasons.remove(id);
+        self.db.changes.remove_series(id);
+        self.remove_tasks_by(|t| t.is_series(id));
+        self.replace_collection_member(CollectionMember::Series { id: *id }, None);
     }
 
-    /// Remove a watch of the given episode.
+    /// Remove the given movie.
     #[tracing::instrument(skip(self))]
-    pub(crate) fn remove_episode_watch(&mut self, episode_id: &EpisodeId, watch_id: &WatchedId) {
-        tracing::trace!("Removing episode watch");
+    pub(crate) fn remove_movie(&mut self, id: &MovieId) {
+        tracing::info!("Remove movie");
 
-        let Some(w) = self.db.watched.remove_watch(watch_id) else {
-            tracing::warn!("Watch missing");
-            return;
-        };
+        let _ = self.db.movies.remove(id);
+        self.db.changes.remove_movie(id);
+        self.remove_tasks_by(|t| t.is_movie(id));
+        self.replace_collection_member(CollectionMember::Movie { id: *id }, None);
+    }
 
-        self.db.changes.change(Change::Watched);
+    /// Iterate over collections ordered by name.
+    pub(crate) fn collections_by_name(&self) -> impl DoubleEndedIterator<Item = &Collection> {
+        self.db.collections.iter_by_name()
+    }
 
-        if let Some(e) = self.db.episodes.get(episode_id) {
-            if self.db.watched.by_episode(&e.id).len() == 0 {
-                self.db.pending.extend([Pending {
-                    timestamp: w.timestamp,
-                    kind: PendingKind::Episode {
-                        series: *e.series(),
-                        episode: e.id,
-                    },
-                }]);
+    /// Get a single collection.
+    pub(crate) fn collection(&self, id: &CollectionId) -> Option<&Collection> {
+        self.db.collections.get(id)
+    }
 
-                self.db.changes.change(Change::Pending);
-            }
+    /// Create a new empty collection with the given name.
+    pub(crate) fn create_collection(&mut self, name: &str) -> CollectionId {
+        let id = CollectionId::random();
+
+        self.db.collections.insert(Collection {
+            id,
+            name: name.trim().to_owned(),
+            members: Vec::new(),
+        });
+
+        self.db.changes.change(Change::Collections);
+        id
+    }
+
+    /// Rename a collection.
+    pub(crate) fn rename_collection(&mut self, id: &CollectionId, name: &str) {
+        if self.db.collections.rename(id, name.trim().to_owned()) {
+            self.db.changes.change(Change::Collections);
         }
     }
 
-    /// Remove a single watch for the given movie.
-    #[tracing::instrument(skip(self))]
-    pub(crate) fn remove_movie_watch(&mut self, movie_id: &MovieId, watch_id: &WatchedId) {
-        tracing::trace!("Removing episode watch");
+    /// Remove a collection. Its members are left alone.
+    pub(crate) fn remove_collection(&mut self, id: &CollectionId) {
+        if self.db.collections.remove(id).is_some() {
+            self.db.changes.change(Change::Collections);
+        }
+    }
 
-        let Some(..) = self.db.watched.remove_watch(watch_id) else {
-            tracing::warn!("Watch missing");
-            return;
+    /// Add a member to a collection, returning `true` if it wasn't already a
+    /// member.
+    pub(crate) fn add_collection_member(
+        &mut self,
+        id: &CollectionId,
+        member: CollectionMember,
+    ) -> bool {
+        let Some(collection) = self.db.collections.get_mut(id) else {
+            return false;
         };
 
-        self.db.changes.change(Change::Watched);
-        // if let Some(m) = self.db.movies.get(movie_id) {
-        //     if self.db.watched.by_movie(&m.id).len() == 0 {
-        //         self.db.pending.extend([Pending {
-        //             series: *m.series(),
-        //             episode: m.id,
-        //             timestamp: w.timestamp,
-        //         }]);
+        if collection.members.contains(&member) {
+            return false;
+        }
 
-        //         self.db.changes.change(Change::Pending);
-        //     }
-        // }
+        collection.members.push(member);
+        self.db.changes.change(Change::Collections);
+        true
     }
 
-    /// Remove all watches of the given episode.
-    #[tracing::instrument(skip(self))]
-    pub(crate) fn remove_season_watches(
+    /// Remove a member from a collection.
+    pub(crate) fn remove_collection_member(
         &mut self,
-        now: &DateTime<Utc>,
-        series_id: &SeriesId,
-        season: &SeasonNumber,
+        id: &CollectionId,
+        member: &CollectionMember,
     ) {
-        tracing::trace!("Removing season watches");
-
-        let mut removed = 0;
+        let Some(collection) = self.db.collections.get_mut(id) else {
+            return;
+        };
 
-        for e in self.db.episodes.by_series(series_id) {
-            if e.season == *season {
-                removed += self.db.watched.remove_by_episode(&e.id);
-            }
-        }
+        let len = collection.members.len();
+        collection.members.retain(|m| m != member);
 
-        if removed > 0 {
-            self.db.changes.change(Change::Watched);
+        if collection.members.len() != len {
+            self.db.changes.change(Change::Collections);
         }
+    }
 
-        if self.db.pending.remove_series(series_id).is_some() {
-            self.db.changes.change(Change::Pending);
-        }
+    /// Replace a member in every collection it's part of, or remove it if
+    /// there is nothing to replace it with.
+    fn replace_collection_member(&mut self, from: CollectionMember, to: Option<CollectionMember>) {
+        let mut changed = false;
 
-        // Find the first episode matching the cleared season.
-        if let Some(e) = self
-            .db
-            .episodes
-            .by_series(series_id)
-            .find(|e| e.season == *season)
-        {
-            let timestamp = self
-                .db
-                .watched
-                .by_series(series_id)
-                .next_back()
-                .map(|w| w.timestamp);
+        for collection in self.db.collections.iter_mut() {
+            let Some(index) = collection.members.iter().position(|m| *m == from) else {
+                continue;
+            };
 
-            self.db.pending.extend([Pending {
-                timestamp: pending_timestamp(now, &[timestamp, e.aired_timestamp()]),
-                kind: PendingKind::Episode {
-                    series: *series_id,
-                    episode: e.id,
-                },
-            }]);
+            match to.filter(|to| !collection.members.contains(to)) {
+                Some(to) => {
+                    collection.members[index] = to;
+                }
+                None => {
+                    collection.members.remove(index);
+                }
+            }
 
-            self.db.changes.change(Change::Pending);
+            changed = true;
         }
-    }
 
-    /// Save changes made.
-    #[tracing::instrument(skip(self))]
-    pub(crate) fn save_changes(&mut self) -> impl Future<Output = Result<()>> {
-        if self.db.changes.contains(Change::Series) || self.db.changes.contains(Change::Schedule) {
-            self.rebuild_schedule();
+        if changed {
+            self.db.changes.change(Change::Collections);
         }
-
-        self.db
-            .save_changes(&self.paths, self.do_not_save)
-            .in_current_span()
     }
 
-    /// Populate pending from a series where we don't know which episode to
-    /// populate from.
-    #[tracing::instrument(skip(self))]
-    pub(crate) fn populate_pending(&mut self, now: &DateTime<Utc>, id: &SeriesId) {
-        tracing::trace!("Populate pending");
+    /// Get the combined watch progress of the members of a collection, as
+    /// the number of watched and total episodes and movies.
+    ///
+    /// Specials are not counted, and members which have been removed are
+    /// ignored.
+    pub(crate) fn collection_watched(&self, collection: &Collection) -> (usize, usize) {
+        let mut watched = 0;
+        let mut total = 0;
 
-        if let Some(pending) = self.db.pending.get(id) {
-            // Do nothing since we already have a pending episode.
-            tracing::trace!(?pending, "pending exists");
-            return;
+        for member in &collection.members {
+            match member {
+                CollectionMember::Series { id } => {
+                    if self.db.series.get(id).map_or(true, |s| s.removed.is_some()) {
+                        continue;
+                    }
+
+                    for season in self.seasons(id).filter(|s| !s.number.is_special()) {
+                        let (w, t) = self.season_watched(id, &season.number);
+                        watched += w;
+                        total += t;
+                    }
+                }
+                CollectionMember::Movie { id } => {
+                    if self.db.movies.get(id).is_none() {
+                        continue;
+                    }
+
+                    watched += usize::from(self.db.watched.by_movie(id).len() > 0);
+                    total += 1;
+                }
+            }
         }
 
-        let last = self.db.watched.by_series(id).next_back();
+        (watched, total)
+    }
 
-        let mut cur = if let Some(WatchedKind::Series { episode, .. }) = last.map(|w| &w.kind) {
-            tracing::trace!(?episode, "Episode after watched");
-            self.db.episodes.get(episode).and_then(EpisodeRef::next)
-        } else {
-            tracing::trace!("Finding next unwatched episode");
-            self.db.episodes.by_series(id).next()
-        };
+    /// Find pairs of movies which are likely duplicates of each other, such as
+    /// the same movie added through different remotes.
+    ///
+    /// The movie with the most complete release information comes first.
+    pub(crate) fn find_duplicate_movies(&self) -> Vec<(MovieId, MovieId)> {
+        let mut pairs = BTreeSet::new();
+        let mut by_title = HashMap::<_, Vec<MovieId>>::new();
 
-        while let Some(e) = cur {
-            if !e.season.is_special() && self.db.watched.by_episode(&e.id).len() == 0 {
-                break;
+        for m in self.db.movies.iter() {
+            // A remote which has been claimed by another movie means that both
+            // refer to the same thing.
+            for remote_id in self.db.remotes.get_by_movie(&m.id) {
+                let Some(other) = self.db.remotes.get_movie(&remote_id) else {
+                    continue;
+                };
+
+                if other != m.id && self.db.movies.get(&other).is_some() {
+                    pairs.insert((m.id.min(other), m.id.max(other)));
+                }
             }
 
-            cur = e.next();
+            let year = m.release_date.map(|d| d.year());
+
+            by_title
+                .entry((m.title.to_lowercase(), year))
+                .or_default()
+                .push(m.id);
         }
 
-        let Some(e) = cur else {
-            return;
-        };
+        for ids in by_title.into_values() {
+            for (n, a) in ids.iter().enumerate() {
+                for b in &ids[n + 1..] {
+                    pairs.insert((*a.min(b), *a.max(b)));
+                }
+            }
+        }
 
-        tracing::trace!(episode = ?e.id, "Set pending");
+        let score = |id: &MovieId| {
+            let releases = self.db.movies.get(id).map_or(0, |m| {
+                m.release_dates.iter().map(|r| r.dates.len()).sum::<usize>()
+            });
 
-        self.db.changes.change(Change::Pending);
-        // Mark the next episode in the show as pending.
-        self.db.pending.extend([Pending {
-            timestamp: pending_timestamp(now, &[last.map(|w| w.timestamp), e.aired_timestamp()]),
-            kind: PendingKind::Episode {
-                series: *id,
-                episode: e.id,
-            },
-        }]);
+            (releases, self.db.watched.by_movie(id).len())
+        };
+
+        pairs
+            .into_iter()
+            .map(|(a, b)| {
+                if score(&b) > score(&a) {
+                    (b, a)
+                } else {
+                    (a, b)
+                }
+            })
+            .collect()
     }
 
-    /// Populate pending from a known episode ID.
-    fn populate_pending_from(&mut self, now: &DateTime<Utc>, series_id: &SeriesId, id: &EpisodeId) {
-        let Some(e) = self.db.episodes.get(id).and_then(|e| e.next()) else {
-            if self.db.pending.remove_series(series_id).is_some() {
-                self.db.changes.change(Change::Pending);
-            }
+    /// Merge the movie `drop` into `keep`, moving its watches and remotes over
+    /// before removing it.
+    #[tracing::instrument(skip(self))]
+    pub(crate) fn merge_movies(&mut self, now: &DateTime<Utc>, keep: &MovieId, drop: &MovieId) {
+        if keep == drop {
+            return;
+        }
 
+        let Some(dropped) = self.db.movies.get(drop).cloned() else {
+            tracing::warn!("Dropped movie missing");
             return;
         };
 
-        self.db.changes.change(Change::Pending);
-        let timestamp = e.aired_timestamp().map(|t| t.max(*now)).unwrap_or(*now);
-
-        self.db.pending.extend([Pending {
-            timestamp,
-            kind: PendingKind::Episode {
-                series: *series_id,
-                episode: e.id,
-            },
-        }]);
-    }
-
-    /// Get current configuration.
-    pub(crate) fn config(&self) -> &Config {
-        &self.db.config
-    }
+        let Some(movie) = self.db.movies.get_mut(keep) else {
+            tracing::warn!("Kept movie missing");
+            return;
+        };
 
-    /// Get configuration mutably indicating that it has been changed.
-    pub(crate) fn config_mut(&mut self) -> &mut Config {
-        self.db.changes.change(Change::Config);
-        &mut self.db.config
-    }
+        tracing::info!("Merging movies");
 
-    /// Get the current theme.
-    pub(crate) fn theme(&self) -> &Theme {
-        &self.current_theme
-    }
+        movie.merge_releases_from(&dropped);
+        self.db.changes.change(Change::Movie);
 
-    /// Set the theme configuration option.
-    pub(crate) fn set_theme(&mut self, theme: ThemeType) {
-        self.db.config.theme = theme;
-        self.db.changes.change(Change::Config);
-        self.current_theme = self.db.config.iced_theme();
-    }
+        let existing = self
+            .db
+            .watched
+            .by_movie(keep)
+            .map(|w| w.timestamp)
+            .collect::<BTreeSet<_>>();
 
-    /// Set the theme configuration option.
-    pub(crate) fn set_tvdb_legacy_api_key(&mut self, api_key: String) {
-        self.tvdb.set_api_key(&api_key);
-        self.db.config.tvdb_legacy_apikey = api_key;
-        self.db.changes.change(Change::Config);
-    }
+        let watches = self.db.watched.by_movie(drop).copied().collect::<Vec<_>>();
 
-    /// Set the theme configuration option.
-    pub(crate) fn set_tmdb_api_key(&mut self, api_key: String) {
-        self.tmdb.set_api_key(&api_key);
-        self.db.config.tmdb_api_key = api_key;
-        self.db.changes.change(Change::Config);
-    }
+        for w in watches {
+            self.db.watched.remove_watch(&w.id);
 
-    /// Check if series is tracked.
-    pub(crate) fn get_series_by_remote(&self, id: &RemoteId) -> Option<&Series> {
-        let id = self.db.remotes.get_series(id)?;
-        self.db.series.get(&id)
-    }
+            // NB: The same watch might have been recorded for both.
+            if existing.contains(&w.timestamp) {
+                continue;
+            }
 
-    /// Check if movie is tracked.
-    pub(crate) fn get_movie_by_remote(&self, id: &RemoteId) -> Option<&Movie> {
-        let id = self.db.remotes.get_movie(id)?;
-        self.db.movies.get(&id)
-    }
+            self.db.watched.insert(Watched {
+                kind: WatchedKind::Movie { movie: *keep },
+                ..w
+            });
+        }
 
-    /// Remove the given series.
-    #[tracing::instrument(skip(self))]
-    pub(crate) fn remove_series(&mut self, id: &SeriesId) {
-        tracing::info!("Remove series");
+        self.db.changes.change(Change::Watched);
 
-        let _ = self.db.series.remove(id);
-        self.db.episodes.remove(id);
-        self.db.seasons.remove(id);
-        self.db.changes.remove_series(id);
-        self.db.tasks.remove_tasks_by(|t| t.is_series(id));
-    }
+        if self.db.remotes.merge_movie(*keep, *drop) {
+            self.db.changes.change(Change::Remotes);
+        }
 
-    /// Remove the given movie.
-    #[tracing::instrument(skip(self))]
-    pub(crate) fn remove_movie(&mut self, id: &MovieId) {
-        tracing::info!("Remove movie");
+        self.replace_collection_member(
+            CollectionMember::Movie { id: *drop },
+            Some(CollectionMember::Movie { id: *keep }),
+        );
 
-        let _ = self.db.movies.remove(id);
-        self.db.changes.remove_movie(id);
-        self.db.tasks.remove_tasks_by(|t| t.is_movie(id));
+        self.db.pending.remove_movie(drop);
+        self.db.pending.remove_movie(keep);
+        self.db.changes.change(Change::Pending);
+        self.remove_movie(drop);
+        self.select_pending_movie(now, keep);
     }
 
     /// Download series using a remote identifier.
@@ -969,16 +3344,24 @@ impl Service {
         &self,
         remote_id: &RemoteId,
         if_none_match: Option<&Etag>,
+        if_none_match_episodes: &[Etag],
         series_id: Option<&SeriesId>,
     ) -> impl Future<Output = Result<Option<NewSeries>>> {
         let tvdb = self.tvdb.clone();
         let tmdb = self.tmdb.clone();
+        let omdb = self.omdb.clone();
         let proxy = self.db.remotes.proxy();
         let remote_id = *remote_id;
         let if_none_match = if_none_match.cloned();
+        let if_none_match_episodes = if_none_match_episodes.to_vec();
         let series_id = series_id.copied();
+        let episode_language = self.db.config.episode_language.clone();
+        let episode_concurrency = self.db.config.tmdb_episode_concurrency;
+        let country = self.db.config.movie_release_country.clone();
 
         let future = async move {
+            use futures::StreamExt;
+
             tracing::info!("Downloading series");
 
             let lookup_series = |q| {
@@ -993,10 +3376,38 @@ impl Service {
 
             let data = match remote_id {
                 RemoteId::Tvdb { id } => {
-                    let series = tvdb.series(id, lookup_series);
-                    let episodes = tvdb.series_episodes(id, lookup_episode);
-                    let ((series, remote_ids, last_etag, last_modified), episodes) =
-                        tokio::try_join!(series, episodes)?;
+                    let series = tvdb.series(id, lookup_series, if_none_match.as_ref());
+                    let episodes =
+                        tvdb.series_episodes(id, lookup_episode, &if_none_match_episodes);
+
+                    let (series, episodes) = tokio::try_join!(series, episodes)?;
+
+                    if series.is_none() && episodes.is_none() {
+                        tracing::trace!("{remote_id}: not changed");
+                        return Ok(None);
+                    }
+
+                    // NB: The series and its episodes change independently,
+                    // so whichever is unchanged has to be downloaded again.
+                    let (series, remote_ids, last_etag, last_modified) = match series {
+                        Some(series) => series,
+                        None => tvdb
+                            .series(id, lookup_series, None)
+                            .await?
+                            .context("series missing")?,
+                    };
+
+                    let (mut episodes, episode_etags) = match episodes {
+                        Some(episodes) => episodes,
+                        None => tvdb
+                            .series_episodes(id, lookup_episode, &[])
+                            .await?
+                            .context("episodes missing")?,
+                    };
+
+                    for e in &mut episodes {
+                        e.episode.air_time = series.air_time;
+                    }
                     let seasons = episodes_into_seasons(&episodes);
 
                     NewSeries {
@@ -1004,45 +3415,77 @@ impl Service {
                         remote_ids,
                         last_etag,
                         last_modified,
+                        episode_etags,
                         episodes,
                         seasons,
                     }
                 }
                 RemoteId::Tmdb { id } => {
                     let Some((series, remote_ids, last_etag, last_modified, seasons)) = tmdb
-                        .series(id, lookup_series, if_none_match.as_ref())
+                        .series(id, lookup_series, if_none_match.as_ref(), Some(&country))
                         .await?
                     else {
                         tracing::trace!("{remote_id}: not changed");
                         return Ok(None);
                     };
 
+                    let mut remaining = seasons.iter();
+                    let mut downloads = FuturesUnordered::new();
                     let mut episodes = Vec::new();
 
-                    for season in &seasons {
-                        let new_episodes = tmdb
-                            .download_episodes(
+                    loop {
+                        while downloads.len() < SEASON_CONCURRENCY {
+                            let Some(season) = remaining.next() else {
+                                break;
+                            };
+
+                            downloads.push(tmdb.download_episodes(
                                 id,
                                 season.number,
+                                episode_language.as_deref(),
                                 series.language.as_deref(),
+                                episode_concurrency,
                                 &lookup_episode,
-                            )
-                            .await?;
+                            ));
+                        }
+
+                        let Some(new_episodes) = downloads.next().await else {
+                            break;
+                        };
 
-                        episodes.extend(new_episodes);
+                        episodes.extend(new_episodes?);
                     }
 
+                    drop(downloads);
+
                     NewSeries {
                         series,
                         remote_ids,
                         last_etag,
                         last_modified,
+                        episode_etags: Vec::new(),
                         episodes,
                         seasons,
                     }
                 }
-                RemoteId::Imdb { .. } => {
-                    bail!("Cannot download series data from IMDB")
+                RemoteId::Imdb { id } => {
+                    if !omdb.has_api_key() {
+                        bail!("Cannot download series data from IMDB without an OMDb API key")
+                    }
+
+                    let Some((series, remote_ids)) = omdb.series(id, lookup_series).await? else {
+                        bail!("{remote_id}: not found on OMDb")
+                    };
+
+                    NewSeries {
+                        series,
+                        remote_ids,
+                        last_etag: None,
+                        last_modified: None,
+                        episode_etags: Vec::new(),
+                        episodes: Vec::new(),
+                        seasons: Vec::new(),
+                    }
                 }
             };
 
@@ -1065,6 +3508,7 @@ impl Service {
         let remote_id = *remote_id;
         let if_none_match = if_none_match.cloned();
         let movie_id = movie_id.copied();
+        let country = self.db.config.movie_release_country.clone();
 
         let future = async move {
             tracing::info!("Downloading movies");
@@ -1079,8 +3523,9 @@ impl Service {
 
             let data = match remote_id {
                 RemoteId::Tmdb { id } => {
-                    let Some((movie, remote_ids, last_etag, last_modified)) =
-                        tmdb.movie(id, lookup_movie, if_none_match.as_ref()).await?
+                    let Some((movie, remote_ids, last_etag, last_modified)) = tmdb
+                        .movie(id, lookup_movie, if_none_match.as_ref(), Some(&country))
+                        .await?
                     else {
                         tracing::trace!("{remote_id}: not changed");
                         return Ok(None);
@@ -1132,10 +3577,20 @@ impl Service {
         };
 
         series.tracked = true;
+        series.removed = None;
         self.db.changes.change(Change::Series);
         true
     }
 
+    /// Set the refresh interval override of a series, or `None` to use the
+    /// default.
+    pub(crate) fn set_refresh_interval(&mut self, series_id: &SeriesId, hours: Option<u32>) {
+        if let Some(s) = self.db.series.get_mut(series_id) {
+            s.refresh_interval_hours = hours;
+            self.db.changes.change(Change::Series);
+        }
+    }
+
     /// Disable tracking of the series with the given id.
     pub(crate) fn untrack(&mut self, series_id: &SeriesId) {
         if let Some(s) = self.db.series.get_mut(series_id) {
@@ -1144,63 +3599,337 @@ impl Service {
         }
     }
 
+    /// Insert a series which is only known by its title, for when no
+    /// metadata can be downloaded for it.
+    pub(crate) fn insert_title_only_series(
+        &mut self,
+        now: &DateTime<Utc>,
+        remote_id: RemoteId,
+        title: &str,
+        year: Option<i32>,
+    ) -> SeriesId {
+        let id = self
+            .db
+            .remotes
+            .get_series(&remote_id)
+            .unwrap_or_else(SeriesId::random);
+
+        let series = UpdateSeries {
+            id,
+            title: title.to_owned(),
+            language: None,
+            first_air_date: year.and_then(|year| NaiveDate::from_ymd_opt(year, 1, 1)),
+            overview: String::new(),
+            graphics: SeriesGraphics::default(),
+            remote_id,
+            content_rating: None,
+            genres: Vec::new(),
+            air_time: None,
+        };
+
+        self.insert_series(
+            now,
+            NewSeries {
+                series,
+                remote_ids: BTreeSet::from([remote_id]),
+                last_etag: None,
+                last_modified: None,
+                episode_etags: Vec::new(),
+                episodes: Vec::new(),
+                seasons: Vec::new(),
+            },
+        );
+
+        id
+    }
+
     /// Insert a new tracked series
     #[tracing::instrument(skip(self))]
     pub(crate) fn insert_series(&mut self, now: &DateTime<Utc>, data: NewSeries) {
         tracing::info!("Inserting new series");
 
-        let series_id = data.series.id;
+        let series_id = data.series.id;
+
+        for &remote_id in &data.remote_ids {
+            if self.db.remotes.insert_series(remote_id, series_id) {
+                self.db.changes.change(Change::Remotes);
+            }
+        }
+
+        if self
+            .db
+            .sync
+            .update_last_etag(data.series.remote_id, data.last_etag)
+        {
+            self.db.changes.change(Change::Sync);
+        }
+
+        if self
+            .db
+            .sync
+            .update_last_modified(data.series.remote_id, data.last_modified)
+        {
+            self.db.changes.change(Change::Sync);
+        }
+
+        if self
+            .db
+            .sync
+            .update_episode_etags(data.series.remote_id, data.episode_etags)
+        {
+            self.db.changes.change(Change::Sync);
+        }
+
+        let new_content = self.detect_new_content(now, &series_id, &data.episodes);
+        let moved = self.moved_episodes(&series_id, &data.episodes);
+
+        // NB: Episodes of a series which is imported for the first time are not
+        // considered to be added.
+        let exists = self.db.series.get(&series_id).is_some();
+
+        let mut episodes = Vec::with_capacity(data.episodes.len());
+
+        for mut episode in data.episodes {
+            // NB: Kinds are assigned by the user, so keep them around.
+            if let Some(e) = self.db.episodes.get(&episode.episode.id) {
+                episode.episode.kind = e.kind;
+                episode.episode.added = e.added;
+            } else if exists {
+                episode.episode.added = Some(*now);
+            }
+
+            for &remote_id in &episode.remote_ids {
+                if self
+                    .db
+                    .remotes
+                    .insert_episode(remote_id, episode.episode.id)
+                {
+                    self.db.changes.change(Change::Remotes);
+                }
+            }
+
+            episodes.push(episode.episode);
+        }
+
+        self.db.episodes.insert(series_id, episodes);
+        self.db.seasons.insert(series_id, data.seasons.clone());
+
+        if let Some(current) = self.db.series.get_mut(&series_id) {
+            current.merge_from(data.series);
+
+            if let Some(new_content) = new_content {
+                tracing::info!(?new_content, "New content for caught up series");
+                current.new_content = Some(new_content);
+            }
+        } else {
+            self.db.series.insert(Series::new_series(data.series));
+        }
+
+        if !moved.is_empty() {
+            self.relocate_pending(&series_id, &moved);
+        }
+
+        // Remove any pending episodes for the given series.
+        self.populate_pending(now, &series_id);
+        self.db.changes.add_series(&series_id);
+    }
+
+    /// Find existing episodes which have been moved to a different season
+    /// upstream.
+    ///
+    /// Watches follow along since they refer to episodes by identifier, as do
+    /// season statistics since they are computed from where episodes currently
+    /// are.
+    fn moved_episodes(&self, series_id: &SeriesId, episodes: &[NewEpisode]) -> Vec<EpisodeId> {
+        let mut moved = Vec::new();
+
+        for new in episodes {
+            let Some(e) = self.db.episodes.get(&new.episode.id) else {
+                continue;
+            };
+
+            if e.season != new.episode.season {
+                tracing::info!(
+                    ?series_id,
+                    episode = ?e.id,
+                    from = ?e.season,
+                    to = ?new.episode.season,
+                    "Episode moved to another season"
+                );
+
+                moved.push(e.id);
+            }
+        }
+
+        moved
+    }
+
+    /// Clear the pending episode of a series if it has been moved to another
+    /// season, so that it's picked again from watch history.
+    fn relocate_pending(&mut self, series_id: &SeriesId, moved: &[EpisodeId]) {
+        let is_moved = matches!(
+            self.db.pending.get(series_id).map(|p| &p.kind),
+            Some(PendingKind::Episode { episode, .. }) if moved.contains(episode)
+        );
+
+        if is_moved && self.db.pending.remove_series(series_id).is_some() {
+            tracing::info!(?series_id, "Recomputing pending for moved episode");
+            self.db.changes.change(Change::Pending);
+        }
+    }
+
+    /// Update the remote identifiers of existing episodes from freshly
+    /// downloaded series data.
+    ///
+    /// Episodes are matched by identifier, or by season and episode number,
+    /// so that existing episode identifiers and their watch history are
+    /// preserved. Nothing but the remote mappings is modified.
+    #[tracing::instrument(skip(self, data))]
+    pub(crate) fn resolve_episode_remotes(&mut self, series_id: &SeriesId, data: NewSeries) {
+        let existing = self
+            .db
+            .episodes
+            .by_series(series_id)
+            .map(|e| (e.id, e.season, e.number))
+            .collect::<Vec<_>>();
+
+        let mut updated = 0usize;
+
+        for new in data.episodes {
+            let e = &new.episode;
+
+            let found = existing.iter().find(|(id, ..)| *id == e.id).or_else(|| {
+                existing
+                    .iter()
+                    .find(|(_, season, number)| *season == e.season && *number == e.number)
+            });
+
+            let Some(&(episode_id, ..)) = found else {
+                continue;
+            };
 
-        for &remote_id in &data.remote_ids {
-            if self.db.remotes.insert_series(remote_id, series_id) {
-                self.db.changes.change(Change::Remotes);
+            if self
+                .db
+                .remotes
+                .replace_episode(episode_id, new.remote_ids.iter().copied())
+            {
+                updated += 1;
             }
         }
 
-        if self
-            .db
-            .sync
-            .update_last_etag(data.series.remote_id, data.last_etag)
-        {
-            self.db.changes.change(Change::Sync);
+        if updated > 0 {
+            self.db.changes.change(Change::Remotes);
         }
 
-        if self
+        tracing::info!(updated, "Re-resolved episode remotes");
+    }
+
+    /// Detect if a series which was previously caught up has gained new
+    /// upcoming episodes.
+    fn detect_new_content(
+        &self,
+        now: &DateTime<Utc>,
+        series_id: &SeriesId,
+        episodes: &[NewEpisode],
+    ) -> Option<NewContent> {
+        // Initial imports and series with something left to watch don't count.
+        if self.db.series.get(series_id).is_none() || self.db.pending.get(series_id).is_some() {
+            return None;
+        }
+
+        let existing = self
             .db
-            .sync
-            .update_last_modified(data.series.remote_id, data.last_modified)
-        {
-            self.db.changes.change(Change::Sync);
+            .episodes
+            .by_series(series_id)
+            .map(|e| (e.id, e.season))
+            .collect::<Vec<_>>();
+
+        if existing.is_empty() {
+            return None;
         }
 
-        let mut episodes = Vec::with_capacity(data.episodes.len());
+        let today = self.db.config.air_today(now);
 
-        for episode in data.episodes {
-            for &remote_id in &episode.remote_ids {
-                if self
-                    .db
-                    .remotes
-                    .insert_episode(remote_id, episode.episode.id)
-                {
-                    self.db.changes.change(Change::Remotes);
-                }
-            }
+        let mut added = episodes.iter().map(|e| &e.episode).filter(|e| {
+            !e.season.is_special()
+                && e.will_air(&today)
+                && !existing.iter().any(|(id, _)| *id == e.id)
+        });
 
-            episodes.push(episode.episode);
+        let first = added.next()?;
+
+        let new_season = std::iter::once(first)
+            .chain(added)
+            .map(|e| e.season)
+            .find(|season| !existing.iter().any(|(_, s)| s == season));
+
+        Some(match new_season {
+            Some(season) => NewContent::Season { season },
+            None => NewContent::Episodes,
+        })
+    }
+
+    /// Iterate over series which have new content.
+    pub(crate) fn series_with_new_content(
+        &self,
+    ) -> impl Iterator<Item = (&Series, NewContent)> + '_ {
+        self.db
+            .series
+            .iter_by_name()
+            .filter(|s| s.removed.is_none())
+            .filter_map(|s| Some((s, s.new_content?)))
+    }
+
+    /// Test if the given episode has recently aired in a tracked series but is
+    /// still missing its name and overview, which usually means the provider
+    /// hasn't been updated yet.
+    pub(crate) fn is_metadata_pending(&self, episode: &EpisodeRef<'_>, today: &NaiveDate) -> bool {
+        if !matches!(episode.season, SeasonNumber::Number(..)) || !episode.has_aired(today) {
+            return false;
         }
 
-        self.db.episodes.insert(series_id, episodes);
-        self.db.seasons.insert(series_id, data.seasons.clone());
+        if episode
+            .name
+            .as_deref()
+            .is_some_and(|n| !n.trim().is_empty())
+            || !episode.overview.trim().is_empty()
+        {
+            return false;
+        }
 
-        if let Some(current) = self.db.series.get_mut(&series_id) {
-            current.merge_from(data.series);
-        } else {
-            self.db.series.insert(Series::new_series(data.series));
+        let Some(aired) = episode.aired else {
+            return false;
+        };
+
+        if (*today - aired).num_days() > METADATA_PENDING_DAYS {
+            return false;
         }
 
-        // Remove any pending episodes for the given series.
-        self.populate_pending(now, &series_id);
-        self.db.changes.add_series(&series_id);
+        self.db
+            .series
+            .get(episode.series())
+            .is_some_and(|s| s.tracked)
+    }
+
+    /// Episodes recently added to tracked series, most recently added first.
+    pub(crate) fn recently_added(&self) -> impl Iterator<Item = (&Series, EpisodeRef<'_>)> {
+        self.recently_added
+            .iter()
+            .flat_map(|(series_id, episode_id)| {
+                Some((
+                    self.db.series.get(series_id)?,
+                    self.db.episodes.get(episode_id)?,
+                ))
+            })
+    }
+
+    /// Clear the new content marker of a series.
+    pub(crate) fn clear_new_content(&mut self, series_id: &SeriesId) {
+        if let Some(series) = self.db.series.get_mut(series_id) {
+            if series.new_content.take().is_some() {
+                self.db.changes.change(Change::Series);
+            }
+        }
     }
 
     /// Insert a new tracked movie.
@@ -1232,13 +3961,17 @@ impl Service {
             self.db.changes.change(Change::Sync);
         }
 
+        self.db.changes.add_movie(&movie_id);
+
+        let country = Some(self.db.config.movie_release_country.as_str());
+
         if let Some(current) = self.db.movies.get_mut(&movie_id) {
-            current.merge_from(data.movie);
+            // NB: Merging rebuilds the cached earliest release dates.
+            current.merge_from(data.movie, country);
         } else {
-            self.db.movies.insert(Movie::new_movie(data.movie));
+            self.db.movies.insert(Movie::new_movie(data.movie, country));
         }
 
-        self.db.changes.add_movie(&movie_id);
         self.select_pending_movie(now, &movie_id);
     }
 
@@ -1246,12 +3979,15 @@ impl Service {
     pub(crate) fn load_images(
         &self,
         images: Vec<(ImageKey, ImageV2)>,
-    ) -> impl Future<Output = Result<Vec<(ImageKey, Handle)>>> {
+    ) -> impl Future<Output = Result<Vec<(ImageKey, Result<Handle, ImageFailure>)>>> {
         use futures::StreamExt;
 
         let paths = self.paths.clone();
         let tvdb = self.tvdb.clone();
         let tmdb = self.tmdb.clone();
+        let omdb = self.omdb.clone();
+        let index = self.images.clone();
+        let semaphore = Arc::new(Semaphore::new(self.db.config.image_concurrency()));
 
         let future = async move {
             let mut output = Vec::with_capacity(images.len());
@@ -1261,26 +3997,49 @@ impl Service {
                 let paths = paths.clone();
                 let tvdb = tvdb.clone();
                 let tmdb = tmdb.clone();
+                let omdb = omdb.clone();
+                let index = index.clone();
+                let semaphore = semaphore.clone();
 
                 futures.push(async move {
+                    let _permit = semaphore.acquire().await;
                     let hash = image.hash();
 
                     let handle = match &image {
                         ImageV2::Tvdb { uri } => {
-                            cache::image(&paths.images, &tvdb, uri.as_ref(), hash, key.hint).await
+                            cache::image(&paths.images, &index, &tvdb, uri.as_ref(), hash, key.hint)
+                                .await
                         }
                         ImageV2::Tmdb { uri } => {
-                            cache::image(&paths.images, &tmdb, uri.as_ref(), hash, key.hint).await
+                            cache::image(&paths.images, &index, &tmdb, uri.as_ref(), hash, key.hint)
+                                .await
+                        }
+                        ImageV2::Imdb { uri } => {
+                            cache::image(&paths.images, &index, &omdb, uri.as_ref(), hash, key.hint)
+                                .await
                         }
                     };
 
-                    let handle = handle.with_context(|| anyhow!("Downloading: {image:?}"))?;
-                    Ok::<_, Error>((key, handle))
+                    let handle = handle.map_err(|error| {
+                        tracing::warn!(?image, "Failed to load image: {error}");
+
+                        for error in error.chain().skip(1) {
+                            tracing::warn!(?image, "Caused by: {error}");
+                        }
+
+                        // Image data which can't be decoded won't get any
+                        // better by retrying.
+                        ImageFailure {
+                            permanent: error.chain().any(|e| e.is::<image_rs::ImageError>()),
+                        }
+                    });
+
+                    (key, handle)
                 });
             }
 
             while let Some(result) = futures.next().await {
-                output.push(result?);
+                output.push(result);
             }
 
             Ok(output)
@@ -1294,6 +4053,58 @@ impl Service {
         self.do_not_save = true;
     }
 
+    /// Test if the service is prevented from saving anything.
+    pub(crate) fn is_do_not_save(&self) -> bool {
+        self.do_not_save
+    }
+
+    /// Remove cached images which aren't referenced by any series, season,
+    /// episode or movie, returning the number of files removed.
+    ///
+    /// If the service isn't allowed to save, nothing is removed and the
+    /// number of files which would be removed is returned.
+    pub fn purge_orphaned_images(&self) -> impl Future<Output = Result<usize>> {
+        let mut referenced = HashSet::new();
+
+        for series in self.db.series.iter() {
+            referenced.extend(series.graphics.images().map(|i| i.hash().as_u128()));
+
+            for season in self.seasons(&series.id) {
+                referenced.extend(season.poster().map(|i| i.hash().as_u128()));
+            }
+
+            for episode in self.episodes(&series.id) {
+                referenced.extend(episode.filename().map(|i| i.hash().as_u128()));
+            }
+        }
+
+        for movie in self.db.movies.iter() {
+            referenced.extend(movie.graphics.images().map(|i| i.hash().as_u128()));
+        }
+
+        let paths = self.paths.clone();
+        let index = self.images.clone();
+        let dry_run = self.do_not_save;
+
+        let future = async move {
+            let task = tokio::task::spawn_blocking(move || {
+                cache::purge(&paths.images, &index, &referenced, dry_run)
+            });
+
+            let (count, bytes) = task.await??;
+
+            if dry_run {
+                tracing::info!("Would purge {count} orphaned images, freeing {bytes} bytes");
+            } else {
+                tracing::info!("Purged {count} orphaned images, freeing {bytes} bytes");
+            }
+
+            Ok(count)
+        };
+
+        future.in_current_span()
+    }
+
     /// Get existing id by remote if it exists.
     pub(crate) fn existing_by_remote_ids<I>(&self, ids: I) -> Option<SeriesId>
     where
@@ -1308,6 +4119,14 @@ impl Service {
         None
     }
 
+    /// Find an existing episode by its remote id.
+    pub(crate) fn existing_episode_by_remote_id(
+        &self,
+        remote_id: &RemoteEpisodeId,
+    ) -> Option<EpisodeId> {
+        self.db.remotes.get_episode(remote_id)
+    }
+
     /// Insert a new watch.
     pub(crate) fn insert_new_watch(
         &mut self,
@@ -1322,6 +4141,7 @@ impl Service {
                 series: series_id,
                 episode: episode_id,
             },
+            rating: None,
         });
 
         self.db.changes.change(Change::Watched);
@@ -1365,6 +4185,15 @@ impl Service {
         async move { tmdb.search_series(&query).await }.in_current_span()
     }
 
+    /// Find a series or movie on tmdb by its IMDb identifier.
+    pub(crate) fn find_imdb(
+        &self,
+        id: Raw<16>,
+    ) -> impl Future<Output = Result<Option<RemoteLink>>> {
+        let tmdb = self.tmdb.clone();
+        async move { tmdb.find_imdb(&id.to_string()).await }.in_current_span()
+    }
+
     /// Search movies from tmdb.
     pub(crate) fn search_movies_tmdb(
         &self,
@@ -1388,7 +4217,7 @@ impl Service {
             .signed_duration_since(self.now)
             .num_days()
             .unsigned_abs()
-            <= self.config().schedule_duration_days
+            <= self.config().schedule_window.days()
         {
             let mut schedule = Vec::new();
 
@@ -1399,12 +4228,24 @@ impl Service {
 
                 let mut scheduled_episodes = Vec::new();
 
+                // NB: Episodes airing while a series is snoozed are left out.
+                let snoozed_until = self
+                    .db
+                    .pending
+                    .get(&series.id)
+                    .and_then(|p| p.snoozed_until)
+                    .map(|until| until.date_naive());
+
                 for e in self.episodes(&series.id) {
                     let Some(air_date) = &e.aired else {
                         continue;
                     };
 
-                    if *air_date != current {
+                    if *air_date != current || self.is_ignored_special(&e) {
+                        continue;
+                    }
+
+                    if snoozed_until.is_some_and(|until| *air_date < until) {
                         continue;
                     }
 
@@ -1412,10 +4253,39 @@ impl Service {
                 }
 
                 if !scheduled_episodes.is_empty() {
-                    schedule.push(ScheduledSeries {
+                    schedule.push(Scheduled::Series(ScheduledSeries {
                         series_id: series.id,
                         episodes: scheduled_episodes,
-                    });
+                    }));
+                }
+            }
+
+            // NB: Series which air at a known time are ordered by it, ahead of
+            // the ones which don't.
+            schedule.sort_by_cached_key(|s| {
+                let time = match s {
+                    Scheduled::Series(s) => s
+                        .episodes
+                        .iter()
+                        .filter_map(|id| self.db.episodes.get(id)?.air_time)
+                        .min(),
+                    Scheduled::Movie(..) => None,
+                };
+
+                (time.is_none(), time)
+            });
+
+            for movie in self.db.movies.iter() {
+                if self.db.watched.by_movie(&movie.id).len() > 0 {
+                    continue;
+                }
+
+                let Some(date) = movie.scheduled_release_date(&self.db.config) else {
+                    continue;
+                };
+
+                if date.date_naive() == current {
+                    schedule.push(Scheduled::Movie(movie.id));
                 }
             }
 
@@ -1434,6 +4304,62 @@ impl Service {
         }
 
         self.schedule = days;
+
+        let since = self
+            .now
+            .checked_sub_days(Days::new(self.db.config.catch_up_days))
+            .unwrap_or(NaiveDate::MIN);
+
+        let mut recently_aired = Vec::new();
+
+        for series in self.db.series.iter() {
+            if !series.tracked {
+                continue;
+            }
+
+            for e in self.db.episodes.by_series(&series.id) {
+                let Some(air_date) = e.aired else {
+                    continue;
+                };
+
+                if air_date >= since && air_date <= self.now {
+                    recently_aired.push((air_date, e.id));
+                }
+            }
+        }
+
+        recently_aired.sort();
+        self.recently_aired = recently_aired;
+
+        let since = self
+            .now
+            .checked_sub_days(Days::new(self.db.config.recently_added_days))
+            .unwrap_or(NaiveDate::MIN);
+
+        let mut recently_added = Vec::new();
+
+        for series in self.db.series.iter() {
+            if !series.tracked || series.removed.is_some() {
+                continue;
+            }
+
+            for e in self.db.episodes.by_series(&series.id) {
+                let Some(added) = e.added else {
+                    continue;
+                };
+
+                if added.date_naive() >= since {
+                    recently_added.push((added, e.season, e.number, series.id, e.id));
+                }
+            }
+        }
+
+        recently_added.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| (a.1, a.2).cmp(&(b.1, b.2))));
+
+        self.recently_added = recently_added
+            .into_iter()
+            .map(|(_, _, _, series_id, episode_id)| (series_id, episode_id))
+            .collect();
     }
 
     /// Take if a queue has been modified.
@@ -1475,6 +4401,39 @@ impl Service {
             .next()
     }
 
+    /// Put a task back in the queue to be retried after the given number of
+    /// seconds, holding off other tasks until then.
+    pub(crate) fn retry_task_after(&mut self, now: &DateTime<Utc>, task: Task, seconds: u64) {
+        let until = i64::try_from(seconds)
+            .ok()
+            .and_then(chrono::Duration::try_seconds)
+            .and_then(|d| now.checked_add_signed(d))
+            .unwrap_or(*now);
+
+        tracing::warn!(task = ?task.id, %until, "Rate limited, retrying task");
+        self.db.tasks.retry_after(task, until);
+        self.db.changes.change(Change::Queue);
+    }
+
+    /// Test if a task which failed with a transient error should be retried.
+    pub(crate) fn should_retry_task(&self, task: &Task) -> bool {
+        task.attempts.saturating_add(1) < self.config().max_task_attempts
+    }
+
+    /// Put a failed task back in the queue, backing off exponentially with
+    /// the number of times it has been attempted.
+    pub(crate) fn retry_failed_task(&mut self, now: &DateTime<Utc>, task: Task) {
+        let seconds = RETRY_DELAY_SECONDS.saturating_mul(1 << task.attempts.min(10));
+
+        let at = chrono::Duration::try_seconds(seconds)
+            .and_then(|d| now.checked_add_signed(d))
+            .unwrap_or(*now);
+
+        tracing::warn!(task = ?task.id, attempts = task.attempts + 1, %at, "Task failed, retrying");
+        self.db.tasks.retry_failed(task, at);
+        self.db.changes.change(Change::Queue);
+    }
+
     /// Mark task as completed.
     #[inline]
     pub(crate) fn complete_task(&mut self, now: &DateTime<Utc>, task: Task) -> Option<TaskStatus> {
@@ -1499,6 +4458,7 @@ impl Service {
             }
         }
 
+        self.db.changes.change(Change::Queue);
         self.db.tasks.complete(now, task)
     }
 
@@ -1518,6 +4478,53 @@ impl Service {
         self.db.remotes.get_by_movie(id)
     }
 
+    /// Get a link to share for the given series.
+    pub(crate) fn series_share_url(&self, id: &SeriesId) -> Option<String> {
+        let series = self.db.series.get(id)?;
+        let remote_id = RemoteId::share(series.remote_id, self.db.remotes.get_by_series(id))?;
+        Some(remote_id.url())
+    }
+
+    /// Get a link to share for the given movie.
+    pub(crate) fn movie_share_url(&self, id: &MovieId) -> Option<String> {
+        let movie = self.db.movies.get(id)?;
+        let remote_id = RemoteId::share(movie.remote_id, self.db.remotes.get_by_movie(id))?;
+        Some(remote_id.movie_url())
+    }
+
+    /// Get a link to share for the given episode.
+    ///
+    /// This links directly to the episode where possible, preferring the
+    /// primary remote of the series, and otherwise falls back to linking to
+    /// the series.
+    pub(crate) fn episode_share_url(&self, id: &EpisodeId) -> Option<String> {
+        let e = self.db.episodes.get(id)?;
+        let series_id = *e.series();
+        let series = self.db.series.get(&series_id)?;
+
+        let primary = RemoteId::share(series.remote_id, self.db.remotes.get_by_series(&series_id));
+
+        let url = primary
+            .into_iter()
+            .chain(self.db.remotes.get_by_series(&series_id))
+            .find_map(|r| r.episode_url(e.season, e.number));
+
+        if let Some(url) = url {
+            return Some(url);
+        }
+
+        let imdb = self.db.remotes.get_by_episode(id).find_map(|r| match r {
+            RemoteEpisodeId::Imdb { id } => Some(id),
+            _ => None,
+        });
+
+        if let Some(id) = imdb {
+            return Some(RemoteId::Imdb { id }.url());
+        }
+
+        self.series_share_url(&series_id)
+    }
+
     /// Clear last sync.
     pub(crate) fn clear_sync(&mut self) {
         self.db.sync.clear();
@@ -1527,6 +4534,11 @@ impl Service {
     pub(crate) fn last_etag(&self, remote_id: &RemoteId) -> Option<&Etag> {
         self.db.sync.last_etag(remote_id)
     }
+
+    /// Get the last episode etags for the given remote id.
+    pub(crate) fn last_episode_etags(&self, remote_id: &RemoteId) -> &[Etag] {
+        self.db.sync.episode_etags(remote_id)
+    }
 }
 
 /// Helper to build seasons out of known episodes.
@@ -1549,6 +4561,21 @@ fn episodes_into_seasons(episodes: &[NewEpisode]) -> Vec<Season> {
     map.into_values().collect()
 }
 
+/// Get the number of seconds series updates are cached for.
+fn series_cache_time(series: &Series) -> i64 {
+    match series.refresh_interval_hours {
+        Some(hours) => (i64::from(hours) * 3600).max(MIN_CACHE_TIME),
+        None => CACHE_TIME,
+    }
+}
+
+/// Get the first day of the week that `date` is in.
+fn week_of(date: &NaiveDate, week_start: Weekday) -> Option<NaiveDate> {
+    let offset =
+        (date.weekday().num_days_from_monday() + 7 - week_start.num_days_from_monday()) % 7;
+    date.checked_sub_days(Days::new(u64::from(offset)))
+}
+
 /// Calculate pending timestamp.
 fn pending_timestamp(now: &DateTime<Utc>, candidates: &[Option<DateTime<Utc>>]) -> DateTime<Utc> {
     if let Some(timestamp) = candidates.iter().flatten().max() {
@@ -1558,11 +4585,346 @@ fn pending_timestamp(now: &DateTime<Utc>, candidates: &[Option<DateTime<Utc>>])
     }
 }
 
-/// Mode for marking remaining season.
+/// What an action which can be undone was performed on.
+#[derive(Debug, Clone, Copy)]
+enum UndoTarget {
+    Series(SeriesId),
+    Movie(MovieId),
+}
+
+/// State from before an action, used to undo it.
 #[derive(Debug)]
+struct Undo {
+    target: UndoTarget,
+    /// What was pending before the action.
+    pending: Option<Pending>,
+    /// The season which was waiting to be started before the action.
+    next_season: Option<NextSeason>,
+    /// The episode which was set as the next one to watch before the action.
+    current_episode: Option<EpisodeId>,
+    /// Watches added by the action.
+    added: Vec<WatchedId>,
+    /// Watches removed by the action.
+    removed: Vec<Watched>,
+    /// Episode which was skipped by the action. This is only set if the
+    /// episode wasn't already skipped, so that undoing doesn't remove a skip
+    /// from before the action.
+    skipped: Option<EpisodeId>,
+}
+
+/// A match in the local library.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LocalSearchHit {
+    Series(SeriesId),
+    Episode(SeriesId, EpisodeId),
+    Movie(MovieId),
+}
+
+/// Mode for marking remaining season.
+#[derive(Debug, Clone, Copy)]
 pub(crate) enum RemainingSeason {
     /// Timestamp should be right now, but only if an episode has aired.
     Aired,
     /// Timestamp should be the air date of the episode.
     AirDate,
+    /// Timestamp should be the given one.
+    At(DateTime<Utc>),
+}
+
+impl RemainingSeason {
+    /// Get the timestamp of a watch, where `release` is the air or release
+    /// date of what's being watched.
+    fn timestamp(
+        self,
+        now: &DateTime<Utc>,
+        release: Option<DateTime<Utc>>,
+    ) -> Option<DateTime<Utc>> {
+        match self {
+            RemainingSeason::Aired => Some(*now),
+            RemainingSeason::AirDate => release,
+            RemainingSeason::At(timestamp) => Some(timestamp),
+        }
+    }
+
+    /// Get the timestamp used when populating what's pending after a watch.
+    fn pending_timestamp(self, now: &DateTime<Utc>) -> DateTime<Utc> {
+        match self {
+            RemainingSeason::At(timestamp) => timestamp,
+            _ => *now,
+        }
+    }
+}
+
+/// Escape characters which have a special meaning in Markdown.
+fn markdown_escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        if matches!(
+            c,
+            '\\' | '`'
+                | '*'
+                | '_'
+                | '{'
+                | '}'
+                | '['
+                | ']'
+                | '<'
+                | '>'
+                | '('
+                | ')'
+                | '#'
+                | '+'
+                | '-'
+                | '!'
+                | '|'
+        ) {
+            out.push('\\');
+        }
+
+        out.push(c);
+    }
+
+    out
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::collections::BTreeSet;
+    use std::path::Path;
+
+    use chrono::{NaiveDate, Utc};
+
+    use crate::model::{Episode, EpisodeId, Movie, MovieId, RemoteId, SeasonNumber, SeriesId};
+
+    use super::{
+        MovieExport, NewEpisode, NewMovie, NewSeries, PendingRef, RemainingSeason, Service,
+        UpdateMovie, UpdateSeries,
+    };
+
+    fn new_movie(id: MovieId, content_rating: &str) -> NewMovie {
+        NewMovie {
+            movie: UpdateMovie {
+                id,
+                title: String::from("Test Movie"),
+                language: None,
+                release_date: None,
+                overview: String::new(),
+                graphics: Default::default(),
+                remote_id: RemoteId::Tmdb { id: 1 },
+                release_dates: Vec::new(),
+                collection: None,
+                content_rating: Some(content_rating.to_owned()),
+            },
+            remote_ids: BTreeSet::from([RemoteId::Tmdb { id: 1 }]),
+            last_etag: None,
+            last_modified: None,
+        }
+    }
+
+    pub(crate) fn new_episode(number: u32) -> NewEpisode {
+        NewEpisode {
+            episode: Episode {
+                id: EpisodeId::random(),
+                name: None,
+                overview: String::new(),
+                absolute_number: None,
+                season: SeasonNumber::Number(1),
+                number,
+                aired: NaiveDate::from_ymd_opt(2020, 1, number),
+                compat_filename: None,
+                graphics: Default::default(),
+                remote_id: None,
+                directors: Vec::new(),
+                writers: Vec::new(),
+                kind: None,
+                added: None,
+                runtime: None,
+                air_time: None,
+            },
+            remote_ids: BTreeSet::new(),
+        }
+    }
+
+    pub(crate) fn new_series(id: SeriesId, episodes: Vec<NewEpisode>) -> NewSeries {
+        NewSeries {
+            series: UpdateSeries {
+                id,
+                title: String::from("Test Series"),
+                language: None,
+                first_air_date: None,
+                overview: String::new(),
+                graphics: Default::default(),
+                remote_id: RemoteId::Tmdb { id: 1 },
+                content_rating: None,
+                genres: Vec::new(),
+                air_time: None,
+            },
+            remote_ids: BTreeSet::from([RemoteId::Tmdb { id: 1 }]),
+            last_etag: None,
+            last_modified: None,
+            episode_etags: Vec::new(),
+            episodes,
+            seasons: Vec::new(),
+        }
+    }
+
+    fn pending_episode(service: &Service, series_id: &SeriesId) -> Option<EpisodeId> {
+        match service.pending_ref_by_series(series_id)? {
+            PendingRef::Episode { episode, .. } => Some(episode.id),
+            _ => None,
+        }
+    }
+
+    async fn reload(mut service: Service, root: &Path) -> Service {
+        service.save_changes().await.unwrap();
+        Service::new(&root.join("config"), &root.join("cache")).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_movie_persistence() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let now = Utc::now();
+        let id = MovieId::random();
+
+        let mut service = Service::new(&root.join("config"), &root.join("cache")).unwrap();
+        service.insert_movie(&now, new_movie(id, "PG"));
+
+        let mut service = reload(service, root).await;
+        assert_eq!(
+            service.movie(&id).unwrap().content_rating.as_deref(),
+            Some("PG")
+        );
+
+        service.set_movie_notes(&id, String::from("Watch with friends"));
+        service.insert_movie(&now, new_movie(id, "R"));
+
+        let mut service = reload(service, root).await;
+        let movie = service.movie(&id).unwrap();
+        assert_eq!(movie.notes, "Watch with friends");
+        assert_eq!(movie.content_rating.as_deref(), Some("R"));
+
+        let imported = MovieId::random();
+
+        service.import_movie(MovieExport {
+            movie: Movie {
+                id: imported,
+                title: String::from("Imported Movie"),
+                release_date: None,
+                overview: String::new(),
+                graphics: Default::default(),
+                remote_id: None,
+                release_dates: Vec::new(),
+                earliest_releases: Vec::new(),
+                collection: None,
+                content_rating: None,
+                notes: String::new(),
+            },
+            remote_ids: Vec::new(),
+            watched: Vec::new(),
+            pending: None,
+        });
+
+        service.remove_movie(&id);
+
+        let service = reload(service, root).await;
+        assert!(service.movie(&id).is_none());
+        assert_eq!(service.movie(&imported).unwrap().title, "Imported Movie");
+    }
+
+    #[test]
+    fn test_skip_current_episode() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let now = Utc::now();
+        let series_id = SeriesId::random();
+
+        let episodes = (1..=3).map(new_episode).collect::<Vec<_>>();
+        let ids = episodes.iter().map(|e| e.episode.id).collect::<Vec<_>>();
+
+        let mut service = Service::new(&root.join("config"), &root.join("cache")).unwrap();
+        service.insert_series(&now, new_series(series_id, episodes));
+
+        service.set_current_episode(&now, &series_id, &ids[1]);
+        assert_eq!(pending_episode(&service, &series_id), Some(ids[1]));
+
+        service.skip(&now, &series_id, &ids[1]);
+        assert_eq!(pending_episode(&service, &series_id), Some(ids[2]));
+    }
+
+    #[test]
+    fn test_undo_current_episode() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let now = Utc::now();
+        let series_id = SeriesId::random();
+
+        let episodes = (1..=4).map(new_episode).collect::<Vec<_>>();
+        let ids = episodes.iter().map(|e| e.episode.id).collect::<Vec<_>>();
+
+        let mut service = Service::new(&root.join("config"), &root.join("cache")).unwrap();
+        service.insert_series(&now, new_series(series_id, episodes));
+
+        service.set_current_episode(&now, &series_id, &ids[2]);
+        service.watch(&now, &ids[2], RemainingSeason::Aired);
+        assert_eq!(service.series(&series_id).unwrap().current_episode, None);
+
+        assert!(service.undo_last_action());
+        assert_eq!(
+            service.series(&series_id).unwrap().current_episode,
+            Some(ids[2])
+        );
+        assert_eq!(pending_episode(&service, &series_id), Some(ids[2]));
+    }
+
+    #[test]
+    fn test_import_series_remotes() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let now = Utc::now();
+        let series_id = SeriesId::random();
+
+        let mut service = Service::new(&root.join("config"), &root.join("cache")).unwrap();
+        service.insert_series(&now, new_series(series_id, vec![new_episode(1)]));
+
+        let mut export = service.export_series(&series_id, true).unwrap();
+        export.series.id = SeriesId::random();
+        assert!(service.import_series(export).is_err());
+
+        let mut export = service.export_series(&series_id, true).unwrap();
+        export.remote_ids = vec![RemoteId::Tmdb { id: 2 }];
+        service.import_series(export).unwrap();
+
+        assert_eq!(
+            service.remotes_by_series(&series_id).collect::<Vec<_>>(),
+            [RemoteId::Tmdb { id: 2 }]
+        );
+        assert_eq!(
+            service.db.remotes.get_series(&RemoteId::Tmdb { id: 1 }),
+            None
+        );
+    }
+
+    #[test]
+    fn test_import_movie_replaces_pending_and_remotes() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let now = Utc::now();
+        let id = MovieId::random();
+
+        let mut service = Service::new(&root.join("config"), &root.join("cache")).unwrap();
+        service.insert_movie(&now, new_movie(id, "PG"));
+        assert!(service.pending_by_movie(&id).is_some());
+
+        service.import_movie(MovieExport {
+            movie: service.movie(&id).unwrap().clone(),
+            remote_ids: Vec::new(),
+            watched: Vec::new(),
+            pending: None,
+        });
+
+        assert!(service.pending_by_movie(&id).is_none());
+        assert_eq!(service.remotes_by_movie(&id).len(), 0);
+    }
 }
@@ -69,6 +69,8 @@ impl AsRef<Path> for Directory {
 
 pub(crate) struct Paths {
     pub(crate) lock: tokio::sync::Mutex<()>,
+    pub(crate) root: Box<Path>,
+    pub(crate) cache: Box<Path>,
     pub(crate) config: Candidate,
     pub(crate) sync: Candidate,
     pub(crate) remotes: Candidate,
@@ -77,6 +79,8 @@ pub(crate) struct Paths {
     pub(crate) movies: Candidate,
     pub(crate) watched: Candidate,
     pub(crate) pending: Candidate,
+    pub(crate) collections: Candidate,
+    pub(crate) queue: Candidate,
     pub(crate) episodes: Directory,
     pub(crate) seasons: Directory,
 }
@@ -86,6 +90,8 @@ impl Paths {
     pub(crate) fn new(config: &Path, cache: &Path) -> Self {
         Self {
             lock: tokio::sync::Mutex::new(()),
+            root: config.into(),
+            cache: cache.into(),
             config: Candidate::new(config.join("config")),
             sync: Candidate::new(config.join("sync")),
             remotes: Candidate::new(config.join("remotes")),
@@ -93,6 +99,8 @@ impl Paths {
             movies: Candidate::new(config.join("movies")),
             watched: Candidate::new(config.join("watched")),
             pending: Candidate::new(config.join("pending")),
+            collections: Candidate::new(config.join("collections")),
+            queue: Candidate::new(config.join("queue")),
             episodes: Directory {
                 path: config.join("episodes").into(),
             },
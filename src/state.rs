@@ -18,6 +18,12 @@ pub(crate) struct State {
     saving: bool,
     /// Naive today date.
     today: NaiveDate,
+    /// Text waiting to be written to the clipboard.
+    clipboard: Option<String>,
+    /// Indicates that something was recently copied to the clipboard.
+    copied: bool,
+    /// Index of the card which has keyboard focus.
+    focus: usize,
 }
 
 impl State {
@@ -29,6 +35,9 @@ impl State {
             errors: VecDeque::new(),
             saving: false,
             today,
+            clipboard: None,
+            copied: false,
+            focus: 0,
         }
     }
 
@@ -82,4 +91,50 @@ impl State {
     pub(crate) fn set_saving(&mut self, saving: bool) {
         self.saving = saving;
     }
+
+    /// Copy the given text to the clipboard.
+    pub(crate) fn copy_to_clipboard(&mut self, text: String) {
+        self.clipboard = Some(text);
+    }
+
+    /// Take text waiting to be written to the clipboard.
+    pub(crate) fn take_clipboard(&mut self) -> Option<String> {
+        let text = self.clipboard.take()?;
+        self.copied = true;
+        Some(text)
+    }
+
+    /// Test if something was recently copied to the clipboard.
+    #[inline]
+    pub(crate) fn is_copied(&self) -> bool {
+        self.copied
+    }
+
+    /// Clear the indication that something was copied to the clipboard.
+    #[inline]
+    pub(crate) fn clear_copied(&mut self) {
+        self.copied = false;
+    }
+
+    /// Index of the card which has keyboard focus.
+    #[inline]
+    pub(crate) fn focus(&self) -> usize {
+        self.focus
+    }
+
+    /// Move keyboard focus by the given number of cards.
+    pub(crate) fn move_focus(&mut self, delta: isize) {
+        self.focus = self.focus.saturating_add_signed(delta);
+    }
+
+    /// Keep keyboard focus within the given number of cards.
+    pub(crate) fn clamp_focus(&mut self, len: usize) {
+        self.focus = self.focus.min(len.saturating_sub(1));
+    }
+
+    /// Move keyboard focus back to the first card.
+    #[inline]
+    pub(crate) fn reset_focus(&mut self) {
+        self.focus = 0;
+    }
 }
@@ -1,7 +1,9 @@
+use chrono::NaiveDate;
 use iced::widget::container;
 use iced::{widget as w, Element, Pixels};
-use iced::{Background, Theme};
+use iced::{Background, Color, Theme};
 
+use crate::model::Episode;
 use crate::params::*;
 
 pub(crate) struct Style;
@@ -80,9 +82,74 @@ pub(crate) fn weak(theme: &Theme) -> container::Appearance {
     }
 }
 
+/// Background of the card which has keyboard focus.
+pub(crate) fn focused(theme: &Theme) -> container::Appearance {
+    let extended = theme.extended_palette();
+    let pair = extended.primary.weak;
+
+    container::Appearance {
+        background: Some(Background::Color(pair.color)),
+        text_color: Some(pair.text),
+        ..Default::default()
+    }
+}
+
 /// Generate warning text.
 pub fn warning_text(theme: &Theme) -> iced::theme::Text {
     let extended = theme.extended_palette();
     let color = extended.danger.base.color;
     iced::theme::Text::Color(color)
 }
+
+/// The state of an episode as it is presented in episode lists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum EpisodeState {
+    /// The episode has been watched.
+    Watched,
+    /// The episode has aired but hasn't been watched.
+    Aired,
+    /// The episode will air in the future.
+    Upcoming,
+    /// The episode has no known air date.
+    Unknown,
+}
+
+impl EpisodeState {
+    /// Determine the state of an episode.
+    pub(crate) fn new(episode: &Episode, watched: usize, today: &NaiveDate) -> Self {
+        if watched > 0 {
+            EpisodeState::Watched
+        } else if episode.has_aired(today) {
+            EpisodeState::Aired
+        } else if episode.will_air(today) {
+            EpisodeState::Upcoming
+        } else {
+            EpisodeState::Unknown
+        }
+    }
+
+    /// Label of the state, so that it's never conveyed through color alone.
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            EpisodeState::Watched => "Watched",
+            EpisodeState::Aired => "Aired",
+            EpisodeState::Upcoming => "Upcoming",
+            EpisodeState::Unknown => "Unknown air date",
+        }
+    }
+
+    /// Text style of the state.
+    ///
+    /// Colors are picked from the Okabe-Ito palette so they remain
+    /// distinguishable for colorblind users.
+    pub(crate) fn text(self, theme: &Theme) -> iced::theme::Text {
+        let color = match self {
+            EpisodeState::Watched => Color::from_rgb8(86, 180, 233),
+            EpisodeState::Aired => Color::from_rgb8(230, 159, 0),
+            EpisodeState::Upcoming => theme.extended_palette().secondary.base.color,
+            EpisodeState::Unknown => Color::from_rgb8(204, 121, 167),
+        };
+
+        iced::theme::Text::Color(color)
+    }
+}
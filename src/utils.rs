@@ -9,3 +9,9 @@ pub(crate) use self::hoverable::Hoverable;
 
 mod option_iter;
 pub(crate) use self::option_iter::OptionIter;
+
+mod open;
+pub(crate) use self::open::open_directory;
+
+mod sparkline;
+pub(crate) use self::sparkline::Sparkline;
@@ -0,0 +1,25 @@
+use std::io;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// Open the given directory in the file browser of the operating system.
+pub(crate) fn open_directory(path: &Path) -> io::Result<()> {
+    let program = if cfg!(target_os = "windows") {
+        "explorer"
+    } else if cfg!(target_os = "macos") {
+        "open"
+    } else {
+        "xdg-open"
+    };
+
+    let mut child = Command::new(program)
+        .arg(path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+
+    // NB: Reap the process once it exits so it doesn't linger.
+    std::thread::spawn(move || child.wait());
+    Ok(())
+}
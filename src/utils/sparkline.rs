@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use iced::mouse::Cursor;
+use iced::widget::canvas::{self, Frame, Geometry, Path};
+use iced::{Point, Rectangle, Renderer, Size, Theme};
+
+/// Spacing between bars as a fraction of their width.
+const BAR_SPACING: f32 = 0.2;
+
+/// A compact bar chart of the given values, drawn left to right.
+pub(crate) struct Sparkline {
+    values: Arc<[usize]>,
+}
+
+impl Sparkline {
+    pub(crate) fn new(values: Arc<[usize]>) -> Self {
+        Self { values }
+    }
+}
+
+impl<Message> canvas::Program<Message> for Sparkline {
+    type State = ();
+
+    fn draw(
+        &self,
+        _: &Self::State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        _: Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+
+        let palette = theme.extended_palette();
+        let max = self.values.iter().copied().max().unwrap_or_default();
+
+        let slot = frame.width() / self.values.len().max(1) as f32;
+        let width = slot * (1.0 - BAR_SPACING);
+
+        let baseline = Path::line(
+            Point::new(0.0, frame.height()),
+            Point::new(frame.width(), frame.height()),
+        );
+
+        frame.stroke(
+            &baseline,
+            canvas::Stroke::default()
+                .with_color(palette.background.strong.color)
+                .with_width(1.0),
+        );
+
+        if max > 0 {
+            for (index, &value) in self.values.iter().enumerate() {
+                if value == 0 {
+                    continue;
+                }
+
+                let height = frame.height() * value as f32 / max as f32;
+                let x = slot * index as f32 + (slot - width) / 2.0;
+
+                frame.fill_rectangle(
+                    Point::new(x, frame.height() - height),
+                    Size::new(width, height),
+                    palette.primary.base.color,
+                );
+            }
+        }
+
+        vec![frame.into_geometry()]
+    }
+}